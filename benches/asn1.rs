@@ -0,0 +1,47 @@
+//! ASN.1 DER signature parsing benchmarks
+
+#![deny(warnings)]
+
+#[macro_use]
+extern crate criterion;
+extern crate signatory;
+
+use criterion::Criterion;
+use signatory::{
+    curve::{
+        nistp256::{Asn1Signature, FixedSignature, SHA256_FIXED_SIZE_TEST_VECTORS},
+        NistP256,
+    },
+    encoding::asn1::validate_ecdsa_der,
+    test_vector::TestVector,
+    Signature,
+};
+
+/// Test vector to use for benchmarking
+const TEST_VECTOR: &TestVector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+
+fn validate_der(c: &mut Criterion) {
+    let fixed_signature = FixedSignature::from_bytes(TEST_VECTOR.sig).unwrap();
+    let der = Asn1Signature::from(&fixed_signature).as_ref().to_vec();
+
+    c.bench_function("nistp256: validate ECDSA DER", move |b| {
+        b.iter(|| validate_ecdsa_der::<NistP256>(&der).unwrap())
+    });
+}
+
+fn parse_der(c: &mut Criterion) {
+    let fixed_signature = FixedSignature::from_bytes(TEST_VECTOR.sig).unwrap();
+    let der = Asn1Signature::from(&fixed_signature).as_ref().to_vec();
+
+    c.bench_function("nistp256: parse ECDSA DER", move |b| {
+        b.iter(|| Asn1Signature::from_bytes(&der).unwrap())
+    });
+}
+
+criterion_group! {
+    name = asn1;
+    config = Criterion::default();
+    targets = validate_der, parse_der
+}
+
+criterion_main!(asn1);
@@ -189,6 +189,21 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn empty_message_roundtrip() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+
+        let signer = P256Signer::from_pkcs8(&vector.to_pkcs8()).unwrap();
+        let verifier = P256Verifier::from(&signer.public_key().unwrap());
+
+        let signature: FixedSignature = signatory::sign_sha256(&signer, &[]).unwrap();
+        assert!(verifier.verify_sha256(&[], &signature).is_ok());
+        assert!(verifier.verify_sha256(b"\0", &signature).is_err());
+
+        let asn1_signature = Asn1Signature::from(&signature);
+        assert!(verifier.verify_sha256(&[], &asn1_signature).is_ok());
+    }
+
     #[test]
     pub fn rejects_tweaked_fixed_signature() {
         let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
@@ -245,4 +260,35 @@ mod tests {
         let asn1_signature = Asn1Signature::from(&fixed_signature);
         assert!(verifier.verify_sha256(vector.msg, &asn1_signature).is_ok());
     }
+
+    /// Signature of `msg` generated with the W3C Web Cryptography API (Node.js
+    /// v20's `crypto.subtle`), i.e. `subtle.sign({ name: "ECDSA", hash:
+    /// "SHA-256" }, privateKey, msg)`, with the public key exported via
+    /// `subtle.exportKey("raw", publicKey)`
+    #[test]
+    fn test_webcrypto_signature_verifies() {
+        let public_key = PublicKey::from_bytes(
+            b"\x04\xf1\xf0\x44\xae\x6f\x62\x4d\x99\x47\xfe\x5b\xb7\x43\xc1\xdd\
+            \x4f\x4f\x74\x46\x55\xad\xa6\x31\xb1\x0f\x23\xa4\xa3\x6d\x3b\xff\
+            \xf0\xd4\x1f\xcd\xd4\xec\x25\x7b\x23\xd7\x60\x39\x99\x87\x79\x98\
+            \x67\x10\x1e\x2e\x57\x40\xf8\x41\x2f\xfe\x87\x13\xe6\x12\xcd\x6c\x12"
+                .as_ref(),
+        ).unwrap();
+
+        let msg = b"signed with WebCrypto";
+
+        let signature = FixedSignature::from_webcrypto(
+            b"\xcd\xdf\x16\xa5\x6d\x5c\x67\x86\x22\x5b\xea\x2d\x8e\xd2\x8e\xba\
+            \x41\x2e\x0a\xac\xdd\x43\x13\x42\xcd\x9a\x4d\x28\x73\xb3\xfc\xd7\
+            \x6e\x73\x6d\xb8\x6b\xbc\xcd\x25\xa1\x99\x28\x3f\x51\x95\x9f\x77\
+            \xa6\x4c\xc7\xc4\x9f\xef\x36\x23\x0f\x73\x28\xff\xf1\x8c\x70\x56",
+        ).unwrap();
+
+        let verifier = P256Verifier::from(&public_key);
+        assert!(verifier.verify_sha256(msg, &signature).is_ok());
+        assert!(verifier.verify_sha256(&msg[1..], &signature).is_err());
+
+        let asn1_signature = Asn1Signature::from(&signature);
+        assert!(verifier.verify_sha256(msg, &asn1_signature).is_ok());
+    }
 }
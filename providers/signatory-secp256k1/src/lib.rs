@@ -1,4 +1,9 @@
 //! ECDSA provider for the `secp256k1` crate (a.k.a. secp256k1-rs)
+//!
+//! Signers and verifiers accept any `Digest` with a 32-byte output, e.g.
+//! SHA-256, or Keccak-256 as used by Ethereum (`sha3::Keccak256`). Digests
+//! must implement the same version of the `Digest` trait as the one
+//! re-exported as `signatory::digest` (i.e. `sha3` v0.7 for `digest` v0.7).
 
 #![crate_name = "signatory_secp256k1"]
 #![crate_type = "lib"]
@@ -12,11 +17,23 @@
 #[macro_use]
 extern crate lazy_static;
 extern crate secp256k1;
+#[cfg(test)]
+extern crate sha2;
+#[cfg(test)]
+extern crate sha3;
 extern crate signatory;
 
+#[cfg(feature = "unstable-provider-internals")]
+use signatory::RawProvider;
 use signatory::{
-    curve::secp256k1::{Asn1Signature, FixedSignature, PublicKey, SecretKey},
+    curve::secp256k1::{
+        Asn1Signature, FixedSignature, PublicKey, RecoverableSignature, Secp256k1, SecretKey,
+    },
     digest::Digest,
+    ecdsa::{
+        self, BundleVerifier, DigestRecoverer, MessageDigest, PublicKeyValidator, RawDigestSigner,
+        RawDigestVerifier, SignatureFormat,
+    },
     generic_array::typenum::U32,
     DigestSigner, DigestVerifier, Error, PublicKeyed, Signature,
 };
@@ -72,7 +89,14 @@ where
 {
     /// Compute an ASN.1 DER-encoded signature of the given 32-byte SHA-256 digest
     fn sign(&self, digest: D) -> Result<Asn1Signature, Error> {
-        let m = secp256k1::Message::from_slice(digest.result().as_slice()).unwrap();
+        self.sign_raw_digest(&MessageDigest::from_digest(digest))
+    }
+}
+
+impl RawDigestSigner<Secp256k1, Asn1Signature> for EcdsaSigner {
+    /// Compute an ASN.1 DER-encoded signature of the given 32-byte digest
+    fn sign_raw_digest(&self, digest: &MessageDigest<Secp256k1>) -> Result<Asn1Signature, Error> {
+        let m = secp256k1::Message::from_slice(digest.as_bytes()).unwrap();
         let sig = SECP256K1_ENGINE.sign(&m, &self.0);
         Ok(Asn1Signature::from_bytes(sig.serialize_der(&SECP256K1_ENGINE)).unwrap())
     }
@@ -84,12 +108,33 @@ where
 {
     /// Compute a compact, fixed-sized signature of the given 32-byte SHA-256 digest
     fn sign(&self, digest: D) -> Result<FixedSignature, Error> {
-        let m = secp256k1::Message::from_slice(digest.result().as_slice()).unwrap();
+        self.sign_raw_digest(&MessageDigest::from_digest(digest))
+    }
+}
+
+impl RawDigestSigner<Secp256k1, FixedSignature> for EcdsaSigner {
+    /// Compute a compact, fixed-sized signature of the given 32-byte digest
+    fn sign_raw_digest(&self, digest: &MessageDigest<Secp256k1>) -> Result<FixedSignature, Error> {
+        let m = secp256k1::Message::from_slice(digest.as_bytes()).unwrap();
         let sig = SECP256K1_ENGINE.sign(&m, &self.0);
         Ok(FixedSignature::from_bytes(&sig.serialize_compact(&SECP256K1_ENGINE)[..]).unwrap())
     }
 }
 
+impl<D> DigestSigner<D, RecoverableSignature> for EcdsaSigner
+where
+    D: Digest<OutputSize = U32> + Default,
+{
+    /// Compute a recoverable signature of the given 32-byte SHA-256 digest
+    fn sign(&self, digest: D) -> Result<RecoverableSignature, Error> {
+        let m = secp256k1::Message::from_slice(digest.result().as_slice()).unwrap();
+        let sig = SECP256K1_ENGINE.sign_recoverable(&m, &self.0);
+        let (recovery_id, bytes) = sig.serialize_compact(&SECP256K1_ENGINE);
+        let fixed_signature = FixedSignature::from_bytes(&bytes[..]).unwrap();
+        RecoverableSignature::new(&fixed_signature, recovery_id.to_i32() as u8)
+    }
+}
+
 /// ECDSA verifier provider for the secp256k1 crate
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EcdsaVerifier(secp256k1::key::PublicKey);
@@ -108,12 +153,22 @@ where
     D: Digest<OutputSize = U32> + Default,
 {
     fn verify(&self, digest: D, signature: &Asn1Signature) -> Result<(), Error> {
+        self.verify_raw_digest(&MessageDigest::from_digest(digest), signature)
+    }
+}
+
+impl RawDigestVerifier<Secp256k1, Asn1Signature> for EcdsaVerifier {
+    fn verify_raw_digest(
+        &self,
+        digest: &MessageDigest<Secp256k1>,
+        signature: &Asn1Signature,
+    ) -> Result<(), Error> {
         let sig = secp256k1::Signature::from_der(&SECP256K1_ENGINE, signature.as_slice())
             .map_err(|e| err!(SignatureInvalid, e))?;
 
         SECP256K1_ENGINE
             .verify(
-                &secp256k1::Message::from_slice(digest.result().as_slice()).unwrap(),
+                &secp256k1::Message::from_slice(digest.as_bytes()).unwrap(),
                 &sig,
                 &self.0,
             ).map_err(|e| err!(SignatureInvalid, e))
@@ -125,30 +180,130 @@ where
     D: Digest<OutputSize = U32> + Default,
 {
     fn verify(&self, digest: D, signature: &FixedSignature) -> Result<(), Error> {
+        self.verify_raw_digest(&MessageDigest::from_digest(digest), signature)
+    }
+}
+
+impl RawDigestVerifier<Secp256k1, FixedSignature> for EcdsaVerifier {
+    fn verify_raw_digest(
+        &self,
+        digest: &MessageDigest<Secp256k1>,
+        signature: &FixedSignature,
+    ) -> Result<(), Error> {
         let sig =
             secp256k1::Signature::from_compact(&SECP256K1_ENGINE, signature.as_slice()).unwrap();
 
         SECP256K1_ENGINE
             .verify(
-                &secp256k1::Message::from_slice(digest.result().as_slice()).unwrap(),
+                &secp256k1::Message::from_slice(digest.as_bytes()).unwrap(),
                 &sig,
                 &self.0,
             ).map_err(|e| err!(SignatureInvalid, e))
     }
 }
 
+impl<D> DigestRecoverer<D, Secp256k1> for EcdsaVerifier
+where
+    D: Digest<OutputSize = U32> + Default,
+{
+    fn recover(digest: D, signature: &RecoverableSignature) -> Result<PublicKey, Error> {
+        let recovery_id = secp256k1::RecoveryId::from_i32(i32::from(signature.recovery_id()))
+            .map_err(|e| err!(SignatureInvalid, e))?;
+
+        let sig = secp256k1::RecoverableSignature::from_compact(
+            &SECP256K1_ENGINE,
+            signature.to_fixed_signature().as_slice(),
+            recovery_id,
+        ).map_err(|e| err!(SignatureInvalid, e))?;
+
+        let public_key = SECP256K1_ENGINE
+            .recover(
+                &secp256k1::Message::from_slice(digest.result().as_slice()).unwrap(),
+                &sig,
+            ).map_err(|e| err!(SignatureInvalid, e))?;
+
+        PublicKey::from_bytes(&public_key.serialize()[..])
+    }
+}
+
+impl BundleVerifier for EcdsaVerifier {
+    fn verify_secp256k1(
+        public_key: &PublicKey,
+        digest: &MessageDigest<Secp256k1>,
+        signature: &[u8],
+        format: SignatureFormat,
+    ) -> Result<(), Error> {
+        ecdsa::verify_signature_bytes::<Self, _>(public_key, digest, signature, format)
+    }
+}
+
+impl PublicKeyValidator<Secp256k1> for EcdsaVerifier {
+    /// Validate the public key by parsing it with libsecp256k1
+    fn validate_public_key(public_key: &PublicKey) -> Result<(), Error> {
+        secp256k1::key::PublicKey::from_slice(&SECP256K1_ENGINE, public_key.as_bytes())
+            .map(|_| ())
+            .map_err(|e| err!(KeyInvalid, e))
+    }
+}
+
+#[cfg(feature = "unstable-provider-internals")]
+impl RawProvider for EcdsaSigner {
+    type Handle = secp256k1::key::SecretKey;
+
+    #[allow(unsafe_code)]
+    unsafe fn raw_handle(&self) -> &secp256k1::key::SecretKey {
+        &self.0
+    }
+}
+
+#[cfg(feature = "unstable-provider-internals")]
+impl RawProvider for EcdsaVerifier {
+    type Handle = secp256k1::key::PublicKey;
+
+    #[allow(unsafe_code)]
+    unsafe fn raw_handle(&self) -> &secp256k1::key::PublicKey {
+        &self.0
+    }
+}
+
 // TODO: test against actual test vectors, rather than just checking if signatures roundtrip
 #[cfg(test)]
 mod tests {
     use super::{EcdsaSigner, EcdsaVerifier};
+    use sha2::Sha224;
+    use sha3::Keccak256;
     use signatory::{
         self,
-        curve::secp256k1::{
-            Asn1Signature, FixedSignature, PublicKey, SecretKey, SHA256_FIXED_SIZE_TEST_VECTORS,
+        curve::{
+            nistp256,
+            secp256k1::{
+                Asn1Signature, FixedSignature, PublicKey, RecoverableSignature, SecretKey,
+                SHA256_FIXED_SIZE_TEST_VECTORS,
+            },
+            WeierstrassCurveKind,
         },
-        PublicKeyed, Sha256Verifier, Signature,
+        digest::{Digest, Input},
+        ecdsa::{self, MessageDigest, PublicKeyBundle, RawDigestSigner, SignatureFormat},
+        DigestSigner, DigestVerifier, ErrorKind, PublicKeyed, Sha256Verifier, Signature,
     };
 
+    /// Signer for the first test vector, the Keccak-256 digest of its message,
+    /// and the signer's signature of that digest
+    fn keccak256_fixture<S>() -> (EcdsaSigner, Keccak256, S)
+    where
+        S: Signature,
+        EcdsaSigner: DigestSigner<Keccak256, S>,
+    {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+
+        let mut digest = Keccak256::default();
+        digest.process(vector.msg);
+
+        let signature = signatory::sign_digest(&signer, digest.clone()).unwrap();
+        (signer, digest, signature)
+    }
+
     #[test]
     pub fn asn1_signature_roundtrip() {
         let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
@@ -197,6 +352,174 @@ mod tests {
         }
     }
 
+    #[test]
+    pub fn recoverable_signature_vectors() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+            let signature: RecoverableSignature =
+                signatory::sign_sha256(&signer, vector.msg).unwrap();
+
+            assert_eq!(signature.to_fixed_signature().as_ref(), vector.sig);
+        }
+    }
+
+    #[test]
+    pub fn validate_public_key() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let public_key = PublicKey::from_bytes(vector.pk).unwrap();
+            assert!(public_key.validate_with::<EcdsaVerifier>().is_ok());
+        }
+
+        // There's no secp256k1 point with `x = 5`, as `5^3 + 7` isn't a square
+        let mut compressed_point = [0u8; 33];
+        compressed_point[0] = 0x02;
+        compressed_point[32] = 5;
+        let not_on_curve = PublicKey::from_bytes(&compressed_point[..]).unwrap();
+        assert_eq!(
+            not_on_curve
+                .validate_with::<EcdsaVerifier>()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::KeyInvalid
+        );
+    }
+
+    /// Is `signature` a signature of `digest` by `trusted_key`? The key
+    /// returned by `verify_and_recover` must be compared against it, as
+    /// recovery alone succeeds for any digest.
+    fn recovers_key(
+        signature: &RecoverableSignature,
+        digest: Keccak256,
+        trusted_key: &PublicKey,
+    ) -> bool {
+        signature
+            .verify_and_recover::<EcdsaVerifier, _>(digest)
+            .map(|public_key| public_key == *trusted_key)
+            .unwrap_or(false)
+    }
+
+    #[test]
+    pub fn verify_and_recover() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+            let mut digest = Keccak256::default();
+            digest.process(vector.msg);
+
+            let signature: RecoverableSignature =
+                signatory::sign_digest(&signer, digest.clone()).unwrap();
+
+            assert!(recovers_key(
+                &signature,
+                digest,
+                &signer.public_key().unwrap()
+            ));
+        }
+
+        // A signature of a different message doesn't verify: recovery yields
+        // some key other than the signer's
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let signature: RecoverableSignature = signatory::sign_sha256(&signer, vector.msg).unwrap();
+
+        let mut digest = Keccak256::default();
+        digest.process(vector.msg);
+        assert!(!recovers_key(
+            &signature,
+            digest,
+            &signer.public_key().unwrap()
+        ));
+    }
+
+    #[test]
+    pub fn public_key_verify_digest() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let mut digest = Keccak256::default();
+        digest.process(vector.msg);
+
+        let signature: FixedSignature = signatory::sign_digest(&signer, digest.clone()).unwrap();
+
+        let public_key = signer.public_key().unwrap();
+        assert!(public_key
+            .verify_digest::<EcdsaVerifier, _, _>(digest, &signature)
+            .is_ok());
+    }
+
+    #[test]
+    pub fn verify_by_oid() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let verifier = EcdsaVerifier::from(&PublicKey::from_bytes(vector.pk).unwrap());
+        let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+
+        let verifiers = ecdsa::OidVerifiers {
+            sha256: Some(&verifier),
+            ..Default::default()
+        };
+
+        assert!(ecdsa::verify_by_oid(
+            &verifiers,
+            ecdsa::ECDSA_WITH_SHA256_OID,
+            vector.msg,
+            &signature
+        )
+        .is_ok());
+
+        for oid in &[ecdsa::ECDSA_WITH_SHA384_OID, &b"\x2a\x03"[..]] {
+            let err = ecdsa::verify_by_oid(&verifiers, oid, vector.msg, &signature)
+                .err()
+                .unwrap();
+            assert_eq!(err.kind(), ErrorKind::Unsupported);
+        }
+    }
+
+    #[test]
+    pub fn verify_digest_with_options() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let mut digest = Keccak256::default();
+        digest.process(vector.msg);
+
+        let signature: FixedSignature = signatory::sign_digest(&signer, digest.clone()).unwrap();
+        let verifier = EcdsaVerifier::from(&signer.public_key().unwrap());
+        let strict = ecdsa::VerifyOptions {
+            require_low_s: true,
+            strict_der: true,
+            reject_zero_scalars: true,
+        };
+
+        let der = Asn1Signature::from(&signature).into_vec();
+        for options in &[ecdsa::VerifyOptions::default(), strict] {
+            assert!(
+                ecdsa::verify_digest_with_options(&verifier, digest.clone(), &der, options).is_ok()
+            );
+        }
+
+        // Re-encode `r` as non-strict DER: without its leading zero byte if it
+        // has one (i.e. as a negative INTEGER), otherwise with a superfluous one
+        let mut lax_der = if der[4] == 0 {
+            vec![0x30, der[1] - 1, 0x02, der[3] - 1]
+        } else {
+            vec![0x30, der[1] + 1, 0x02, der[3] + 1, 0x00]
+        };
+        lax_der.extend_from_slice(&der[if der[4] == 0 { 5 } else { 4 }..]);
+
+        let default_options = ecdsa::VerifyOptions::default();
+        assert!(ecdsa::verify_digest_with_options(
+            &verifier,
+            digest.clone(),
+            &lax_der,
+            &default_options
+        )
+        .is_ok());
+
+        let err = ecdsa::verify_digest_with_options(&verifier, digest, &lax_der, &strict)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
     #[test]
     pub fn rejects_tweaked_fixed_signature() {
         let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
@@ -217,4 +540,323 @@ mod tests {
             "expected bad signature to cause validation error!"
         );
     }
+
+    #[test]
+    pub fn keccak256_fixed_signature_roundtrip() {
+        let (signer, digest, signature) = keccak256_fixture::<FixedSignature>();
+
+        let verifier = EcdsaVerifier::from(&signer.public_key().unwrap());
+        assert!(verifier.verify(digest, &signature).is_ok());
+    }
+
+    #[test]
+    pub fn keccak256_asn1_signature_roundtrip() {
+        let (signer, digest, signature) = keccak256_fixture::<Asn1Signature>();
+
+        let verifier = EcdsaVerifier::from(&signer.public_key().unwrap());
+        assert!(verifier.verify(digest, &signature).is_ok());
+    }
+
+    /// Known-answer test from the Ethereum transaction signing example in
+    /// EIP-155: signing the Keccak-256 digest of the RLP encoded transaction
+    /// with the private key `0x4646...46` yields the published `r`, `s`, and
+    /// `v = 37` (i.e. recovery ID 0 for chain ID 1)
+    #[test]
+    pub fn keccak256_ethereum_known_answer() {
+        let signing_data = b"\xec\x09\x85\x04\xa8\x17\xc8\x00\x82\x52\x08\x94\x35\x35\x35\x35\
+                             \x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\
+                             \x88\x0d\xe0\xb6\xb3\xa7\x64\x00\x00\x80\x01\x80\x80";
+
+        let expected_signature = b"\x28\xef\x61\x34\x0b\xd9\x39\xbc\x21\x95\xfe\x53\x75\x67\x86\x60\
+                                   \x03\xe1\xa1\x5d\x3c\x71\xff\x63\xe1\x59\x06\x20\xaa\x63\x62\x76\
+                                   \x67\xcb\xe9\xd8\x99\x7f\x76\x1a\xec\xb7\x03\x30\x4b\x38\x00\xcc\
+                                   \xf5\x55\xc9\xf3\xdc\x64\x21\x4b\x29\x7f\xb1\x96\x6a\x3b\x6d\x83";
+
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(&[0x46; 32][..]).unwrap());
+        let mut digest = Keccak256::default();
+        digest.process(signing_data);
+
+        let signature: FixedSignature = signatory::sign_digest(&signer, digest.clone()).unwrap();
+        assert_eq!(signature.as_ref(), &expected_signature[..]);
+
+        let recoverable_signature: RecoverableSignature =
+            signatory::sign_digest(&signer, digest.clone()).unwrap();
+        assert_eq!(recoverable_signature.recovery_id(), 0);
+
+        let verifier = EcdsaVerifier::from(&signer.public_key().unwrap());
+        assert!(verifier.verify(digest, &signature).is_ok());
+    }
+
+    #[test]
+    pub fn keccak256_verify_raw_digest() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let mut digest = Keccak256::default();
+        digest.process(vector.msg);
+
+        let signature: Asn1Signature = signatory::sign_digest(&signer, digest).unwrap();
+        let verifier = EcdsaVerifier::from(&signer.public_key().unwrap());
+
+        let message_digest = MessageDigest::from_message::<Keccak256>(vector.msg);
+        assert!(ecdsa::verify_raw_digest(&verifier, &message_digest, &signature).is_ok());
+
+        let wrong_digest = MessageDigest::from_message::<Keccak256>(&vector.msg[1..]);
+        assert!(ecdsa::verify_raw_digest(&verifier, &wrong_digest, &signature).is_err());
+    }
+
+    #[test]
+    pub fn keccak256_sign_message() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let verifier = EcdsaVerifier::from(&signer.public_key().unwrap());
+        let message_digest = MessageDigest::from_message::<Keccak256>(vector.msg);
+
+        let signature: FixedSignature = signer.sign_message::<Keccak256>(vector.msg).unwrap();
+        assert!(ecdsa::verify_raw_digest(&verifier, &message_digest, &signature).is_ok());
+
+        let signature = ecdsa::sign_message::<Keccak256, _>(&signer, vector.msg).unwrap();
+        assert!(ecdsa::verify_raw_digest(&verifier, &message_digest, &signature).is_ok());
+
+        let signature = ecdsa::sign_message_asn1::<Keccak256, _>(&signer, vector.msg).unwrap();
+        assert!(ecdsa::verify_raw_digest(&verifier, &message_digest, &signature).is_ok());
+
+        let wrong_digest = MessageDigest::from_message::<Keccak256>(&vector.msg[1..]);
+        assert!(ecdsa::verify_raw_digest(&verifier, &wrong_digest, &signature).is_err());
+    }
+
+    #[test]
+    pub fn sign_digest_batch() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let verifier = EcdsaVerifier::from(&signer.public_key().unwrap());
+
+        let digests: Vec<_> = (0..4)
+            .map(|i| MessageDigest::from_message::<Keccak256>(&vector.msg[i..]))
+            .collect();
+
+        let signatures: Vec<FixedSignature> = ecdsa::sign_digest_batch(&signer, &digests).unwrap();
+        assert_eq!(signatures.len(), digests.len());
+
+        for (digest, signature) in digests.iter().zip(&signatures) {
+            assert!(ecdsa::verify_raw_digest(&verifier, digest, signature).is_ok());
+
+            // Signing is deterministic, so batches match signing one at a time
+            let expected: FixedSignature = ecdsa::sign_raw_digest(&signer, digest).unwrap();
+            assert_eq!(signature, &expected);
+        }
+
+        let empty: Vec<FixedSignature> = ecdsa::sign_digest_batch(&signer, &[]).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    pub fn keccak256_verify_public_key_bundle() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let mut digest = Keccak256::default();
+        digest.process(vector.msg);
+        let digest_bytes = digest.clone().result();
+
+        let asn1_signature: Asn1Signature =
+            signatory::sign_digest(&signer, digest.clone()).unwrap();
+        let fixed_signature: FixedSignature = signatory::sign_digest(&signer, digest).unwrap();
+
+        let public_key = signer.public_key().unwrap();
+        let bundle =
+            PublicKeyBundle::from_bytes(WeierstrassCurveKind::Secp256k1, public_key.as_bytes())
+                .unwrap();
+        assert_eq!(bundle, PublicKeyBundle::from(public_key));
+
+        for &(format, sig_bytes) in &[
+            (SignatureFormat::Asn1, asn1_signature.as_slice()),
+            (SignatureFormat::Fixed, fixed_signature.as_slice()),
+        ] {
+            assert!(bundle
+                .verify::<EcdsaVerifier>(&digest_bytes, sig_bytes, format)
+                .is_ok());
+
+            let err = bundle
+                .verify::<EcdsaVerifier>(&digest_bytes[1..], sig_bytes, format)
+                .err()
+                .unwrap();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+
+        let err = bundle
+            .verify::<EcdsaVerifier>(
+                &digest_bytes,
+                asn1_signature.as_slice(),
+                SignatureFormat::Fixed,
+            )
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+
+        // P-256 isn't supported by this provider
+        let mut p256_key = vec![0x04];
+        p256_key.extend_from_slice(nistp256::SHA256_FIXED_SIZE_TEST_VECTORS[0].pk);
+        let p256_bundle =
+            PublicKeyBundle::from_bytes(WeierstrassCurveKind::NistP256, &p256_key).unwrap();
+        let err = p256_bundle
+            .verify::<EcdsaVerifier>(
+                &digest_bytes,
+                fixed_signature.as_slice(),
+                SignatureFormat::Fixed,
+            )
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    #[cfg(feature = "unstable-provider-internals")]
+    #[test]
+    pub fn raw_provider_handles() {
+        use signatory::RawProvider;
+
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let public_key = signer.public_key().unwrap();
+        let verifier = EcdsaVerifier::from(&public_key);
+
+        #[allow(unsafe_code)]
+        unsafe {
+            assert_eq!(&signer.raw_handle()[..], vector.sk);
+            assert_eq!(
+                &verifier.raw_handle().serialize()[..],
+                public_key.as_bytes()
+            );
+        }
+    }
+
+    #[test]
+    pub fn verify_cert_signature() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let tbs_bytes = vector.msg;
+
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let issuer_key = signer.public_key().unwrap();
+        let signature: Asn1Signature = signatory::sign_sha256(&signer, tbs_bytes).unwrap();
+
+        let verify = |algorithm_oid: &[u8], signature_der: &[u8]| {
+            ecdsa::verify_cert_signature::<EcdsaVerifier, _>(
+                tbs_bytes,
+                algorithm_oid,
+                signature_der,
+                &issuer_key,
+            )
+        };
+
+        assert!(verify(ecdsa::ECDSA_WITH_SHA256_OID, signature.as_ref()).is_ok());
+
+        // Signatures are checked against the digest named by the OID
+        let err = verify(ecdsa::ECDSA_WITH_SHA384_OID, signature.as_ref())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+
+        let err = verify(ecdsa::ID_EC_PUBLIC_KEY_OID, signature.as_ref())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        let err = verify(ecdsa::ECDSA_WITH_SHA256_OID, &signature.as_ref()[1..])
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+
+        let mut sha224 = Sha224::default();
+        sha224.process(tbs_bytes);
+        let signature: Asn1Signature = signer
+            .sign_raw_digest(&MessageDigest::from_digest(sha224))
+            .unwrap();
+        assert!(verify(ecdsa::ECDSA_WITH_SHA224_OID, signature.as_ref()).is_ok());
+    }
+
+    #[test]
+    pub fn verify_cms_signer_info() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+
+        // `signedAttrs` containing a single `content-type` attribute of `id-data`
+        let signed_attrs = b"\xa0\x18\x30\x16\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x09\x03\
+                             \x31\x09\x06\x07\x2a\x86\x48\x86\xf7\x0d\x01";
+
+        // The signature covers `signedAttrs` re-tagged as a `SET OF`
+        let mut set_of_attrs = signed_attrs.to_vec();
+        set_of_attrs[0] = 0x31;
+
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let signer_key = signer.public_key().unwrap();
+        let signature: Asn1Signature = signatory::sign_sha256(&signer, &set_of_attrs).unwrap();
+
+        let verify = |signed_attrs: &[u8], digest_oid: &[u8]| {
+            ecdsa::verify_cms_signer_info::<EcdsaVerifier, _>(
+                signed_attrs,
+                digest_oid,
+                signature.as_ref(),
+                &signer_key,
+            )
+        };
+
+        assert!(verify(signed_attrs, ecdsa::ID_SHA256_OID).is_ok());
+        assert!(verify(&set_of_attrs, ecdsa::ID_SHA256_OID).is_ok());
+
+        let err = verify(signed_attrs, ecdsa::ID_SHA384_OID).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+
+        let err = verify(signed_attrs, ecdsa::ECDSA_WITH_SHA256_OID).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        // Missing tag, truncated, and trailing data
+        for bad in &[
+            signed_attrs[2..].to_vec(),
+            signed_attrs[..signed_attrs.len() - 1].to_vec(),
+            [&signed_attrs[..], b"\x00"].concat(),
+        ] {
+            let err = verify(bad, ecdsa::ID_SHA256_OID).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+
+        let mut sha224 = Sha224::default();
+        sha224.process(&set_of_attrs);
+        let signature: Asn1Signature = signer
+            .sign_raw_digest(&MessageDigest::from_digest(sha224))
+            .unwrap();
+        assert!(ecdsa::verify_cms_signer_info::<EcdsaVerifier, _>(
+            signed_attrs,
+            ecdsa::ID_SHA224_OID,
+            signature.as_ref(),
+            &signer_key
+        )
+        .is_ok());
+    }
+
+    #[test]
+    pub fn keccak256_verify_prehashed_scalar() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+
+        let signer = EcdsaSigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let mut digest = Keccak256::default();
+        digest.process(vector.msg);
+
+        let signature: FixedSignature = signatory::sign_digest(&signer, digest.clone()).unwrap();
+        let public_key = signer.public_key().unwrap();
+
+        let z = MessageDigest::from_prehashed_scalar_unchecked(digest.result());
+        let result =
+            ecdsa::verify_prehashed_scalar::<EcdsaVerifier, _, _>(&public_key, &z, &signature);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    pub fn rejects_sha256_signature_as_keccak256() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+
+        let (signer, digest, _) = keccak256_fixture::<FixedSignature>();
+        let signature: FixedSignature = signatory::sign_sha256(&signer, vector.msg).unwrap();
+
+        let verifier = EcdsaVerifier::from(&signer.public_key().unwrap());
+        assert!(verifier.verify(digest, &signature).is_err());
+    }
 }
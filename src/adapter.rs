@@ -0,0 +1,125 @@
+//! Adapters for the `signature` crate's `Signer` and `Verifier` traits
+//!
+//! Enable Signatory's `digest` and `signature` cargo features to enable
+//! these adapters.
+
+use core::marker::PhantomData;
+use digest::Digest;
+use signature_crate;
+
+use signer::DigestSigner;
+use verifier::DigestVerifier;
+use Signature;
+
+/// Adapter which impls the `signature` crate's `Signer` and `Verifier` traits
+/// for a `DigestSigner` or `DigestVerifier`, computing the digest of messages
+/// using the digest function `D`.
+///
+/// This allows Signatory providers to be used with libraries which expect
+/// the `signature` crate's traits.
+pub struct DigestAdapter<T, D> {
+    /// `DigestSigner` or `DigestVerifier` being adapted
+    inner: T,
+
+    /// Placeholder for digest function type
+    digest: PhantomData<D>,
+}
+
+impl<T, D> DigestAdapter<T, D>
+where
+    D: Digest,
+{
+    /// Adapt the given `DigestSigner` or `DigestVerifier`
+    pub fn new(inner: T) -> Self {
+        DigestAdapter {
+            inner,
+            digest: PhantomData,
+        }
+    }
+
+    /// Borrow the inner `DigestSigner` or `DigestVerifier`
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwrap the inner `DigestSigner` or `DigestVerifier`
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T, D, S> signature_crate::Signer<S> for DigestAdapter<T, D>
+where
+    T: DigestSigner<D, S>,
+    D: Digest,
+    S: Signature,
+{
+    fn try_sign(&self, msg: &[u8]) -> Result<S, signature_crate::Error> {
+        let mut digest = D::default();
+        digest.input(msg);
+
+        self.inner
+            .sign(digest)
+            .map_err(|_| signature_crate::Error::new())
+    }
+}
+
+impl<T, D, S> signature_crate::Verifier<S> for DigestAdapter<T, D>
+where
+    T: DigestVerifier<D, S>,
+    D: Digest,
+    S: Signature,
+{
+    fn verify(&self, msg: &[u8], signature: &S) -> Result<(), signature_crate::Error> {
+        let mut digest = D::default();
+        digest.input(msg);
+
+        self.inner
+            .verify(digest, signature)
+            .map_err(|_| signature_crate::Error::new())
+    }
+}
+
+#[cfg(all(test, feature = "ecdsa", feature = "sha2"))]
+mod tests {
+    use super::DigestAdapter;
+    use curve::nistp256::FixedSignature;
+    use digest::Digest;
+    use error::Error;
+    use sha2::Sha512;
+    use signature_crate::{Signer, Verifier};
+    use signer::DigestSigner;
+    use verifier::DigestVerifier;
+    use Signature;
+
+    /// Toy "signer" whose signatures are just SHA-512 digests (which happen
+    /// to be the size of a P-256 fixed-width signature)
+    struct ToySigner;
+
+    impl DigestSigner<Sha512, FixedSignature> for ToySigner {
+        fn sign(&self, digest: Sha512) -> Result<FixedSignature, Error> {
+            FixedSignature::from_bytes(digest.result().as_slice())
+        }
+    }
+
+    impl DigestVerifier<Sha512, FixedSignature> for ToySigner {
+        fn verify(&self, digest: Sha512, signature: &FixedSignature) -> Result<(), Error> {
+            ensure!(
+                digest.result().as_slice() == signature.as_ref(),
+                SignatureInvalid,
+                "bad signature"
+            );
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_digest_adapter() {
+        let adapter = DigestAdapter::<_, Sha512>::new(ToySigner);
+        let signature: FixedSignature = adapter.try_sign(b"hello").unwrap();
+        assert_eq!(signature.as_ref(), Sha512::digest(b"hello").as_slice());
+
+        assert!(adapter.verify(b"hello", &signature).is_ok());
+        assert!(adapter.verify(b"goodbye", &signature).is_err());
+    }
+}
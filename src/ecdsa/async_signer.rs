@@ -0,0 +1,128 @@
+//! Asynchronous ECDSA signing, e.g. for signers backed by network services
+//! such as cloud HSMs or remote signers.
+//!
+//! Enable Signatory's `async` cargo feature to enable this trait.
+
+use core::future::{self, Future, Ready};
+use digest::Digest;
+
+use super::Asn1Signature;
+use curve::WeierstrassCurve;
+use error::Error;
+use signer::DigestSigner;
+
+/// Trait for signers which produce ASN.1 DER signatures of a prehashed
+/// `Digest` asynchronously, so signing with e.g. a networked HSM doesn't
+/// block the calling thread.
+///
+/// The synchronous signing traits are unaffected: local providers can be
+/// used wherever an `AsyncDigestSigner` is expected by wrapping them in a
+/// `SyncSigner`.
+pub trait AsyncDigestSigner<C, D>: Send + Sync
+where
+    C: WeierstrassCurve,
+    D: Digest,
+{
+    /// Future which resolves to the signature
+    type Future: Future<Output = Result<Asn1Signature<C>, Error>> + Send;
+
+    /// Sign the output of the given digest with this signer's private key,
+    /// returning a future which resolves to an ASN.1 DER signature.
+    fn sign_digest_asn1_signature(&self, digest: D) -> Self::Future;
+}
+
+/// Adapter which impls `AsyncDigestSigner` for a synchronous `DigestSigner`
+/// (e.g. a local provider), signing as soon as it's called and returning
+/// an already resolved future.
+pub struct SyncSigner<T> {
+    /// `DigestSigner` being adapted
+    inner: T,
+}
+
+impl<T> SyncSigner<T> {
+    /// Adapt the given `DigestSigner`
+    pub fn new(inner: T) -> Self {
+        SyncSigner { inner }
+    }
+
+    /// Borrow the inner `DigestSigner`
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwrap the inner `DigestSigner`
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T, C, D> AsyncDigestSigner<C, D> for SyncSigner<T>
+where
+    T: DigestSigner<D, Asn1Signature<C>>,
+    C: WeierstrassCurve,
+    D: Digest,
+    Asn1Signature<C>: Send,
+{
+    type Future = Ready<Result<Asn1Signature<C>, Error>>;
+
+    fn sign_digest_asn1_signature(&self, digest: D) -> Self::Future {
+        future::ready(self.inner.sign(digest))
+    }
+}
+
+#[cfg(all(test, feature = "sha2", feature = "test-vectors"))]
+mod tests {
+    use core::{
+        future::Future,
+        pin::Pin,
+        ptr,
+        task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    };
+    use sha2::Sha256;
+
+    use super::{AsyncDigestSigner, SyncSigner};
+    use curve::nistp256::{Asn1Signature, FixedSignature, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use error::Error;
+    use signer::DigestSigner;
+    use Signature;
+
+    /// Signer which always returns the first test vector's signature
+    struct TestVectorSigner;
+
+    impl DigestSigner<Sha256, Asn1Signature> for TestVectorSigner {
+        fn sign(&self, _digest: Sha256) -> Result<Asn1Signature, Error> {
+            let fixed_signature =
+                FixedSignature::from_bytes(SHA256_FIXED_SIZE_TEST_VECTORS[0].sig)?;
+            Ok(Asn1Signature::from(&fixed_signature))
+        }
+    }
+
+    /// Poll a future which is expected to be ready immediately
+    fn poll_ready<F: Future + Unpin>(mut future: F) -> F::Output {
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(ptr::null(), &VTABLE)
+        }
+
+        fn noop(_: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        // Sound as the vtable functions never touch the (null) data pointer
+        #[allow(unsafe_code)]
+        let waker = unsafe { Waker::from_raw(clone(ptr::null())) };
+
+        match Pin::new(&mut future).poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future wasn't ready"),
+        }
+    }
+
+    #[test]
+    fn test_sync_signer() {
+        let signer = SyncSigner::new(TestVectorSigner);
+        let future = signer.sign_digest_asn1_signature(Sha256::default());
+
+        let expected = TestVectorSigner.sign(Sha256::default()).unwrap();
+        assert_eq!(poll_ready(future).unwrap(), expected);
+    }
+}
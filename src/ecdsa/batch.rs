@@ -0,0 +1,389 @@
+//! Verification of batches of ECDSA signatures, reporting a result for each
+//! individual signature in the batch
+
+use digest::Digest;
+
+use super::{FixedSignature, PublicKey};
+use curve::WeierstrassCurve;
+use error::Error;
+#[allow(unused_imports)]
+use prelude::*;
+use verifier::DigestVerifier;
+
+/// Maximum number of entries in a batch passed to `verify_batch_bitmap`
+pub const MAX_BATCH_BITMAP_SIZE: usize = 64;
+
+/// Verify a batch of (public key, digest, signature) entries with the
+/// provider's verifier type `V`, returning a bitmap where bit `i` is set if
+/// entry `i` verified successfully, without allocating.
+///
+/// Batches may contain at most `MAX_BATCH_BITMAP_SIZE` (64) entries. Larger
+/// batches are rejected with `ErrorKind::Unsupported`: use `verify_batch` for
+/// those instead. Each signature is verified individually.
+pub fn verify_batch_bitmap<V, D, C>(
+    entries: &[(PublicKey<C>, D, FixedSignature<C>)],
+) -> Result<u64, Error>
+where
+    V: DigestVerifier<D, FixedSignature<C>> + for<'a> From<&'a PublicKey<C>>,
+    D: Digest + Clone,
+    C: WeierstrassCurve,
+{
+    ensure!(
+        entries.len() <= MAX_BATCH_BITMAP_SIZE,
+        Unsupported,
+        "batch too large for bitmap: {} entries (max {})",
+        entries.len(),
+        MAX_BATCH_BITMAP_SIZE
+    );
+
+    let mut bitmap = 0u64;
+
+    for (i, &(ref public_key, ref digest, ref signature)) in entries.iter().enumerate() {
+        let verifier = V::from(public_key);
+
+        if verifier.verify(digest.clone(), signature).is_ok() {
+            bitmap |= 1 << i;
+        }
+    }
+
+    Ok(bitmap)
+}
+
+/// Verify a batch of (public key, digest, signature) entries of any size with
+/// the provider's verifier type `V`, returning whether each entry verified
+/// successfully.
+#[cfg(feature = "alloc")]
+pub fn verify_batch<V, D, C>(entries: &[(PublicKey<C>, D, FixedSignature<C>)]) -> Vec<bool>
+where
+    V: DigestVerifier<D, FixedSignature<C>> + for<'a> From<&'a PublicKey<C>>,
+    D: Digest + Clone,
+    C: WeierstrassCurve,
+{
+    entries
+        .iter()
+        .map(|&(ref public_key, ref digest, ref signature)| {
+            let verifier = V::from(public_key);
+            verifier.verify(digest.clone(), signature).is_ok()
+        })
+        .collect()
+}
+
+/// One step of a chain of signatures (e.g. a certificate in a certificate
+/// chain, or a part of a multi-part signed document) to be verified with
+/// `verify_chain_collecting`
+#[derive(Clone, Debug)]
+pub struct VerifyStep<C: WeierstrassCurve, D: Digest> {
+    /// Public key the signature should verify under
+    pub public_key: PublicKey<C>,
+
+    /// Digest of the signed message
+    pub digest: D,
+
+    /// Signature to verify
+    pub signature: FixedSignature<C>,
+}
+
+/// Verify each step of a chain of signatures independently with the
+/// provider's verifier type `V`, returning the result of every step (in the
+/// same order) rather than stopping at the first failure.
+///
+/// This is for reporting, e.g. producing a complete diagnostic of which
+/// steps failed and why: a chain is only valid if every result is `Ok`.
+#[cfg(feature = "alloc")]
+pub fn verify_chain_collecting<V, D, C>(steps: &[VerifyStep<C, D>]) -> Vec<Result<(), Error>>
+where
+    V: DigestVerifier<D, FixedSignature<C>> + for<'a> From<&'a PublicKey<C>>,
+    D: Digest + Clone,
+    C: WeierstrassCurve,
+{
+    steps
+        .iter()
+        .map(|step| V::from(&step.public_key).verify(step.digest.clone(), &step.signature))
+        .collect()
+}
+
+/// Verify signatures of the given digest from a set of (public key,
+/// signature) pairs with the provider's verifier type `V`, succeeding if the
+/// signatures from at least `threshold` distinct keys are valid (e.g. for
+/// M-of-N multisig).
+///
+/// Each key is only counted once, however many valid signatures it has (keys
+/// are compared with `PublicKey::same_point`). If the threshold isn't met, a
+/// `SignatureInvalid` error reports how many keys had valid signatures. A
+/// `threshold` of zero is rejected as `Unsupported` rather than trivially met.
+pub fn verify_threshold<V, D, C>(
+    keys_and_sigs: &[(PublicKey<C>, FixedSignature<C>)],
+    digest: D,
+    threshold: usize,
+) -> Result<(), Error>
+where
+    V: DigestVerifier<D, FixedSignature<C>> + for<'a> From<&'a PublicKey<C>>,
+    D: Digest + Clone,
+    C: WeierstrassCurve,
+{
+    ensure!(threshold > 0, Unsupported, "threshold must be at least 1");
+
+    let mut valid_keys = 0;
+
+    for (i, &(ref public_key, _)) in keys_and_sigs.iter().enumerate() {
+        // Each distinct key is handled at its first occurrence, where all of
+        // its signatures are checked
+        if keys_and_sigs[..i]
+            .iter()
+            .any(|&(ref other_key, _)| other_key.same_point(public_key))
+        {
+            continue;
+        }
+
+        let verifier = V::from(public_key);
+
+        let is_valid = keys_and_sigs[i..]
+            .iter()
+            .filter(|&&(ref other_key, _)| other_key.same_point(public_key))
+            .any(|&(_, ref signature)| verifier.verify(digest.clone(), signature).is_ok());
+
+        if is_valid {
+            valid_keys += 1;
+
+            if valid_keys >= threshold {
+                return Ok(());
+            }
+        }
+    }
+
+    fail!(
+        SignatureInvalid,
+        "threshold not met: valid signatures from {} of {} required keys",
+        valid_keys,
+        threshold
+    );
+}
+
+#[cfg(all(
+    test,
+    feature = "sha2",
+    feature = "std",
+    feature = "test-signer",
+    feature = "test-vectors"
+))]
+mod tests {
+    use digest::Digest;
+    use sha2::Sha256;
+
+    use super::{
+        verify_batch, verify_batch_bitmap, verify_chain_collecting, verify_threshold, VerifyStep,
+        MAX_BATCH_BITMAP_SIZE,
+    };
+    use curve::nistp256::{FixedSignature, PublicKey, SecretKey, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use ecdsa::public_key::tests::compressed_key;
+    use ecdsa::test_verifier::ResigningVerifier;
+    use ecdsa::MemorySigner;
+    use error::ErrorKind;
+    #[allow(unused_imports)]
+    use prelude::*;
+    use {DigestSigner, PublicKeyed, Signature};
+
+    /// SHA-256 digest of the given message
+    fn digest(msg: &[u8]) -> Sha256 {
+        let mut digest = Sha256::default();
+        digest.input(msg);
+        digest
+    }
+
+    /// Public key of the `i`th test vector, and its signature of `msg`
+    fn sign(i: usize, msg: &[u8]) -> (PublicKey, FixedSignature) {
+        let secret_key = SecretKey::from_bytes(SHA256_FIXED_SIZE_TEST_VECTORS[i].sk).unwrap();
+        let signer = MemorySigner::from(&secret_key);
+        (
+            signer.public_key().unwrap(),
+            signer.sign(digest(msg)).unwrap(),
+        )
+    }
+
+    /// Copy of the given signature with its last byte altered
+    fn tweak(signature: &FixedSignature) -> FixedSignature {
+        let mut bytes = signature.as_ref().to_vec();
+        *bytes.last_mut().unwrap() ^= 42;
+        FixedSignature::from_bytes(bytes).unwrap()
+    }
+
+    /// Entries with valid signatures at indexes 0 and 2 only
+    fn batch() -> Vec<(PublicKey, Sha256, FixedSignature)> {
+        let (key0, signature0) = sign(0, b"message 0");
+        let (key1, signature1) = sign(1, b"message 1");
+
+        vec![
+            (key0.clone(), digest(b"message 0"), signature0.clone()),
+            (key0.clone(), digest(b"message 0"), tweak(&signature0)),
+            (key1.clone(), digest(b"message 1"), signature1),
+            (key1, digest(b"message 0"), signature0),
+        ]
+    }
+
+    #[test]
+    fn test_verify_batch_bitmap_clears_bits_of_invalid_signatures() {
+        let bitmap = verify_batch_bitmap::<ResigningVerifier, _, _>(&batch()).unwrap();
+        assert_eq!(bitmap, 0b0101);
+
+        assert_eq!(
+            verify_batch_bitmap::<ResigningVerifier, Sha256, _>(&[]).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_verify_batch_bitmap_size_limit() {
+        let (public_key, signature) = sign(0, b"message");
+        let entry = (public_key, digest(b"message"), signature);
+
+        let entries = vec![entry; MAX_BATCH_BITMAP_SIZE + 1];
+        let bitmap =
+            verify_batch_bitmap::<ResigningVerifier, _, _>(&entries[..MAX_BATCH_BITMAP_SIZE]);
+        assert_eq!(bitmap.unwrap(), u64::max_value());
+
+        let err = verify_batch_bitmap::<ResigningVerifier, _, _>(&entries).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_verify_batch_reports_each_entry() {
+        let results = verify_batch::<ResigningVerifier, _, _>(&batch());
+        assert_eq!(results, vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn test_verify_chain_collecting_reports_every_failed_step() {
+        let steps: Vec<_> = (0..3)
+            .map(|i| {
+                let (public_key, signature) = sign(i, b"message");
+
+                VerifyStep {
+                    public_key,
+                    digest: digest(b"message"),
+                    signature,
+                }
+            })
+            .collect();
+
+        for result in verify_chain_collecting::<ResigningVerifier, _, _>(&steps) {
+            assert!(result.is_ok());
+        }
+
+        // Break the first and last steps by swapping their signatures
+        let mut broken_steps = steps.clone();
+        broken_steps[0].signature = steps[2].signature.clone();
+        broken_steps[2].signature = steps[0].signature.clone();
+
+        let results = verify_chain_collecting::<ResigningVerifier, _, _>(&broken_steps);
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap_err().kind(),
+            ErrorKind::SignatureInvalid
+        );
+        assert!(results[1].is_ok());
+        assert_eq!(
+            results[2].as_ref().unwrap_err().kind(),
+            ErrorKind::SignatureInvalid
+        );
+    }
+
+    #[test]
+    fn test_verify_threshold_met() {
+        let keys_and_sigs: Vec<_> = (0..3).map(|i| sign(i, b"message")).collect();
+
+        for threshold in 1..4 {
+            assert!(verify_threshold::<ResigningVerifier, _, _>(
+                &keys_and_sigs,
+                digest(b"message"),
+                threshold
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_verify_threshold_not_met() {
+        let keys_and_sigs: Vec<_> = (0..3).map(|i| sign(i, b"message")).collect();
+        let verify = |keys_and_sigs: &[(PublicKey, FixedSignature)], threshold| {
+            verify_threshold::<ResigningVerifier, _, _>(
+                keys_and_sigs,
+                digest(b"message"),
+                threshold,
+            )
+        };
+
+        let err = verify(&keys_and_sigs, 4).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+
+        // A signature by the wrong key doesn't count
+        let mut mismatched = keys_and_sigs.clone();
+        mismatched[2].1 = keys_and_sigs[0].1.clone();
+        assert!(verify(&mismatched, 2).is_ok());
+        assert_eq!(
+            verify(&mismatched, 3).unwrap_err().kind(),
+            ErrorKind::SignatureInvalid
+        );
+
+        // Nor do signatures of another message
+        let err = verify_threshold::<ResigningVerifier, _, _>(
+            &keys_and_sigs,
+            digest(b"another message"),
+            1,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn test_verify_threshold_zero_is_unsupported() {
+        let keys_and_sigs = [sign(0, b"message")];
+        let err =
+            verify_threshold::<ResigningVerifier, _, _>(&keys_and_sigs, digest(b"message"), 0)
+                .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+
+        let err = verify_threshold::<ResigningVerifier, Sha256, _>(&[], digest(b"message"), 0)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_verify_threshold_counts_duplicate_keys_once() {
+        let (public_key, signature) = sign(0, b"message");
+        let verify = |keys_and_sigs: &[(PublicKey, FixedSignature)], threshold| {
+            verify_threshold::<ResigningVerifier, _, _>(
+                keys_and_sigs,
+                digest(b"message"),
+                threshold,
+            )
+        };
+
+        // Repeating a key (even with another valid signature) doesn't count twice
+        let repeated = vec![
+            (public_key.clone(), signature.clone()),
+            (public_key.clone(), signature.clone()),
+        ];
+        assert!(verify(&repeated, 1).is_ok());
+        assert_eq!(
+            verify(&repeated, 2).unwrap_err().kind(),
+            ErrorKind::SignatureInvalid
+        );
+
+        // Nor does repeating it in compressed form
+        let compressed_key = compressed_key(&SHA256_FIXED_SIZE_TEST_VECTORS[0]);
+
+        let repeated = vec![
+            (public_key.clone(), signature.clone()),
+            (compressed_key, signature.clone()),
+        ];
+        assert!(verify(&repeated, 1).is_ok());
+        assert!(verify(&repeated, 2).is_err());
+
+        // A key counts if any of its signatures is valid, not just the first
+        let repeated = vec![
+            (public_key.clone(), tweak(&signature)),
+            (public_key, signature),
+        ];
+        assert!(verify(&repeated, 1).is_ok());
+    }
+}
@@ -0,0 +1,192 @@
+//! Public keys over an elliptic curve selected at runtime, verifying raw
+//! digests with algorithm negotiation (e.g. for a TLS-like verifier)
+
+use super::{
+    Asn1Signature, FixedSignature, MessageDigest, PublicKey, RawDigestVerifier, SignatureFormat,
+};
+use curve::{NistP256, NistP384, Secp256k1, WeierstrassCurve, WeierstrassCurveKind};
+use error::Error;
+use generic_array::{typenum::Unsigned, GenericArray};
+use Signature;
+
+/// ECDSA public key over an elliptic curve selected at runtime
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PublicKeyBundle {
+    /// NIST P-256 public key
+    NistP256(PublicKey<NistP256>),
+
+    /// NIST P-384 public key
+    NistP384(PublicKey<NistP384>),
+
+    /// secp256k1 public key
+    Secp256k1(PublicKey<Secp256k1>),
+}
+
+impl PublicKeyBundle {
+    /// Parse a public key (see `PublicKey::from_bytes`) over the given curve
+    pub fn from_bytes(curve_kind: WeierstrassCurveKind, bytes: &[u8]) -> Result<Self, Error> {
+        Ok(match curve_kind {
+            WeierstrassCurveKind::NistP256 => {
+                PublicKeyBundle::NistP256(PublicKey::from_bytes(bytes)?)
+            }
+            WeierstrassCurveKind::NistP384 => {
+                PublicKeyBundle::NistP384(PublicKey::from_bytes(bytes)?)
+            }
+            WeierstrassCurveKind::Secp256k1 => {
+                PublicKeyBundle::Secp256k1(PublicKey::from_bytes(bytes)?)
+            }
+        })
+    }
+
+    /// Get the elliptic curve of this public key
+    pub fn curve_kind(&self) -> WeierstrassCurveKind {
+        match self {
+            PublicKeyBundle::NistP256(_) => NistP256::CURVE_KIND,
+            PublicKeyBundle::NistP384(_) => NistP384::CURVE_KIND,
+            PublicKeyBundle::Secp256k1(_) => Secp256k1::CURVE_KIND,
+        }
+    }
+
+    /// Verify a signature in the given format against a raw message digest,
+    /// using the provider's `BundleVerifier` type `V` for the curve of this
+    /// public key, e.g.:
+    ///
+    /// `bundle.verify::<EcdsaVerifier>(digest_bytes, sig_bytes, format)`
+    ///
+    /// The digest must be exactly the size of the curve's scalars (anything
+    /// else is a `ParseError`, rather than being truncated or padded), and the
+    /// signature must parse as the given format for the curve. Curves the provider doesn't support are
+    /// an `ErrorKind::Unsupported` error.
+    pub fn verify<V>(
+        &self,
+        digest_bytes: &[u8],
+        sig_bytes: &[u8],
+        format: SignatureFormat,
+    ) -> Result<(), Error>
+    where
+        V: BundleVerifier,
+    {
+        match self {
+            PublicKeyBundle::NistP256(ref key) => {
+                V::verify_nistp256(key, &digest_from_bytes(digest_bytes)?, sig_bytes, format)
+            }
+            PublicKeyBundle::NistP384(ref key) => {
+                V::verify_nistp384(key, &digest_from_bytes(digest_bytes)?, sig_bytes, format)
+            }
+            PublicKeyBundle::Secp256k1(ref key) => {
+                V::verify_secp256k1(key, &digest_from_bytes(digest_bytes)?, sig_bytes, format)
+            }
+        }
+    }
+}
+
+/// Use a digest passed to `PublicKeyBundle::verify` as a message scalar for
+/// the curve `C`, checking it's the right size at runtime (as the type-erased
+/// path can't check it at compile time)
+fn digest_from_bytes<C: WeierstrassCurve>(bytes: &[u8]) -> Result<MessageDigest<C>, Error> {
+    ensure!(
+        bytes.len() == C::ScalarSize::to_usize(),
+        ParseError,
+        "expected {}-byte digest for {:?} (got {})",
+        C::ScalarSize::to_usize(),
+        C::CURVE_KIND,
+        bytes.len()
+    );
+
+    Ok(MessageDigest::from_prehashed_scalar_unchecked(
+        GenericArray::clone_from_slice(bytes),
+    ))
+}
+
+impl From<PublicKey<NistP256>> for PublicKeyBundle {
+    fn from(public_key: PublicKey<NistP256>) -> PublicKeyBundle {
+        PublicKeyBundle::NistP256(public_key)
+    }
+}
+
+impl From<PublicKey<NistP384>> for PublicKeyBundle {
+    fn from(public_key: PublicKey<NistP384>) -> PublicKeyBundle {
+        PublicKeyBundle::NistP384(public_key)
+    }
+}
+
+impl From<PublicKey<Secp256k1>> for PublicKeyBundle {
+    fn from(public_key: PublicKey<Secp256k1>) -> PublicKeyBundle {
+        PublicKeyBundle::Secp256k1(public_key)
+    }
+}
+
+/// Providers' verifiers for each of the curves of `PublicKeyBundle`.
+///
+/// Curves a provider doesn't support default to returning an
+/// `ErrorKind::Unsupported` error. Supported curves can be implemented
+/// using `verify_signature_bytes`.
+pub trait BundleVerifier {
+    /// Verify a NIST P-256 signature in the given format
+    fn verify_nistp256(
+        _public_key: &PublicKey<NistP256>,
+        _digest: &MessageDigest<NistP256>,
+        _signature: &[u8],
+        _format: SignatureFormat,
+    ) -> Result<(), Error> {
+        fail!(
+            Unsupported,
+            "{:?} not supported by provider",
+            NistP256::CURVE_KIND
+        )
+    }
+
+    /// Verify a NIST P-384 signature in the given format
+    fn verify_nistp384(
+        _public_key: &PublicKey<NistP384>,
+        _digest: &MessageDigest<NistP384>,
+        _signature: &[u8],
+        _format: SignatureFormat,
+    ) -> Result<(), Error> {
+        fail!(
+            Unsupported,
+            "{:?} not supported by provider",
+            NistP384::CURVE_KIND
+        )
+    }
+
+    /// Verify a secp256k1 signature in the given format
+    fn verify_secp256k1(
+        _public_key: &PublicKey<Secp256k1>,
+        _digest: &MessageDigest<Secp256k1>,
+        _signature: &[u8],
+        _format: SignatureFormat,
+    ) -> Result<(), Error> {
+        fail!(
+            Unsupported,
+            "{:?} not supported by provider",
+            Secp256k1::CURVE_KIND
+        )
+    }
+}
+
+/// Parse a signature in the given format and verify it against a raw
+/// message digest, using the provider's `RawDigestVerifier` type `V`.
+pub fn verify_signature_bytes<V, C>(
+    public_key: &PublicKey<C>,
+    digest: &MessageDigest<C>,
+    signature: &[u8],
+    format: SignatureFormat,
+) -> Result<(), Error>
+where
+    V: RawDigestVerifier<C, Asn1Signature<C>>
+        + RawDigestVerifier<C, FixedSignature<C>>
+        + for<'a> From<&'a PublicKey<C>>,
+    C: WeierstrassCurve,
+{
+    let verifier = V::from(public_key);
+
+    match format {
+        SignatureFormat::Asn1 => {
+            verifier.verify_raw_digest(digest, &Asn1Signature::from_bytes(signature)?)
+        }
+        SignatureFormat::Fixed => {
+            verifier.verify_raw_digest(digest, &FixedSignature::from_bytes(signature)?)
+        }
+    }
+}
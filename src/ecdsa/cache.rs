@@ -0,0 +1,290 @@
+//! Caching of ECDSA verification results, for applications which verify
+//! the same signatures repeatedly (e.g. transactions relayed by many peers)
+
+use core::marker::PhantomData;
+use digest::Digest;
+use sha2::Sha256;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use super::{FixedSignature, PublicKey};
+use curve::WeierstrassCurve;
+use error::Error;
+use verifier::DigestVerifier;
+
+/// Key of a cache entry: SHA-256 of (public key || digest || signature)
+type CacheKey = [u8; 32];
+
+/// Verifier which remembers which (public key, digest, signature) tuples
+/// have verified successfully, skipping verification when it sees the same
+/// tuple again.
+///
+/// Only successful verifications are cached, so failures are always
+/// verified afresh. At most `capacity` entries are kept: once it's full, the
+/// least recently used entry is evicted. Entries are 32-byte hashes of the
+/// tuple, so memory usage is bounded regardless of key or digest size.
+/// Requires the `digest`, `sha2`, and `std` cargo features.
+///
+/// Signatures are verified with the provider's verifier type `V`. Cached
+/// results are only ever reused by the same `V`, so results from a lenient
+/// verifier are never trusted by a stricter one.
+pub struct CachingVerifier<C: WeierstrassCurve, V> {
+    /// Successfully verified tuples
+    cache: Mutex<LruCache>,
+
+    /// Placeholder for elliptic curve type
+    curve: PhantomData<C>,
+
+    /// Placeholder for the provider's verifier type
+    verifier: PhantomData<V>,
+}
+
+impl<C, V> CachingVerifier<C, V>
+where
+    C: WeierstrassCurve,
+{
+    /// Create a new verifier which caches at most `capacity` results. A
+    /// `capacity` of zero disables caching.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            cache: Mutex::new(LruCache::new(capacity)),
+            curve: PhantomData,
+            verifier: PhantomData,
+        }
+    }
+
+    /// Verify a signature of the given digest under the given public key with
+    /// the provider's verifier type `V`, unless the same (public key, digest,
+    /// signature) tuple has already been verified successfully.
+    pub fn verify<D>(
+        &self,
+        public_key: &PublicKey<C>,
+        digest: D,
+        signature: &FixedSignature<C>,
+    ) -> Result<(), Error>
+    where
+        V: DigestVerifier<D, FixedSignature<C>> + for<'a> From<&'a PublicKey<C>>,
+        D: Digest + Clone,
+    {
+        let key = cache_key(public_key, digest.clone(), signature);
+
+        if self.lock().touch(&key) {
+            return Ok(());
+        }
+
+        V::from(public_key).verify(digest, signature)?;
+        self.lock().insert(key);
+
+        Ok(())
+    }
+
+    /// Maximum number of cached results
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    /// Number of cached results
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Is the cache empty?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forget all cached results
+    pub fn clear(&self) {
+        let mut cache = self.lock();
+        cache.entries.clear();
+        cache.recency.clear();
+    }
+
+    /// Lock the cache. It's only ever modified while in a consistent state,
+    /// so it's still usable if another thread panicked while holding it.
+    fn lock(&self) -> MutexGuard<'_, LruCache> {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Compute the cache key for a (public key, digest, signature) tuple.
+///
+/// Public keys and digests may vary in length, so their lengths are hashed too.
+fn cache_key<C, D>(public_key: &PublicKey<C>, digest: D, signature: &FixedSignature<C>) -> CacheKey
+where
+    C: WeierstrassCurve,
+    D: Digest,
+{
+    let digest_output = digest.result();
+
+    let mut hasher = Sha256::default();
+    hasher.input(&[public_key.as_bytes().len() as u8]);
+    hasher.input(public_key.as_bytes());
+    hasher.input(&[digest_output.len() as u8]);
+    hasher.input(&digest_output);
+    hasher.input(signature.as_ref());
+
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&hasher.result());
+    key
+}
+
+/// Least recently used cache of verified tuples
+struct LruCache {
+    /// Maximum number of entries
+    capacity: usize,
+
+    /// Cached entries, and when each was last used
+    entries: HashMap<CacheKey, u64>,
+
+    /// Cached entries ordered by when they were last used
+    recency: BTreeMap<u64, CacheKey>,
+
+    /// Counter incremented each time an entry is used
+    clock: u64,
+}
+
+impl LruCache {
+    /// Create a new cache holding at most `capacity` entries
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            clock: 0,
+        }
+    }
+
+    /// Is the given key cached? If so, mark it as the most recently used
+    fn touch(&mut self, key: &CacheKey) -> bool {
+        let now = self.tick();
+
+        match self.entries.get_mut(key) {
+            Some(last_used) => {
+                self.recency.remove(last_used);
+                self.recency.insert(now, *key);
+                *last_used = now;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Add the given key to the cache, evicting the least recently used entry
+    /// if it's full
+    fn insert(&mut self, key: CacheKey) {
+        if self.capacity == 0 || self.touch(&key) {
+            return;
+        }
+
+        if self.entries.len() >= self.capacity {
+            let oldest = *self.recency.keys().next().unwrap();
+            let evicted = self.recency.remove(&oldest).unwrap();
+            self.entries.remove(&evicted);
+        }
+
+        let now = self.tick();
+        self.entries.insert(key, now);
+        self.recency.insert(now, key);
+    }
+
+    /// Advance the clock, returning its new value
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+}
+
+#[cfg(all(test, feature = "test-vectors"))]
+mod tests {
+    use sha2::Sha256;
+
+    use super::CachingVerifier;
+    use curve::nistp256::{FixedSignature, PublicKey, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use curve::NistP256;
+    use digest::Digest;
+    use ecdsa::test_verifier::TestVerifier;
+    use error::ErrorKind;
+    use generic_array::GenericArray;
+    use Signature;
+
+    fn digest(msg: &[u8]) -> Sha256 {
+        let mut digest = Sha256::default();
+        digest.input(msg);
+        digest
+    }
+
+    #[test]
+    fn test_caching_verifier() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+        let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+        let verifier = CachingVerifier::<NistP256, TestVerifier>::new(2);
+        let start = TestVerifier::verifications();
+
+        // Only the first verification of identical inputs is performed
+        for _ in 0..3 {
+            verifier
+                .verify(&public_key, digest(vector.msg), &signature)
+                .unwrap();
+        }
+
+        assert_eq!(TestVerifier::verifications() - start, 1);
+        assert_eq!(verifier.len(), 1);
+
+        // Different digests are cached separately, evicting the least recently used
+        verifier
+            .verify(&public_key, digest(b"a"), &signature)
+            .unwrap();
+        verifier
+            .verify(&public_key, digest(vector.msg), &signature)
+            .unwrap();
+        verifier
+            .verify(&public_key, digest(b"b"), &signature)
+            .unwrap();
+        assert_eq!(TestVerifier::verifications() - start, 3);
+        assert_eq!(verifier.len(), 2);
+
+        verifier
+            .verify(&public_key, digest(vector.msg), &signature)
+            .unwrap();
+        assert_eq!(TestVerifier::verifications() - start, 3);
+        verifier
+            .verify(&public_key, digest(b"a"), &signature)
+            .unwrap();
+        assert_eq!(TestVerifier::verifications() - start, 4);
+
+        // Failures aren't cached
+        let invalid = FixedSignature::default();
+
+        for _ in 0..2 {
+            let err = verifier
+                .verify(&public_key, digest(vector.msg), &invalid)
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+        }
+
+        assert_eq!(TestVerifier::verifications() - start, 6);
+
+        verifier.clear();
+        assert!(verifier.is_empty());
+    }
+
+    #[test]
+    fn test_caching_verifier_zero_capacity() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+        let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+        let verifier = CachingVerifier::<NistP256, TestVerifier>::new(0);
+        let start = TestVerifier::verifications();
+
+        for _ in 0..2 {
+            verifier
+                .verify(&public_key, digest(vector.msg), &signature)
+                .unwrap();
+        }
+
+        assert_eq!(TestVerifier::verifications() - start, 2);
+        assert!(verifier.is_empty());
+    }
+}
@@ -0,0 +1,586 @@
+//! Modular and elliptic curve arithmetic, used to validate public keys (and
+//! to sign with `MemorySigner`).
+//!
+//! Integers are little endian arrays of 32-bit limbs, and field elements are
+//! kept in Montgomery form. Nothing here is constant-time, so it must not be
+//! used with secret values outside of tests.
+
+use core::cmp::Ordering;
+
+use curve::WeierstrassCurveKind;
+
+/// Maximum number of 32-bit limbs in an integer (i.e. 384-bit for P-384)
+pub(crate) const MAX_LIMBS: usize = 12;
+
+/// Integer serialized as little endian 32-bit limbs
+pub(crate) type Limbs = [u32; MAX_LIMBS];
+
+/// Odd modulus `m` for Montgomery arithmetic, i.e. computing with `x * R`
+/// (mod `m`) in place of `x`, where `R = 2^(32 * len)`
+pub(crate) struct Modulus {
+    /// The modulus itself
+    m: Limbs,
+
+    /// Number of limbs in use
+    len: usize,
+
+    /// `-m^-1 mod 2^32`
+    m_inv: u32,
+
+    /// `R^2 mod m`, for converting into Montgomery form
+    r2: Limbs,
+}
+
+impl Modulus {
+    /// Create a modulus from a big endian integer, which must be odd and a
+    /// multiple of 4 bytes long (with its leading limb non-zero)
+    pub fn new(bytes: &[u8]) -> Self {
+        let len = bytes.len() / 4;
+        debug_assert!(bytes.len() % 4 == 0 && len <= MAX_LIMBS);
+
+        let mut modulus = Modulus {
+            m: [0; MAX_LIMBS],
+            len,
+            m_inv: 0,
+            r2: [0; MAX_LIMBS],
+        };
+
+        modulus.m = modulus.parse(bytes);
+        debug_assert!(modulus.m[0] & 1 == 1 && modulus.m[len - 1] != 0);
+
+        // Newton's method: each iteration doubles the correct low bits
+        let mut inv = 1u32;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u32.wrapping_sub(modulus.m[0].wrapping_mul(inv)));
+        }
+        modulus.m_inv = inv.wrapping_neg();
+
+        // R^2 mod m, by doubling 1 (mod m) 2 * 32 * len times
+        let mut r2 = [0; MAX_LIMBS];
+        r2[0] = 1;
+        for _ in 0..(64 * len) {
+            r2 = modulus.add(&r2, &r2);
+        }
+        modulus.r2 = r2;
+
+        modulus
+    }
+
+    /// Parse a big endian integer of `4 * len` bytes (not reduced)
+    pub fn parse(&self, bytes: &[u8]) -> Limbs {
+        debug_assert_eq!(bytes.len(), self.len * 4);
+        let mut limbs = [0; MAX_LIMBS];
+
+        for (i, chunk) in bytes.rchunks(4).enumerate() {
+            limbs[i] = chunk
+                .iter()
+                .fold(0u32, |limb, &byte| (limb << 8) | u32::from(byte));
+        }
+
+        limbs
+    }
+
+    /// Serialize an integer as `4 * len` big endian bytes
+    pub fn serialize(&self, x: &Limbs, bytes: &mut [u8]) {
+        debug_assert_eq!(bytes.len(), self.len * 4);
+
+        for (i, chunk) in bytes.rchunks_mut(4).enumerate() {
+            for (j, byte) in chunk.iter_mut().enumerate() {
+                *byte = (x[i] >> (8 * (3 - j))) as u8;
+            }
+        }
+    }
+
+    /// Compare integers
+    pub fn cmp(&self, a: &Limbs, b: &Limbs) -> Ordering {
+        a[..self.len].iter().rev().cmp(b[..self.len].iter().rev())
+    }
+
+    /// Is the given integer in the range `[1, m)`?
+    pub fn is_nonzero_residue(&self, x: &Limbs) -> bool {
+        !is_zero(x) && self.cmp(x, &self.m) == Ordering::Less
+    }
+
+    /// Reduce an integer less than `2 * m`
+    #[cfg(feature = "test-signer")]
+    pub fn reduce_once(&self, x: &Limbs) -> Limbs {
+        if self.cmp(x, &self.m) == Ordering::Less {
+            *x
+        } else {
+            sub_limbs(x, &self.m, self.len).0
+        }
+    }
+
+    /// Compute `a + b (mod m)`
+    pub fn add(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let mut sum = [0; MAX_LIMBS];
+        let mut carry = 0u64;
+
+        for i in 0..self.len {
+            carry += u64::from(a[i]) + u64::from(b[i]);
+            sum[i] = carry as u32;
+            carry >>= 32;
+        }
+
+        if carry != 0 || self.cmp(&sum, &self.m) != Ordering::Less {
+            sum = sub_limbs(&sum, &self.m, self.len).0;
+        }
+
+        sum
+    }
+
+    /// Compute `a - b (mod m)`
+    pub fn sub(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let (difference, borrow) = sub_limbs(a, b, self.len);
+
+        if borrow {
+            add_limbs(&difference, &self.m, self.len)
+        } else {
+            difference
+        }
+    }
+
+    /// Compute the Montgomery product `a * b * R^-1 (mod m)`
+    pub fn mul(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let n = self.len;
+        let mut t = [0u32; MAX_LIMBS + 2];
+
+        for i in 0..n {
+            // t += a * b[i]
+            let mut carry = 0u64;
+            for j in 0..n {
+                carry += u64::from(t[j]) + u64::from(a[j]) * u64::from(b[i]);
+                t[j] = carry as u32;
+                carry >>= 32;
+            }
+            carry += u64::from(t[n]);
+            t[n] = carry as u32;
+            t[n + 1] = (carry >> 32) as u32;
+
+            // t = (t + u * m) / 2^32, where u is chosen so the division is exact
+            let u = t[0].wrapping_mul(self.m_inv);
+            let mut carry = (u64::from(t[0]) + u64::from(u) * u64::from(self.m[0])) >> 32;
+            for j in 1..n {
+                carry += u64::from(t[j]) + u64::from(u) * u64::from(self.m[j]);
+                t[j - 1] = carry as u32;
+                carry >>= 32;
+            }
+            carry += u64::from(t[n]);
+            t[n - 1] = carry as u32;
+            t[n] = t[n + 1] + (carry >> 32) as u32;
+        }
+
+        let mut product = [0; MAX_LIMBS];
+        product[..n].copy_from_slice(&t[..n]);
+
+        if t[n] != 0 || self.cmp(&product, &self.m) != Ordering::Less {
+            product = sub_limbs(&product, &self.m, n).0;
+        }
+
+        product
+    }
+
+    /// Convert an integer less than `m` into Montgomery form
+    pub fn to_montgomery(&self, x: &Limbs) -> Limbs {
+        self.mul(x, &self.r2)
+    }
+
+    /// Convert an integer out of Montgomery form
+    pub fn reduce_montgomery(&self, x: &Limbs) -> Limbs {
+        let mut one = [0; MAX_LIMBS];
+        one[0] = 1;
+        self.mul(x, &one)
+    }
+
+    /// Compute `x^-1 (mod m)` of a non-zero `x` in Montgomery form, using
+    /// Fermat's little theorem (i.e. `x^(m - 2)`, so `m` must be prime)
+    #[cfg(feature = "test-signer")]
+    pub fn invert(&self, x: &Limbs) -> Limbs {
+        let mut two = [0; MAX_LIMBS];
+        two[0] = 2;
+        self.pow(x, &sub_limbs(&self.m, &two, self.len).0)
+    }
+
+    /// Is `x` (in Montgomery form) a square modulo the (odd prime) modulus?
+    /// Uses Euler's criterion, i.e. whether `x^((m - 1) / 2)` is 0 or 1.
+    pub fn is_square(&self, x: &Limbs) -> bool {
+        let mut exponent = self.m;
+        for i in 0..self.len {
+            let high_bit = if i + 1 < self.len {
+                exponent[i + 1] << 31
+            } else {
+                0
+            };
+            exponent[i] = (exponent[i] >> 1) | high_bit;
+        }
+
+        let mut one = [0; MAX_LIMBS];
+        one[0] = 1;
+
+        is_zero(x) || self.pow(x, &exponent) == self.to_montgomery(&one)
+    }
+
+    /// Compute a square root of `x` (in Montgomery form), if it has one, as
+    /// `x^((m + 1) / 4)`, which requires `m = 3 (mod 4)`
+    pub fn sqrt(&self, x: &Limbs) -> Option<Limbs> {
+        debug_assert_eq!(self.m[0] & 3, 3);
+
+        let mut one = [0; MAX_LIMBS];
+        one[0] = 1;
+        let mut exponent = add_limbs(&self.m, &one, self.len);
+        for i in 0..self.len {
+            let high_bits = if i + 1 < self.len {
+                exponent[i + 1] << 30
+            } else {
+                0
+            };
+            exponent[i] = (exponent[i] >> 2) | high_bits;
+        }
+
+        let root = self.pow(x, &exponent);
+
+        if self.mul(&root, &root) == *x {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// Compute `x^exponent (mod m)` of `x` in Montgomery form
+    fn pow(&self, x: &Limbs, exponent: &Limbs) -> Limbs {
+        let mut one = [0; MAX_LIMBS];
+        one[0] = 1;
+        let mut result = self.to_montgomery(&one);
+
+        for i in (0..(32 * self.len)).rev() {
+            result = self.mul(&result, &result);
+
+            if (exponent[i / 32] >> (i % 32)) & 1 == 1 {
+                result = self.mul(&result, x);
+            }
+        }
+
+        result
+    }
+}
+
+/// Is the given integer zero?
+pub(crate) fn is_zero(x: &Limbs) -> bool {
+    x.iter().all(|&limb| limb == 0)
+}
+
+/// Compute `a + b`, discarding the carry out of `len` limbs
+fn add_limbs(a: &Limbs, b: &Limbs, len: usize) -> Limbs {
+    let mut sum = [0; MAX_LIMBS];
+    let mut carry = 0u64;
+
+    for i in 0..len {
+        carry += u64::from(a[i]) + u64::from(b[i]);
+        sum[i] = carry as u32;
+        carry >>= 32;
+    }
+
+    sum
+}
+
+/// Compute `a - b` over `len` limbs, returning the difference and whether
+/// there was a borrow (i.e. `a < b`)
+fn sub_limbs(a: &Limbs, b: &Limbs, len: usize) -> (Limbs, bool) {
+    let mut difference = [0; MAX_LIMBS];
+    let mut borrow = 0i64;
+
+    for i in 0..len {
+        borrow += i64::from(a[i]) - i64::from(b[i]);
+        difference[i] = borrow as u32;
+        borrow >>= 32;
+    }
+
+    (difference, borrow != 0)
+}
+
+/// Short Weierstrass curve `y^2 = x^3 + ax + b` over the prime field `p`,
+/// with base point `G`
+pub(crate) struct Curve {
+    /// Field modulus
+    pub p: Modulus,
+
+    /// Coefficient `a` (in Montgomery form)
+    a: Limbs,
+
+    /// Coefficient `b` (in Montgomery form)
+    b: Limbs,
+
+    /// Base point (in Montgomery form)
+    #[cfg(feature = "test-signer")]
+    g: Point,
+}
+
+/// Point in Jacobian coordinates `(X, Y, Z)`, i.e. the affine point
+/// `(X / Z^2, Y / Z^3)`, with `Z = 0` for the point at infinity
+#[cfg(feature = "test-signer")]
+#[derive(Copy, Clone)]
+struct Point {
+    x: Limbs,
+    y: Limbs,
+    z: Limbs,
+}
+
+impl Curve {
+    /// Get the parameters of the given curve
+    pub fn new(curve_kind: WeierstrassCurveKind) -> Self {
+        // The base point is only needed to sign with `MemorySigner`
+        #[cfg_attr(not(feature = "test-signer"), allow(unused_variables))]
+        let (p, a, b, gx, gy): (&[u8], &[u8], &[u8], &[u8], &[u8]) = match curve_kind {
+            WeierstrassCurveKind::NistP256 => (
+                b"\xff\xff\xff\xff\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\
+                  \x00\x00\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+                b"\xff\xff\xff\xff\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\
+                  \x00\x00\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfc",
+                b"\x5a\xc6\x35\xd8\xaa\x3a\x93\xe7\xb3\xeb\xbd\x55\x76\x98\x86\xbc\
+                  \x65\x1d\x06\xb0\xcc\x53\xb0\xf6\x3b\xce\x3c\x3e\x27\xd2\x60\x4b",
+                b"\x6b\x17\xd1\xf2\xe1\x2c\x42\x47\xf8\xbc\xe6\xe5\x63\xa4\x40\xf2\
+                  \x77\x03\x7d\x81\x2d\xeb\x33\xa0\xf4\xa1\x39\x45\xd8\x98\xc2\x96",
+                b"\x4f\xe3\x42\xe2\xfe\x1a\x7f\x9b\x8e\xe7\xeb\x4a\x7c\x0f\x9e\x16\
+                  \x2b\xce\x33\x57\x6b\x31\x5e\xce\xcb\xb6\x40\x68\x37\xbf\x51\xf5",
+            ),
+            WeierstrassCurveKind::NistP384 => (
+                b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+                  \xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\
+                  \xff\xff\xff\xff\x00\x00\x00\x00\x00\x00\x00\x00\xff\xff\xff\xff",
+                b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+                  \xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\
+                  \xff\xff\xff\xff\x00\x00\x00\x00\x00\x00\x00\x00\xff\xff\xff\xfc",
+                b"\xb3\x31\x2f\xa7\xe2\x3e\xe7\xe4\x98\x8e\x05\x6b\xe3\xf8\x2d\x19\
+                  \x18\x1d\x9c\x6e\xfe\x81\x41\x12\x03\x14\x08\x8f\x50\x13\x87\x5a\
+                  \xc6\x56\x39\x8d\x8a\x2e\xd1\x9d\x2a\x85\xc8\xed\xd3\xec\x2a\xef",
+                b"\xaa\x87\xca\x22\xbe\x8b\x05\x37\x8e\xb1\xc7\x1e\xf3\x20\xad\x74\
+                  \x6e\x1d\x3b\x62\x8b\xa7\x9b\x98\x59\xf7\x41\xe0\x82\x54\x2a\x38\
+                  \x55\x02\xf2\x5d\xbf\x55\x29\x6c\x3a\x54\x5e\x38\x72\x76\x0a\xb7",
+                b"\x36\x17\xde\x4a\x96\x26\x2c\x6f\x5d\x9e\x98\xbf\x92\x92\xdc\x29\
+                  \xf8\xf4\x1d\xbd\x28\x9a\x14\x7c\xe9\xda\x31\x13\xb5\xf0\xb8\xc0\
+                  \x0a\x60\xb1\xce\x1d\x7e\x81\x9d\x7a\x43\x1d\x7c\x90\xea\x0e\x5f",
+            ),
+            WeierstrassCurveKind::Secp256k1 => (
+                b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+                  \xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\xff\xff\xfc\x2f",
+                &[0u8; 32],
+                b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+                  \x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x07",
+                b"\x79\xbe\x66\x7e\xf9\xdc\xbb\xac\x55\xa0\x62\x95\xce\x87\x0b\x07\
+                  \x02\x9b\xfc\xdb\x2d\xce\x28\xd9\x59\xf2\x81\x5b\x16\xf8\x17\x98",
+                b"\x48\x3a\xda\x77\x26\xa3\xc4\x65\x5d\xa4\xfb\xfc\x0e\x11\x08\xa8\
+                  \xfd\x17\xb4\x48\xa6\x85\x54\x19\x9c\x47\xd0\x8f\xfb\x10\xd4\xb8",
+            ),
+        };
+
+        let p = Modulus::new(p);
+        let a = p.to_montgomery(&p.parse(a));
+        let b = p.to_montgomery(&p.parse(b));
+
+        #[cfg(feature = "test-signer")]
+        let g = {
+            let mut one = [0; MAX_LIMBS];
+            one[0] = 1;
+
+            Point {
+                x: p.to_montgomery(&p.parse(gx)),
+                y: p.to_montgomery(&p.parse(gy)),
+                z: p.to_montgomery(&one),
+            }
+        };
+
+        Curve {
+            p,
+            a,
+            b,
+            #[cfg(feature = "test-signer")]
+            g,
+        }
+    }
+
+    /// Is `(x, y)` (not in Montgomery form) a point on the curve, i.e. are
+    /// both coordinates reduced and `y^2 = x^3 + ax + b`?
+    pub fn contains(&self, x: &Limbs, y: &Limbs) -> bool {
+        let p = &self.p;
+
+        if !self.is_reduced(x) || !self.is_reduced(y) {
+            return false;
+        }
+
+        let y = p.to_montgomery(y);
+        p.mul(&y, &y) == self.rhs(&p.to_montgomery(x))
+    }
+
+    /// Is `x` (not in Montgomery form) the `x` coordinate of a point on the
+    /// curve, i.e. is it reduced and `x^3 + ax + b` a square?
+    pub fn contains_x(&self, x: &Limbs) -> bool {
+        self.is_reduced(x) && self.p.is_square(&self.rhs(&self.p.to_montgomery(x)))
+    }
+
+    /// Compute the `y` coordinate with the given parity for the point with
+    /// the given `x` coordinate (neither in Montgomery form), if there is one
+    pub fn decompress(&self, x: &Limbs, y_is_odd: bool) -> Option<Limbs> {
+        let p = &self.p;
+
+        if !self.is_reduced(x) {
+            return None;
+        }
+
+        let y = p.reduce_montgomery(&p.sqrt(&self.rhs(&p.to_montgomery(x)))?);
+
+        if (y[0] & 1 == 1) == y_is_odd {
+            Some(y)
+        } else {
+            Some(p.sub(&[0; MAX_LIMBS], &y))
+        }
+    }
+
+    /// Is the given integer in the range `[0, p)`?
+    fn is_reduced(&self, x: &Limbs) -> bool {
+        is_zero(x) || self.p.is_nonzero_residue(x)
+    }
+
+    /// Compute `x^3 + ax + b` for `x` in Montgomery form
+    fn rhs(&self, x: &Limbs) -> Limbs {
+        let p = &self.p;
+        let x3 = p.mul(&p.mul(x, x), x);
+        p.add(&p.add(&x3, &p.mul(&self.a, x)), &self.b)
+    }
+
+    /// Compute `k * G` for a scalar `k`, returning its affine coordinates
+    /// (not in Montgomery form), or `None` if it's the point at infinity
+    #[cfg(feature = "test-signer")]
+    pub fn mul_base(&self, k: &Limbs) -> Option<(Limbs, Limbs)> {
+        let mut result = Point {
+            x: [0; MAX_LIMBS],
+            y: [0; MAX_LIMBS],
+            z: [0; MAX_LIMBS],
+        };
+
+        // Double-and-add, from the most significant bit
+        for i in (0..(32 * self.p.len)).rev() {
+            result = self.double(&result);
+
+            if (k[i / 32] >> (i % 32)) & 1 == 1 {
+                result = self.add(&result, &self.g);
+            }
+        }
+
+        if is_zero(&result.z) {
+            return None;
+        }
+
+        let p = &self.p;
+        let z_inv = p.invert(&result.z);
+        let z_inv2 = p.mul(&z_inv, &z_inv);
+        let x = p.mul(&result.x, &z_inv2);
+        let y = p.mul(&result.y, &p.mul(&z_inv2, &z_inv));
+
+        Some((p.reduce_montgomery(&x), p.reduce_montgomery(&y)))
+    }
+
+    /// Compute `2 * P` ("dbl-2007-bl" formulas for any `a`)
+    #[cfg(feature = "test-signer")]
+    fn double(&self, point: &Point) -> Point {
+        let p = &self.p;
+
+        if is_zero(&point.z) || is_zero(&point.y) {
+            return Point {
+                z: [0; MAX_LIMBS],
+                ..*point
+            };
+        }
+
+        let xx = p.mul(&point.x, &point.x);
+        let yy = p.mul(&point.y, &point.y);
+        let yyyy = p.mul(&yy, &yy);
+        let zz = p.mul(&point.z, &point.z);
+
+        // S = 2 * ((X + YY)^2 - XX - YYYY)
+        let x_yy = p.add(&point.x, &yy);
+        let s = p.sub(&p.sub(&p.mul(&x_yy, &x_yy), &xx), &yyyy);
+        let s = p.add(&s, &s);
+
+        // M = 3 * XX + a * ZZ^2
+        let m = p.add(&p.add(&xx, &xx), &xx);
+        let m = p.add(&m, &p.mul(&self.a, &p.mul(&zz, &zz)));
+
+        // X3 = M^2 - 2 * S
+        let x3 = p.sub(&p.sub(&p.mul(&m, &m), &s), &s);
+
+        // Y3 = M * (S - X3) - 8 * YYYY
+        let yyyy2 = p.add(&yyyy, &yyyy);
+        let yyyy4 = p.add(&yyyy2, &yyyy2);
+        let yyyy8 = p.add(&yyyy4, &yyyy4);
+        let y3 = p.sub(&p.mul(&m, &p.sub(&s, &x3)), &yyyy8);
+
+        // Z3 = (Y + Z)^2 - YY - ZZ
+        let y_z = p.add(&point.y, &point.z);
+        let z3 = p.sub(&p.sub(&p.mul(&y_z, &y_z), &yy), &zz);
+
+        Point {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Compute `P + Q` ("add-2007-bl" formulas)
+    #[cfg(feature = "test-signer")]
+    fn add(&self, a: &Point, b: &Point) -> Point {
+        let p = &self.p;
+
+        if is_zero(&a.z) {
+            return *b;
+        }
+
+        if is_zero(&b.z) {
+            return *a;
+        }
+
+        let z1z1 = p.mul(&a.z, &a.z);
+        let z2z2 = p.mul(&b.z, &b.z);
+        let u1 = p.mul(&a.x, &z2z2);
+        let u2 = p.mul(&b.x, &z1z1);
+        let s1 = p.mul(&a.y, &p.mul(&b.z, &z2z2));
+        let s2 = p.mul(&b.y, &p.mul(&a.z, &z1z1));
+
+        let h = p.sub(&u2, &u1);
+        let r = p.sub(&s2, &s1);
+
+        if is_zero(&h) {
+            return if is_zero(&r) {
+                self.double(a)
+            } else {
+                Point {
+                    z: [0; MAX_LIMBS],
+                    ..*a
+                }
+            };
+        }
+
+        // I = (2 * H)^2, J = H * I, r = 2 * (S2 - S1), V = U1 * I
+        let h2 = p.add(&h, &h);
+        let i = p.mul(&h2, &h2);
+        let j = p.mul(&h, &i);
+        let r = p.add(&r, &r);
+        let v = p.mul(&u1, &i);
+
+        // X3 = r^2 - J - 2 * V
+        let x3 = p.sub(&p.sub(&p.sub(&p.mul(&r, &r), &j), &v), &v);
+
+        // Y3 = r * (V - X3) - 2 * S1 * J
+        let s1_j = p.mul(&s1, &j);
+        let y3 = p.sub(&p.sub(&p.mul(&r, &p.sub(&v, &x3)), &s1_j), &s1_j);
+
+        // Z3 = ((Z1 + Z2)^2 - Z1Z1 - Z2Z2) * H
+        let z1_z2 = p.add(&a.z, &b.z);
+        let z3 = p.mul(&p.sub(&p.sub(&p.mul(&z1_z2, &z1_z2), &z1z1), &z2z2), &h);
+
+        Point {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 //! Elliptic Curves: Weierstrass form - for use with ECDSA.
 
 use core::{fmt::Debug, hash::Hash, str::FromStr};
-use generic_array::ArrayLength;
+use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
 
+pub(crate) mod arithmetic;
 pub mod nistp256;
 pub mod nistp384;
 pub mod point;
@@ -15,9 +16,26 @@ use error::Error;
 pub trait WeierstrassCurve:
     Clone + Debug + Default + Hash + Eq + PartialEq + Send + Sized + Sync
 {
-    /// Elliptic curve kind
+    /// Elliptic curve kind: the runtime value identifying this curve type.
+    ///
+    /// Generic code can compare it to branch on the concrete curve, e.g.
+    /// `if C::CURVE_KIND == WeierstrassCurveKind::Secp256k1 { ... }`.
     const CURVE_KIND: WeierstrassCurveKind;
 
+    /// Order of the curve's base point (a.k.a. `n`) serialized as a
+    /// big-endian integer `ScalarSize` bytes in length
+    const ORDER: &'static [u8];
+
+    /// Half the order of the base point (`floor(n/2)`), precomputed and
+    /// serialized like `ORDER`. The largest "low S" value (see
+    /// `FixedSignature::is_low_s`).
+    const ORDER_HALF: &'static [u8];
+
+    /// Does this curve support public key recovery from signatures (i.e.
+    /// `RecoverableSignature`)? If so, it also impls `RecoverableCurve`.
+    /// Defaults to `false`.
+    const SUPPORTS_RECOVERY: bool = false;
+
     // TODO: unify these sizes, either with `typenum` or after const generics
     // hopefully make this kind of type-level arithmetic easy to do.
 
@@ -49,6 +67,58 @@ pub trait WeierstrassCurve:
     type FixedSignatureSize: ArrayLength<u8>;
 }
 
+/// Marker trait for curves which support recoverable signatures, i.e. where
+/// `WeierstrassCurve::SUPPORTS_RECOVERY` is `true`.
+///
+/// `RecoverableSignature` is bounded on this trait, so attempting recovery
+/// with an unsupported curve is a compile error.
+pub trait RecoverableCurve: WeierstrassCurve {
+    /// Size of a recoverable ECDSA signature using this curve: a fixed-sized
+    /// signature followed by a 1-byte recovery ID
+    type RecoverableSignatureSize: ArrayLength<u8>;
+}
+
+/// Convert a message digest to the scalar `z` used when signing/verifying
+/// with the curve `C`, i.e. its leftmost `bitlen(n)` bits (where `n` is the
+/// curve's order) as per SEC 1 section 4.1.3 step 5.
+///
+/// This truncates *bits*, not bytes: when `bitlen(n)` isn't a multiple of 8
+/// (e.g. P-521) the leftmost bytes are shifted right to discard the excess
+/// bits. Digests shorter than `bitlen(n)` are left-padded with zeroes.
+///
+/// The result is not reduced modulo `n`, so it may be greater than or equal
+/// to `n`. It's mainly useful for debugging interop failures.
+pub fn digest_to_scalar<C>(digest: &[u8]) -> GenericArray<u8, C::ScalarSize>
+where
+    C: WeierstrassCurve,
+{
+    let scalar_size = C::ScalarSize::to_usize();
+    let order = &C::ORDER[C::ORDER.iter().take_while(|&&byte| byte == 0).count()..];
+    let order_bits = order.len() * 8 - order.first().map_or(0, |b| b.leading_zeros() as usize);
+
+    let mut scalar = GenericArray::default();
+
+    if digest.len() * 8 <= order_bits {
+        scalar[(scalar_size - digest.len())..].copy_from_slice(digest);
+        return scalar;
+    }
+
+    // Take the leftmost `ceil(bitlen(n) / 8)` bytes, then discard excess bits
+    let len = (order_bits + 7) / 8;
+    let shift = len * 8 - order_bits;
+    let out = &mut scalar[(scalar_size - len)..];
+    out.copy_from_slice(&digest[..len]);
+
+    if shift > 0 {
+        for i in (0..len).rev() {
+            let carry = if i > 0 { out[i - 1] << (8 - shift) } else { 0 };
+            out[i] = (out[i] >> shift) | carry;
+        }
+    }
+
+    scalar
+}
+
 /// Types of Weierstrass curves known to this library
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum WeierstrassCurveKind {
@@ -105,4 +175,193 @@ impl WeierstrassCurveKind {
             WeierstrassCurveKind::Secp256k1 => Some("secp256k1"),
         }
     }
+
+    /// Get the DER-encoded object identifier (OID) for this elliptic curve,
+    /// sans tag and length, as used in the `namedCurve` parameters of an
+    /// EC `SubjectPublicKeyInfo` (see RFC 5480).
+    pub fn to_oid(self) -> &'static [u8] {
+        match self {
+            // prime256v1 (1.2.840.10045.3.1.7)
+            WeierstrassCurveKind::NistP256 => &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07],
+            // secp384r1 (1.3.132.0.34)
+            WeierstrassCurveKind::NistP384 => &[0x2b, 0x81, 0x04, 0x00, 0x22],
+            // secp256k1 (1.3.132.0.10)
+            WeierstrassCurveKind::Secp256k1 => &[0x2b, 0x81, 0x04, 0x00, 0x0a],
+        }
+    }
+
+    /// Get the W3C Web Cryptography API `namedCurve` name for this particular
+    /// elliptic curve (if it's supported by WebCrypto).
+    ///
+    /// <https://www.w3.org/TR/WebCryptoAPI/#dfn-NamedCurve>
+    pub fn to_webcrypto_name(self) -> Option<&'static str> {
+        match self {
+            WeierstrassCurveKind::NistP256 => Some("P-256"),
+            WeierstrassCurveKind::NistP384 => Some("P-384"),
+            WeierstrassCurveKind::Secp256k1 => None,
+        }
+    }
+
+    /// Get the size of a scalar (e.g. a private key, or the `r` or `s`
+    /// component of a signature) for this elliptic curve in bytes, i.e. its
+    /// `WeierstrassCurve::ScalarSize`.
+    pub fn scalar_size(self) -> usize {
+        match self {
+            WeierstrassCurveKind::NistP256 => {
+                <NistP256 as WeierstrassCurve>::ScalarSize::to_usize()
+            }
+            WeierstrassCurveKind::NistP384 => {
+                <NistP384 as WeierstrassCurve>::ScalarSize::to_usize()
+            }
+            WeierstrassCurveKind::Secp256k1 => {
+                <Secp256k1 as WeierstrassCurve>::ScalarSize::to_usize()
+            }
+        }
+    }
+
+    /// Get the size of a fixed-width signature (`r || s`) for this elliptic
+    /// curve in bytes, i.e. its `WeierstrassCurve::FixedSignatureSize`, e.g.
+    /// for sizing buffers when the curve is negotiated at runtime.
+    pub fn fixed_signature_size(self) -> usize {
+        self.scalar_size() * 2
+    }
+
+    /// Get the maximum size of an ASN.1 DER signature for this elliptic curve
+    /// in bytes, i.e. its `WeierstrassCurve::Asn1SignatureMaxSize`.
+    pub fn asn1_max_size(self) -> usize {
+        match self {
+            WeierstrassCurveKind::NistP256 => {
+                <NistP256 as WeierstrassCurve>::Asn1SignatureMaxSize::to_usize()
+            }
+            WeierstrassCurveKind::NistP384 => {
+                <NistP384 as WeierstrassCurve>::Asn1SignatureMaxSize::to_usize()
+            }
+            WeierstrassCurveKind::Secp256k1 => {
+                <Secp256k1 as WeierstrassCurve>::Asn1SignatureMaxSize::to_usize()
+            }
+        }
+    }
+
+    /// Get the SSH public key algorithm name for this particular elliptic
+    /// curve (if it's supported by SSH), as used in SSH signature blobs.
+    ///
+    /// <https://tools.ietf.org/html/rfc5656#section-6.2>
+    pub fn to_ssh_name(self) -> Option<&'static str> {
+        match self {
+            WeierstrassCurveKind::NistP256 => Some("ecdsa-sha2-nistp256"),
+            WeierstrassCurveKind::NistP384 => Some("ecdsa-sha2-nistp384"),
+            WeierstrassCurveKind::Secp256k1 => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        digest_to_scalar, NistP256, NistP384, Secp256k1, WeierstrassCurve, WeierstrassCurveKind,
+    };
+    use generic_array::typenum::{Unsigned, U2};
+    use util::be_shr1;
+
+    /// Toy curve whose 9-bit order isn't a multiple of 8 bits
+    #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+    struct NineBitCurve;
+
+    impl WeierstrassCurve for NineBitCurve {
+        const CURVE_KIND: WeierstrassCurveKind = WeierstrassCurveKind::NistP256;
+        const ORDER: &'static [u8] = b"\x01\xff";
+        const ORDER_HALF: &'static [u8] = b"\x00\xff";
+        type ScalarSize = U2;
+        type CompressedPointSize = U2;
+        type UntaggedPointSize = U2;
+        type UncompressedPointSize = U2;
+        type Asn1SignatureMaxSize = U2;
+        type FixedSignatureSize = U2;
+    }
+
+    #[test]
+    fn test_digest_to_scalar() {
+        let digest = [0xabu8; 64];
+
+        // Byte-aligned orders truncate to the leftmost bytes
+        assert_eq!(
+            digest_to_scalar::<NistP256>(&digest).as_slice(),
+            &digest[..32]
+        );
+        assert_eq!(
+            digest_to_scalar::<NistP384>(&digest).as_slice(),
+            &digest[..48]
+        );
+
+        // Shorter digests are left-padded
+        let scalar = digest_to_scalar::<NistP384>(&digest[..32]);
+        assert_eq!(&scalar[..16], &[0u8; 16]);
+        assert_eq!(&scalar[16..], &digest[..32]);
+
+        // Otherwise, the leftmost 9 bits of 0xff80... are 0x1ff
+        assert_eq!(
+            digest_to_scalar::<NineBitCurve>(&[0xff, 0x80, 0x12]).as_slice(),
+            &[0x01, 0xff]
+        );
+        assert_eq!(
+            digest_to_scalar::<NineBitCurve>(&[0x80, 0x00, 0x12]).as_slice(),
+            &[0x01, 0x00]
+        );
+    }
+
+    #[test]
+    fn test_order_half() {
+        fn check<C: WeierstrassCurve>() {
+            let len = C::ORDER.len();
+            let mut half_order = [0u8; 48];
+            be_shr1(C::ORDER, &mut half_order[..len]);
+            assert_eq!(C::ORDER_HALF, &half_order[..len]);
+
+            // All of the curves' orders are odd, so `2 * ORDER_HALF + 1 == ORDER`
+            let mut order = [0u8; 48];
+            let mut carry = 1;
+
+            for (o, &byte) in order[..len].iter_mut().zip(C::ORDER_HALF.iter()).rev() {
+                *o = (byte << 1) | carry;
+                carry = byte >> 7;
+            }
+
+            assert_eq!(carry, 0);
+            assert_eq!(C::ORDER, &order[..len]);
+        }
+
+        check::<NistP256>();
+        check::<NistP384>();
+        check::<Secp256k1>();
+        check::<NineBitCurve>();
+    }
+
+    #[test]
+    fn test_curve_kind_sizes() {
+        fn check<C: WeierstrassCurve>() {
+            assert_eq!(C::CURVE_KIND.scalar_size(), C::ScalarSize::to_usize());
+            assert_eq!(
+                C::CURVE_KIND.fixed_signature_size(),
+                C::FixedSignatureSize::to_usize()
+            );
+            assert_eq!(
+                C::CURVE_KIND.asn1_max_size(),
+                C::Asn1SignatureMaxSize::to_usize()
+            );
+        }
+
+        check::<NistP256>();
+        check::<NistP384>();
+        check::<Secp256k1>();
+    }
+
+    #[test]
+    fn test_supports_recovery() {
+        assert!(NistP256::SUPPORTS_RECOVERY);
+        assert!(!NistP384::SUPPORTS_RECOVERY);
+        assert!(Secp256k1::SUPPORTS_RECOVERY);
+
+        // Curves don't support recovery unless they say so
+        assert!(!NineBitCurve::SUPPORTS_RECOVERY);
+    }
 }
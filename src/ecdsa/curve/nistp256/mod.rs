@@ -8,7 +8,7 @@
 
 use generic_array::typenum::{U32, U33, U64, U65, U73};
 
-use super::{WeierstrassCurve, WeierstrassCurveKind};
+use super::{RecoverableCurve, WeierstrassCurve, WeierstrassCurveKind};
 
 #[cfg(feature = "test-vectors")]
 mod test_vectors;
@@ -31,6 +31,19 @@ impl WeierstrassCurve for NistP256 {
     /// Elliptic curve kind
     const CURVE_KIND: WeierstrassCurveKind = WeierstrassCurveKind::NistP256;
 
+    /// Order of the base point (`n`)
+    const ORDER: &'static [u8] =
+        b"\xff\xff\xff\xff\x00\x00\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff\
+          \xbc\xe6\xfa\xad\xa7\x17\x9e\x84\xf3\xb9\xca\xc2\xfc\x63\x25\x51";
+
+    /// Half the order of the base point (`floor(n/2)`)
+    const ORDER_HALF: &'static [u8] =
+        b"\x7f\xff\xff\xff\x80\x00\x00\x00\x7f\xff\xff\xff\xff\xff\xff\xff\
+          \xde\x73\x7d\x56\xd3\x8b\xcf\x42\x79\xdc\xe5\x61\x7e\x31\x92\xa8";
+
+    /// Public keys can be recovered from signatures
+    const SUPPORTS_RECOVERY: bool = true;
+
     /// Random 256-bit (32-byte) private scalar
     type ScalarSize = U32;
 
@@ -55,6 +68,11 @@ impl WeierstrassCurve for NistP256 {
     type FixedSignatureSize = U64;
 }
 
+impl RecoverableCurve for NistP256 {
+    /// Fixed-sized signature followed by a 1-byte recovery ID
+    type RecoverableSignatureSize = U65;
+}
+
 /// NIST P-256 secret key
 pub type SecretKey = ::ecdsa::SecretKey<NistP256>;
 
@@ -66,3 +84,6 @@ pub type Asn1Signature = ::ecdsa::Asn1Signature<NistP256>;
 
 /// Compact, fixed-sized secp256k1 ECDSA signature
 pub type FixedSignature = ::ecdsa::FixedSignature<NistP256>;
+
+/// Recoverable NIST P-256 ECDSA signature
+pub type RecoverableSignature = ::ecdsa::RecoverableSignature<NistP256>;
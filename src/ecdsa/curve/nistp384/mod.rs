@@ -6,9 +6,13 @@
 //! This curve is part of the US National Security Agency's "Suite B" and
 //! and is widely used in protocols like TLS and the associated X.509 PKI.
 
-use generic_array::typenum::{U105, U48, U49, U96, U97};
+use generic_array::{
+    typenum::{U105, U48, U49, U96, U97},
+    GenericArray,
+};
 
 use super::{WeierstrassCurve, WeierstrassCurveKind};
+use error::Error;
 
 #[cfg(feature = "test-vectors")]
 mod test_vectors;
@@ -32,6 +36,21 @@ impl WeierstrassCurve for NistP384 {
     /// Elliptic curve kind
     const CURVE_KIND: WeierstrassCurveKind = WeierstrassCurveKind::NistP384;
 
+    /// Order of the base point (`n`)
+    const ORDER: &'static [u8] =
+        b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+          \xff\xff\xff\xff\xff\xff\xff\xff\xc7\x63\x4d\x81\xf4\x37\x2d\xdf\
+          \x58\x1a\x0d\xb2\x48\xb0\xa7\x7a\xec\xec\x19\x6a\xcc\xc5\x29\x73";
+
+    /// Half the order of the base point (`floor(n/2)`)
+    const ORDER_HALF: &'static [u8] =
+        b"\x7f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+          \xff\xff\xff\xff\xff\xff\xff\xff\xe3\xb1\xa6\xc0\xfa\x1b\x96\xef\
+          \xac\x0d\x06\xd9\x24\x58\x53\xbd\x76\x76\x0c\xb5\x66\x62\x94\xb9";
+
+    /// Recoverable signatures are not supported for this curve
+    const SUPPORTS_RECOVERY: bool = false;
+
     /// Random 384-bit (48-byte) private scalar
     type ScalarSize = U48;
 
@@ -67,3 +86,84 @@ pub type Asn1Signature = ::ecdsa::Asn1Signature<NistP384>;
 
 /// Compact, fixed-sized secp384k1 ECDSA signature
 pub type FixedSignature = ::ecdsa::FixedSignature<NistP384>;
+
+impl FixedSignature {
+    /// Parse a 96-byte P-384 `r || s` signature (48-byte scalars).
+    ///
+    /// This is `from_bytes` with an error message which names the curve,
+    /// for clearer diagnostics when a signature for another curve (e.g. a
+    /// 64-byte P-256 signature) is passed by mistake.
+    pub fn from_96_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        ensure!(
+            bytes.len() == 96,
+            SignatureInvalid,
+            "P-384 expects 96 bytes (48-byte 'r' || 48-byte 's'), got {}",
+            bytes.len()
+        );
+
+        Ok(Self::from(GenericArray::clone_from_slice(bytes)))
+    }
+}
+
+#[cfg(all(test, feature = "encoding", feature = "test-vectors"))]
+mod tests {
+    use super::{Asn1Signature, FixedSignature, SHA384_FIXED_SIZE_TEST_VECTORS};
+    use error::ErrorKind;
+
+    #[test]
+    fn test_from_96_bytes() {
+        for vector in SHA384_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_96_bytes(vector.sig).unwrap();
+            assert_eq!(signature.as_ref(), vector.sig);
+        }
+
+        let bytes = [1u8; 97];
+
+        for len in &[0, 64, 95, 97] {
+            let err = FixedSignature::from_96_bytes(&bytes[..*len]).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+
+            #[cfg(feature = "std")]
+            assert!(format!("{}", err).contains("P-384 expects 96 bytes"));
+        }
+    }
+
+    #[test]
+    fn test_asn1_signature_known_answer() {
+        // Neither scalar of the first CAVP vector has its high bit set
+        let vector = &SHA384_FIXED_SIZE_TEST_VECTORS[0];
+        let fixed_signature = FixedSignature::from_96_bytes(vector.sig).unwrap();
+        let asn1_signature = Asn1Signature::from(&fixed_signature);
+
+        let der = asn1_signature.as_ref();
+        assert_eq!(der.len(), 102);
+        assert_eq!(&der[..4], &[0x30, 0x64, 0x02, 0x30]);
+        assert_eq!(&der[4..52], &vector.sig[..48]);
+        assert_eq!(&der[52..54], &[0x02, 0x30]);
+        assert_eq!(&der[54..], &vector.sig[48..]);
+    }
+
+    #[test]
+    fn test_asn1_signature_max_size() {
+        // Both scalars need sign padding, which is the largest P-384 DER
+        // signature. At 102 bytes, the SEQUENCE contents still fit in the
+        // short-form length (i.e. the `0x81` long form isn't used).
+        let bytes = [0xffu8; 96];
+        let fixed_signature = FixedSignature::from_96_bytes(&bytes).unwrap();
+        let asn1_signature = Asn1Signature::from(&fixed_signature);
+
+        let der = asn1_signature.as_ref();
+        assert_eq!(der.len(), 104);
+        assert_eq!(&der[..5], &[0x30, 0x66, 0x02, 0x31, 0x00]);
+        assert_eq!(&der[5..53], &bytes[..48]);
+        assert_eq!(&der[53..56], &[0x02, 0x31, 0x00]);
+        assert_eq!(&der[56..], &bytes[48..]);
+        assert_eq!(Asn1Signature::length_bounds().1, der.len());
+
+        for vector in SHA384_FIXED_SIZE_TEST_VECTORS {
+            let fixed_signature = FixedSignature::from_96_bytes(vector.sig).unwrap();
+            let asn1_signature = Asn1Signature::from(&fixed_signature);
+            assert_eq!(FixedSignature::from(&asn1_signature), fixed_signature);
+        }
+    }
+}
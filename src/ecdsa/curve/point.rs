@@ -5,7 +5,7 @@
 //! <http://www.secg.org/sec1-v2.pdf>
 
 use core::marker::PhantomData;
-use generic_array::GenericArray;
+use generic_array::{typenum::Unsigned, GenericArray};
 
 use super::WeierstrassCurve;
 use error::Error;
@@ -125,6 +125,22 @@ where
     pub fn into_bytes(self) -> GenericArray<u8, C::UncompressedPointSize> {
         self.bytes
     }
+
+    /// Compress this point, i.e. serialize its `x` coordinate tagged with
+    /// the parity of its `y` coordinate (`0x02` if even, `0x03` if odd).
+    pub fn to_compressed(&self) -> CompressedCurvePoint<C> {
+        let x_len = C::CompressedPointSize::to_usize() - 1;
+        let y_is_odd = self.bytes[self.bytes.len() - 1] & 1;
+
+        let mut bytes = GenericArray::default();
+        bytes[0] = 0x02 | y_is_odd;
+        bytes[1..].copy_from_slice(&self.bytes[1..=x_len]);
+
+        CompressedCurvePoint {
+            bytes,
+            curve: PhantomData,
+        }
+    }
 }
 
 impl<C: WeierstrassCurve> AsRef<[u8]> for UncompressedCurvePoint<C> {
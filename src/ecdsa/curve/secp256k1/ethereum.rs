@@ -0,0 +1,130 @@
+//! Ethereum's 65-byte `r || s || v` signature serialization
+
+use generic_array::typenum::Unsigned;
+
+use super::{FixedSignature, RecoverableSignature, Secp256k1};
+use curve::WeierstrassCurve;
+use error::Error;
+use Signature;
+
+/// Size of an Ethereum signature (`r || s || v`)
+const ETH_SIGNATURE_SIZE: usize = 65;
+
+/// Offset added to the recovery ID in Ethereum's legacy `v` encoding
+const ETH_RECOVERY_ID_OFFSET: u8 = 27;
+
+impl FixedSignature {
+    /// Parse the `r || s` portion of a 65-byte Ethereum `r || s || v`
+    /// signature, discarding the trailing `v`.
+    ///
+    /// Returns `ParseError` if `bytes` isn't 65 bytes, and `SignatureInvalid`
+    /// if `r` or `s` is zero or not less than the order of the curve.
+    pub fn from_eth_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        ensure!(
+            bytes.len() == ETH_SIGNATURE_SIZE,
+            ParseError,
+            "expected {}-byte Ethereum signature (got {})",
+            ETH_SIGNATURE_SIZE,
+            bytes.len()
+        );
+
+        let (r, s) = bytes[..ETH_SIGNATURE_SIZE - 1].split_at(32);
+
+        ensure!(
+            is_valid_scalar(r) && is_valid_scalar(s),
+            SignatureInvalid,
+            "Ethereum signature 'r' and 's' must be in the range [1, n)"
+        );
+
+        Self::from_bytes(&bytes[..ETH_SIGNATURE_SIZE - 1])
+    }
+}
+
+impl RecoverableSignature {
+    /// Parse a 65-byte Ethereum `r || s || v` signature, keeping `v` as the
+    /// recovery ID.
+    ///
+    /// `v` may be either a raw recovery ID (`0`-`3`) or use Ethereum's legacy
+    /// encoding with an offset of 27 (i.e. `27`/`28`), which is normalized
+    /// to the raw recovery ID. EIP-155 `v` values with a chain ID folded in
+    /// aren't supported and are `SignatureInvalid`, as is an invalid `r` or
+    /// `s` (see `FixedSignature::from_eth_bytes`).
+    pub fn from_eth_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let signature = FixedSignature::from_eth_bytes(bytes)?;
+        let v = bytes[ETH_SIGNATURE_SIZE - 1];
+
+        let recovery_id = if v >= ETH_RECOVERY_ID_OFFSET {
+            v - ETH_RECOVERY_ID_OFFSET
+        } else {
+            v
+        };
+
+        Self::new(&signature, recovery_id)
+    }
+}
+
+/// Is the given big endian scalar in the range `[1, n)`?
+fn is_valid_scalar(scalar: &[u8]) -> bool {
+    debug_assert_eq!(
+        scalar.len(),
+        <Secp256k1 as WeierstrassCurve>::ScalarSize::to_usize()
+    );
+
+    scalar.iter().any(|&byte| byte != 0) && scalar < Secp256k1::ORDER
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{FixedSignature, RecoverableSignature, Secp256k1};
+    use curve::WeierstrassCurve;
+    use error::ErrorKind;
+
+    /// Build an Ethereum signature with the given `v` from arbitrary valid
+    /// `r` and `s` values
+    fn eth_bytes(v: u8) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[31] = 1;
+        bytes[32..64].copy_from_slice(&[0x42; 32]);
+        bytes[64] = v;
+        bytes
+    }
+
+    #[test]
+    fn test_fixed_signature_from_eth_bytes() {
+        let bytes = eth_bytes(28);
+        let signature = FixedSignature::from_eth_bytes(&bytes).unwrap();
+        assert_eq!(signature.as_ref(), &bytes[..64]);
+    }
+
+    #[test]
+    fn test_fixed_signature_from_eth_bytes_invalid() {
+        let err = FixedSignature::from_eth_bytes(&eth_bytes(27)[..64]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+
+        // r = 0
+        let mut bytes = eth_bytes(27);
+        bytes[31] = 0;
+        let err = FixedSignature::from_eth_bytes(&bytes).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+
+        // s = n
+        let mut bytes = eth_bytes(27);
+        bytes[32..64].copy_from_slice(Secp256k1::ORDER);
+        let err = FixedSignature::from_eth_bytes(&bytes).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn test_recoverable_signature_from_eth_bytes() {
+        for &(v, recovery_id) in &[(0, 0), (1, 1), (27, 0), (28, 1)] {
+            let bytes = eth_bytes(v);
+            let signature = RecoverableSignature::from_eth_bytes(&bytes).unwrap();
+            assert_eq!(signature.recovery_id(), recovery_id);
+            assert_eq!(signature.to_fixed_signature().as_ref(), &bytes[..64]);
+        }
+
+        // EIP-155 `v` for chain ID 1
+        let err = RecoverableSignature::from_eth_bytes(&eth_bytes(37)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+}
@@ -5,12 +5,13 @@
 //!
 //! This curve is most notable for its use in Bitcoin and other cryptocurrencies.
 
+mod ethereum;
 #[cfg(feature = "test-vectors")]
 mod test_vectors;
 
 use generic_array::typenum::{U32, U33, U64, U65, U73};
 
-use super::{WeierstrassCurve, WeierstrassCurveKind};
+use super::{RecoverableCurve, WeierstrassCurve, WeierstrassCurveKind};
 
 #[cfg(feature = "test-vectors")]
 pub use self::test_vectors::SHA256_FIXED_SIZE_TEST_VECTORS;
@@ -23,6 +24,19 @@ impl WeierstrassCurve for Secp256k1 {
     /// Elliptic curve kind
     const CURVE_KIND: WeierstrassCurveKind = WeierstrassCurveKind::Secp256k1;
 
+    /// Order of the base point (`n`)
+    const ORDER: &'static [u8] =
+        b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\
+          \xba\xae\xdc\xe6\xaf\x48\xa0\x3b\xbf\xd2\x5e\x8c\xd0\x36\x41\x41";
+
+    /// Half the order of the base point (`floor(n/2)`)
+    const ORDER_HALF: &'static [u8] =
+        b"\x7f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+          \x5d\x57\x6e\x73\x57\xa4\x50\x1d\xdf\xe9\x2f\x46\x68\x1b\x20\xa0";
+
+    /// Public keys can be recovered from signatures
+    const SUPPORTS_RECOVERY: bool = true;
+
     /// Random 256-bit (32-byte) private scalar
     type ScalarSize = U32;
 
@@ -46,6 +60,11 @@ impl WeierstrassCurve for Secp256k1 {
     type FixedSignatureSize = U64;
 }
 
+impl RecoverableCurve for Secp256k1 {
+    /// Fixed-sized signature followed by a 1-byte recovery ID
+    type RecoverableSignatureSize = U65;
+}
+
 /// secp256k1 secret key
 pub type SecretKey = ::ecdsa::SecretKey<Secp256k1>;
 
@@ -57,3 +76,6 @@ pub type Asn1Signature = ::ecdsa::Asn1Signature<Secp256k1>;
 
 /// Compact, fixed-sized secp256k1 ECDSA signature
 pub type FixedSignature = ::ecdsa::FixedSignature<Secp256k1>;
+
+/// Recoverable secp256k1 ECDSA signature
+pub type RecoverableSignature = ::ecdsa::RecoverableSignature<Secp256k1>;
@@ -0,0 +1,199 @@
+//! Dynamic dispatch for ECDSA signatures and verifiers whose elliptic curve
+//! is only known at runtime (e.g. when negotiated by a protocol)
+
+use core::fmt::{self, Debug};
+
+use super::FixedSignature;
+use curve::{NistP256, NistP384, Secp256k1, WeierstrassCurve, WeierstrassCurveKind};
+use error::Error;
+use verifier::{Sha256Verifier, Sha384Verifier};
+
+/// Fixed-sized ECDSA signature over an elliptic curve selected at runtime
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DynSignature {
+    /// NIST P-256 ECDSA signature
+    NistP256(FixedSignature<NistP256>),
+
+    /// NIST P-384 ECDSA signature
+    NistP384(FixedSignature<NistP384>),
+
+    /// secp256k1 ECDSA signature
+    Secp256k1(FixedSignature<Secp256k1>),
+}
+
+impl DynSignature {
+    /// Get the elliptic curve this signature was produced with
+    pub fn curve_kind(&self) -> WeierstrassCurveKind {
+        match self {
+            DynSignature::NistP256(_) => NistP256::CURVE_KIND,
+            DynSignature::NistP384(_) => NistP384::CURVE_KIND,
+            DynSignature::Secp256k1(_) => Secp256k1::CURVE_KIND,
+        }
+    }
+
+    /// Get the size of the `r` and `s` scalars of this signature (in bytes),
+    /// i.e. half the size of the signature itself
+    pub fn scalar_size(&self) -> usize {
+        self.curve_kind().scalar_size()
+    }
+}
+
+impl AsRef<[u8]> for DynSignature {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            DynSignature::NistP256(ref sig) => sig.as_ref(),
+            DynSignature::NistP384(ref sig) => sig.as_ref(),
+            DynSignature::Secp256k1(ref sig) => sig.as_ref(),
+        }
+    }
+}
+
+/// ECDSA verifier for a public key whose elliptic curve is selected at
+/// runtime. Each variant borrows a provider's verifier for that curve,
+/// using the curve's customary SHA-2 digest.
+pub enum DynVerifier<'a> {
+    /// NIST P-256 ECDSA verifier (SHA-256)
+    NistP256(&'a Sha256Verifier<FixedSignature<NistP256>>),
+
+    /// NIST P-384 ECDSA verifier (SHA-384)
+    NistP384(&'a Sha384Verifier<FixedSignature<NistP384>>),
+
+    /// secp256k1 ECDSA verifier (SHA-256)
+    Secp256k1(&'a Sha256Verifier<FixedSignature<Secp256k1>>),
+}
+
+impl<'a> DynVerifier<'a> {
+    /// Get the elliptic curve of the public key this verifier checks
+    /// signatures against
+    pub fn curve_kind(&self) -> WeierstrassCurveKind {
+        match self {
+            DynVerifier::NistP256(_) => NistP256::CURVE_KIND,
+            DynVerifier::NistP384(_) => NistP384::CURVE_KIND,
+            DynVerifier::Secp256k1(_) => Secp256k1::CURVE_KIND,
+        }
+    }
+
+    /// Verify the given message against a signature over a runtime-selected
+    /// curve.
+    ///
+    /// Returns `ErrorKind::Unsupported` if the signature's curve differs from
+    /// the curve of the verifier's public key (e.g. a P-256 signature and a
+    /// secp256k1 public key).
+    pub fn verify(&self, msg: &[u8], signature: &DynSignature) -> Result<(), Error> {
+        match (self, signature) {
+            (&DynVerifier::NistP256(verifier), &DynSignature::NistP256(ref sig)) => {
+                verifier.verify_sha256(msg, sig)
+            }
+            (&DynVerifier::NistP384(verifier), &DynSignature::NistP384(ref sig)) => {
+                verifier.verify_sha384(msg, sig)
+            }
+            (&DynVerifier::Secp256k1(verifier), &DynSignature::Secp256k1(ref sig)) => {
+                verifier.verify_sha256(msg, sig)
+            }
+            _ => fail!(
+                Unsupported,
+                "can't verify {:?} signature with {:?} public key",
+                signature.curve_kind(),
+                self.curve_kind()
+            ),
+        }
+    }
+}
+
+impl<'a> Debug for DynVerifier<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "signatory::ecdsa::DynVerifier<{:?}>", self.curve_kind())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynSignature, DynVerifier};
+    use curve::{WeierstrassCurve, WeierstrassCurveKind};
+    use ecdsa::FixedSignature;
+    use error::{Error, ErrorKind};
+    use verifier::{Sha256Verifier, Sha384Verifier};
+
+    /// Verifier which accepts any signature over its curve
+    struct TestVerifier;
+
+    impl<C: WeierstrassCurve> Sha256Verifier<FixedSignature<C>> for TestVerifier {
+        fn verify_sha256(&self, _msg: &[u8], _sig: &FixedSignature<C>) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    impl<C: WeierstrassCurve> Sha384Verifier<FixedSignature<C>> for TestVerifier {
+        fn verify_sha384(&self, _msg: &[u8], _sig: &FixedSignature<C>) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_dyn_verifier_matching_curves() {
+        let verifier = TestVerifier;
+        let pairs = [
+            (
+                DynVerifier::NistP256(&verifier),
+                DynSignature::NistP256(FixedSignature::default()),
+            ),
+            (
+                DynVerifier::NistP384(&verifier),
+                DynSignature::NistP384(FixedSignature::default()),
+            ),
+            (
+                DynVerifier::Secp256k1(&verifier),
+                DynSignature::Secp256k1(FixedSignature::default()),
+            ),
+        ];
+
+        for &(ref dyn_verifier, ref signature) in pairs.iter() {
+            assert!(dyn_verifier.verify(b"example", signature).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_dyn_verifier_mismatched_curves() {
+        let verifier = TestVerifier;
+        let pairs = [
+            (
+                DynVerifier::NistP256(&verifier),
+                DynSignature::Secp256k1(FixedSignature::default()),
+            ),
+            (
+                DynVerifier::NistP384(&verifier),
+                DynSignature::NistP256(FixedSignature::default()),
+            ),
+            (
+                DynVerifier::Secp256k1(&verifier),
+                DynSignature::NistP384(FixedSignature::default()),
+            ),
+        ];
+
+        for &(ref dyn_verifier, ref signature) in pairs.iter() {
+            let err = dyn_verifier.verify(b"example", signature).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Unsupported);
+        }
+    }
+
+    #[test]
+    fn test_dyn_signature_scalar_size() {
+        let signatures = [
+            DynSignature::NistP256(FixedSignature::default()),
+            DynSignature::NistP384(FixedSignature::default()),
+            DynSignature::Secp256k1(FixedSignature::default()),
+        ];
+
+        let expected = [
+            (WeierstrassCurveKind::NistP256, 32),
+            (WeierstrassCurveKind::NistP384, 48),
+            (WeierstrassCurveKind::Secp256k1, 32),
+        ];
+
+        for (signature, &(curve_kind, scalar_size)) in signatures.iter().zip(expected.iter()) {
+            assert_eq!(signature.curve_kind(), curve_kind);
+            assert_eq!(signature.scalar_size(), scalar_size);
+            assert_eq!(signature.as_ref().len(), scalar_size * 2);
+        }
+    }
+}
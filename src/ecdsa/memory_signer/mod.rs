@@ -0,0 +1,496 @@
+//! In-memory software ECDSA signer for tests which don't have access to an
+//! HSM or a provider crate.
+//!
+//! Signatures are computed with deterministic nonces per RFC 6979, so the
+//! same key, digest, and message always produce the same signature.
+//!
+//! **This signer is NOT constant-time and is only intended for testing!**
+//! Enable Signatory's `test-signer` cargo feature to use it.
+
+mod rfc6979;
+
+use core::marker::PhantomData;
+use digest::Digest;
+use generic_array::{typenum::Unsigned, GenericArray};
+use zeroize::Zeroize;
+
+use self::rfc6979::HmacDrbg;
+use curve::arithmetic::{is_zero, Curve, Limbs, Modulus, MAX_LIMBS};
+use curve::{digest_to_scalar, RecoverableCurve, WeierstrassCurve};
+#[cfg(feature = "encoding")]
+use ecdsa::Asn1Signature;
+use ecdsa::{FixedSignature, PublicKey, RecoverableSignature, SecretKey};
+use error::Error;
+use {DigestSigner, PublicKeyed};
+
+/// Maximum number of nonces tried by
+/// `MemorySigner::sign_digest_with_recovery_parity`. Each nonce yields either
+/// parity with probability 1/2.
+pub const MAX_RECOVERY_PARITY_ATTEMPTS: u32 = 64;
+
+/// ECDSA signer which holds a `SecretKey` in memory and signs in software
+/// using deterministic nonces (RFC 6979). Supports NIST P-256, NIST P-384,
+/// and secp256k1.
+///
+/// **Not constant-time: only use this signer in tests!**
+pub struct MemorySigner<C: WeierstrassCurve> {
+    /// Secret scalar `d` serialized as a big endian integer, padded with
+    /// trailing zeroes after the first `C::ScalarSize` bytes
+    secret_key: [u8; MAX_LIMBS * 4],
+
+    /// Placeholder for elliptic curve type
+    curve: PhantomData<C>,
+}
+
+impl<'a, C> From<&'a SecretKey<C>> for MemorySigner<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Create a new in-memory signer from the given `SecretKey`
+    fn from(secret_key: &'a SecretKey<C>) -> Self {
+        let mut bytes = [0u8; MAX_LIMBS * 4];
+        bytes[..C::ScalarSize::to_usize()].copy_from_slice(secret_key.as_secret_slice());
+
+        MemorySigner {
+            secret_key: bytes,
+            curve: PhantomData,
+        }
+    }
+}
+
+impl<C> MemorySigner<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Parse the secret scalar `d`, ensuring it's in the range `[1, n)`
+    fn secret_scalar(&self, n: &Modulus) -> Result<Limbs, Error> {
+        let d = n.parse(&self.secret_key[..C::ScalarSize::to_usize()]);
+
+        ensure!(
+            n.is_nonzero_residue(&d),
+            KeyInvalid,
+            "{:?} secret key is out of range",
+            C::CURVE_KIND
+        );
+
+        Ok(d)
+    }
+
+    /// Compute a fixed-width signature of the given digest, mixing the given
+    /// additional data `k'` into the RFC 6979 nonce derivation (which is the
+    /// same as plain RFC 6979 if `added_entropy` is empty)
+    fn sign_fixed<D: Digest>(
+        &self,
+        digest: D,
+        added_entropy: &[u8],
+    ) -> Result<FixedSignature<C>, Error> {
+        self.sign_hash::<D>(&digest.result(), added_entropy)
+            .map(|(signature, _)| signature)
+    }
+
+    /// Compute a fixed-width signature of the given output of the digest
+    /// `D` (see `sign_fixed`), along with its recovery ID, i.e. the parity of
+    /// `R.y` plus 2 if `R.x` was reduced modulo `n`
+    fn sign_hash<D: Digest>(
+        &self,
+        hash: &[u8],
+        added_entropy: &[u8],
+    ) -> Result<(FixedSignature<C>, u8), Error> {
+        let n = Modulus::new(C::ORDER);
+        let d = self.secret_scalar(&n)?;
+        let curve = Curve::new(C::CURVE_KIND);
+
+        // `bits2int(H(m)) mod n`, which is also `bits2octets` for RFC 6979
+        let z = n.reduce_once(&n.parse(&digest_to_scalar::<C>(hash)));
+        let mut z_bytes: GenericArray<u8, C::ScalarSize> = GenericArray::default();
+        n.serialize(&z, &mut z_bytes);
+
+        let mut drbg = HmacDrbg::<D>::new(
+            &self.secret_key[..C::ScalarSize::to_usize()],
+            &z_bytes,
+            added_entropy,
+        );
+
+        loop {
+            let mut t: GenericArray<u8, C::ScalarSize> = GenericArray::default();
+            drbg.fill_bytes(&mut t);
+            let k = n.parse(&digest_to_scalar::<C>(&t));
+
+            if n.is_nonzero_residue(&k) {
+                // `r = x(k * G) mod n` (`x < p < 2n` for all supported curves)
+                if let Some((x, y)) = curve.mul_base(&k) {
+                    let r = n.reduce_once(&x);
+
+                    if !is_zero(&r) {
+                        // `s = k^-1 * (z + r * d) mod n`
+                        let k_inv = n.invert(&n.to_montgomery(&k));
+                        let rd = n.mul(&n.to_montgomery(&r), &n.to_montgomery(&d));
+                        let sum = n.add(&n.to_montgomery(&z), &rd);
+                        let s = n.reduce_montgomery(&n.mul(&k_inv, &sum));
+
+                        if !is_zero(&s) {
+                            let mut r_bytes: GenericArray<u8, C::ScalarSize> =
+                                GenericArray::default();
+                            let mut s_bytes: GenericArray<u8, C::ScalarSize> =
+                                GenericArray::default();
+
+                            n.serialize(&r, &mut r_bytes);
+                            n.serialize(&s, &mut s_bytes);
+
+                            let recovery_id = (y[0] & 1) as u8 | if r != x { 2 } else { 0 };
+                            let signature = FixedSignature::from_scalars(&r_bytes, &s_bytes)?;
+                            return Ok((signature, recovery_id));
+                        }
+                    }
+                }
+            }
+
+            drbg.retry();
+        }
+    }
+}
+
+impl<C> MemorySigner<C>
+where
+    C: RecoverableCurve,
+{
+    /// Compute a recoverable signature of the given digest whose recovery ID
+    /// has the given `R.y` parity (even if `want_even_y`, odd otherwise), by
+    /// grinding the deterministic nonce.
+    ///
+    /// The first attempt is the ordinary RFC 6979 signature, and subsequent
+    /// attempts pass an incrementing counter as the RFC 6979 additional data
+    /// `k'` (see `DigestSigner::sign_digest_deterministic_with_entropy`), so
+    /// the result is still deterministic. If no attempt within
+    /// `MAX_RECOVERY_PARITY_ATTEMPTS` has the wanted parity, returns an
+    /// `ErrorKind::ProviderError`.
+    ///
+    /// Note that normalizing the resulting signature to "low S" form flips
+    /// the parity of its recovery ID if `s` is high.
+    pub fn sign_digest_with_recovery_parity<D: Digest>(
+        &self,
+        digest: D,
+        want_even_y: bool,
+    ) -> Result<RecoverableSignature<C>, Error> {
+        let hash = digest.result();
+
+        for counter in 0..MAX_RECOVERY_PARITY_ATTEMPTS {
+            // Counter as little endian additional data, zero-padded to 32 bytes
+            let mut added_entropy = [0u8; 32];
+
+            for (i, byte) in added_entropy[..4].iter_mut().enumerate() {
+                *byte = (counter >> (i * 8)) as u8;
+            }
+
+            let (signature, recovery_id) = self.sign_hash::<D>(
+                &hash,
+                if counter == 0 {
+                    &[]
+                } else {
+                    &added_entropy[..]
+                },
+            )?;
+
+            if (recovery_id & 1 == 0) == want_even_y {
+                return RecoverableSignature::new(&signature, recovery_id);
+            }
+        }
+
+        fail!(
+            ProviderError,
+            "no signature with {} recovery parity in {} attempts",
+            if want_even_y { "even" } else { "odd" },
+            MAX_RECOVERY_PARITY_ATTEMPTS
+        )
+    }
+}
+
+impl<C> Drop for MemorySigner<C>
+where
+    C: WeierstrassCurve,
+{
+    fn drop(&mut self) {
+        self.secret_key.as_mut().zeroize();
+    }
+}
+
+impl<C> PublicKeyed<PublicKey<C>> for MemorySigner<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Compute the (uncompressed) public key `d * G` for this signer
+    fn public_key(&self) -> Result<PublicKey<C>, Error> {
+        let n = Modulus::new(C::ORDER);
+        let d = self.secret_scalar(&n)?;
+        let curve = Curve::new(C::CURVE_KIND);
+
+        // `d` is in `[1, n)`, so `d * G` can't be the point at infinity
+        let (x, y) = curve.mul_base(&d).unwrap();
+        let mut bytes: GenericArray<u8, C::UntaggedPointSize> = GenericArray::default();
+        let (x_bytes, y_bytes) = bytes.split_at_mut(C::UntaggedPointSize::to_usize() / 2);
+
+        curve.p.serialize(&x, x_bytes);
+        curve.p.serialize(&y, y_bytes);
+
+        Ok(PublicKey::from_untagged_point(&bytes))
+    }
+}
+
+impl<D, C> DigestSigner<D, FixedSignature<C>> for MemorySigner<C>
+where
+    D: Digest,
+    C: WeierstrassCurve,
+{
+    /// Compute a compact, fixed-sized signature of the given digest
+    fn sign(&self, digest: D) -> Result<FixedSignature<C>, Error> {
+        self.sign_fixed(digest, &[])
+    }
+
+    /// Compute a compact, fixed-sized signature of the given digest with the
+    /// given added entropy (RFC 6979 section 3.6). With empty
+    /// `added_entropy`, this is the same as `sign`.
+    fn sign_digest_deterministic_with_entropy(
+        &self,
+        digest: D,
+        added_entropy: &[u8],
+    ) -> Result<FixedSignature<C>, Error> {
+        self.sign_fixed(digest, added_entropy)
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl<D, C> DigestSigner<D, Asn1Signature<C>> for MemorySigner<C>
+where
+    D: Digest,
+    C: WeierstrassCurve,
+{
+    /// Compute an ASN.1 DER-encoded signature of the given digest
+    fn sign(&self, digest: D) -> Result<Asn1Signature<C>, Error> {
+        Ok(Asn1Signature::from(&self.sign_fixed(digest, &[])?))
+    }
+
+    /// Compute an ASN.1 DER-encoded signature of the given digest with the
+    /// given added entropy (RFC 6979 section 3.6)
+    fn sign_digest_deterministic_with_entropy(
+        &self,
+        digest: D,
+        added_entropy: &[u8],
+    ) -> Result<Asn1Signature<C>, Error> {
+        Ok(Asn1Signature::from(
+            &self.sign_fixed(digest, added_entropy)?,
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "sha2", feature = "test-vectors"))]
+mod tests {
+    use digest::Digest;
+    use sha2::{Sha256, Sha384};
+
+    use super::MemorySigner;
+    use curve::{secp256k1, NistP256, NistP384};
+    use ecdsa::{FixedSignature, PublicKey, SecretKey};
+    use {DigestSigner, PublicKeyed};
+
+    /// RFC 6979 appendix A.2.5: P-256 with SHA-256, message "sample"
+    #[test]
+    fn test_rfc6979_p256() {
+        let secret_key = SecretKey::<NistP256>::from_bytes(
+            b"\xc9\xaf\xa9\xd8\x45\xba\x75\x16\x6b\x5c\x21\x57\x67\xb1\xd6\x93\
+              \x4e\x50\xc3\xdb\x36\xe8\x9b\x12\x7b\x8a\x62\x2b\x12\x0f\x67\x21",
+        )
+        .unwrap();
+        let signer = MemorySigner::from(&secret_key);
+
+        let public_key = PublicKey::<NistP256>::from_bytes(
+            &b"\x04\x60\xfe\xd4\xba\x25\x5a\x9d\x31\xc9\x61\xeb\x74\xc6\x35\x6d\x68\
+               \xc0\x49\xb8\x92\x3b\x61\xfa\x6c\xe6\x69\x62\x2e\x60\xf2\x9f\xb6\
+               \x79\x03\xfe\x10\x08\xb8\xbc\x99\xa4\x1a\xe9\xe9\x56\x28\xbc\x64\
+               \xf2\xf1\xb2\x0c\x2d\x7e\x9f\x51\x77\xa3\xc2\x94\xd4\x46\x22\x99"[..],
+        )
+        .unwrap();
+        assert_eq!(signer.public_key().unwrap(), public_key);
+
+        let mut digest = Sha256::default();
+        digest.input(b"sample");
+        let signature: FixedSignature<NistP256> = signer.sign(digest).unwrap();
+
+        assert_eq!(
+            signature.as_ref(),
+            &b"\xef\xd4\x8b\x2a\xac\xb6\xa8\xfd\x11\x40\xdd\x9c\xd4\x5e\x81\xd6\
+               \x9d\x2c\x87\x7b\x56\xaa\xf9\x91\xc3\x4d\x0e\xa8\x4e\xaf\x37\x16\
+               \xf7\xcb\x1c\x94\x2d\x65\x7c\x41\xd4\x36\xc7\xa1\xb6\xe2\x9f\x65\
+               \xf3\xe9\x00\xdb\xb9\xaf\xf4\x06\x4d\xc4\xab\x2f\x84\x3a\xcd\xa8"[..]
+        );
+    }
+
+    /// RFC 6979 appendix A.2.6: P-384 with SHA-384, message "sample"
+    #[test]
+    fn test_rfc6979_p384() {
+        let secret_key = SecretKey::<NistP384>::from_bytes(
+            b"\x6b\x9d\x3d\xad\x2e\x1b\x8c\x1c\x05\xb1\x98\x75\xb6\x65\x9f\x4d\
+              \xe2\x3c\x3b\x66\x7b\xf2\x97\xba\x9a\xa4\x77\x40\x78\x71\x37\xd8\
+              \x96\xd5\x72\x4e\x4c\x70\xa8\x25\xf8\x72\xc9\xea\x60\xd2\xed\xf5",
+        )
+        .unwrap();
+        let signer = MemorySigner::from(&secret_key);
+
+        let mut digest = Sha384::default();
+        digest.input(b"sample");
+        let signature: FixedSignature<NistP384> = signer.sign(digest).unwrap();
+
+        assert_eq!(
+            signature.as_ref(),
+            &b"\x94\xed\xbb\x92\xa5\xec\xb8\xaa\xd4\x73\x6e\x56\xc6\x91\x91\x6b\
+               \x3f\x88\x14\x06\x66\xce\x9f\xa7\x3d\x64\xc4\xea\x95\xad\x13\x3c\
+               \x81\xa6\x48\x15\x2e\x44\xac\xf9\x6e\x36\xdd\x1e\x80\xfa\xbe\x46\
+               \x99\xef\x4a\xeb\x15\xf1\x78\xce\xa1\xfe\x40\xdb\x26\x03\x13\x8f\
+               \x13\x0e\x74\x0a\x19\x62\x45\x26\x20\x3b\x63\x51\xd0\xa3\xa9\x4f\
+               \xa3\x29\xc1\x45\x78\x6e\x67\x9e\x7b\x82\xc7\x1a\x38\x62\x8a\xc8"[..]
+        );
+    }
+
+    /// libsecp256k1 also uses RFC 6979 (but always produces low-S signatures)
+    #[test]
+    fn test_secp256k1_vectors() {
+        for vector in secp256k1::SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signer = MemorySigner::from(&secp256k1::SecretKey::from_bytes(vector.sk).unwrap());
+            let public_key = secp256k1::PublicKey::from_bytes(vector.pk).unwrap();
+            assert!(signer.public_key().unwrap().same_point(&public_key));
+
+            let mut digest = Sha256::default();
+            digest.input(vector.msg);
+            let mut signature: secp256k1::FixedSignature = signer.sign(digest).unwrap();
+            signature.normalize_s();
+
+            assert_eq!(signature.as_ref(), vector.sig);
+        }
+    }
+
+    #[test]
+    fn test_sign_digest_deterministic_with_entropy() {
+        let vector = &secp256k1::SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = MemorySigner::from(&secp256k1::SecretKey::from_bytes(vector.sk).unwrap());
+
+        let mut digest = Sha256::default();
+        digest.input(vector.msg);
+
+        let sign_with_entropy = |added_entropy: &[u8]| -> secp256k1::FixedSignature {
+            signer
+                .sign_digest_deterministic_with_entropy(digest.clone(), added_entropy)
+                .unwrap()
+        };
+
+        // Without added entropy, signatures match the plain RFC 6979 ones
+        let signature: secp256k1::FixedSignature = signer.sign(digest.clone()).unwrap();
+        assert_eq!(sign_with_entropy(&[]), signature);
+
+        let blinded = sign_with_entropy(&[0x42; 32]);
+        assert_ne!(blinded, signature);
+
+        // Same as libsecp256k1 with the same 32 bytes of extra nonce data
+        let mut normalized = blinded.clone();
+        normalized.normalize_s();
+        assert_eq!(
+            normalized.as_ref(),
+            &b"\xc7\x56\x41\xf6\x4b\x77\x9f\x82\xd3\xd7\x1c\x8d\x17\xf9\xf5\x7f\
+               \x3a\x24\x3a\x4e\x21\xeb\x64\xb0\x87\x1d\x53\x5b\xc4\x89\x7d\x7f\
+               \x15\x8a\xe1\xf8\x89\x2c\x46\x44\x36\xcd\x77\xac\x5c\xf4\x90\x8f\
+               \xe4\xad\x1b\xab\x32\x21\x41\x1f\xf6\xf5\x87\x54\x1d\x55\xf8\x5b"[..]
+        );
+        assert_eq!(sign_with_entropy(&[0x42; 32]), blinded);
+        assert_ne!(sign_with_entropy(&[0x43; 32]), blinded);
+
+        // DER signatures are the DER encoding of the fixed-width ones
+        #[cfg(feature = "encoding")]
+        {
+            let asn1_signature: secp256k1::Asn1Signature = signer
+                .sign_digest_deterministic_with_entropy(digest, &[0x42; 32])
+                .unwrap();
+            assert_eq!(asn1_signature, secp256k1::Asn1Signature::from(&blinded));
+        }
+    }
+
+    #[test]
+    fn test_sign_digest_with_recovery_parity() {
+        let mut ground_any = false;
+
+        for vector in secp256k1::SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signer = MemorySigner::from(&secp256k1::SecretKey::from_bytes(vector.sk).unwrap());
+
+            let mut digest = Sha256::default();
+            digest.input(vector.msg);
+            let signature: secp256k1::FixedSignature = signer.sign(digest.clone()).unwrap();
+
+            for &want_even_y in &[true, false] {
+                let ground = signer
+                    .sign_digest_with_recovery_parity(digest.clone(), want_even_y)
+                    .unwrap();
+                assert_eq!(ground.recovery_id() & 1 == 0, want_even_y);
+
+                // Grinding is deterministic
+                assert_eq!(
+                    signer
+                        .sign_digest_with_recovery_parity(digest.clone(), want_even_y)
+                        .unwrap(),
+                    ground
+                );
+
+                // Exactly one parity is the ordinary RFC 6979 signature
+                if ground.to_fixed_signature() != signature {
+                    ground_any = true;
+                }
+            }
+        }
+
+        assert!(ground_any);
+    }
+
+    /// The recovery ID is the parity of `R = k * G`, with `k` from RFC 6979
+    /// appendix A.2.5 (P-256 with SHA-256, message "sample")
+    #[test]
+    fn test_recovery_parity_rfc6979_p256() {
+        use curve::arithmetic::{Curve, Modulus};
+        use curve::WeierstrassCurve;
+
+        let secret_key = SecretKey::<NistP256>::from_bytes(
+            b"\xc9\xaf\xa9\xd8\x45\xba\x75\x16\x6b\x5c\x21\x57\x67\xb1\xd6\x93\
+              \x4e\x50\xc3\xdb\x36\xe8\x9b\x12\x7b\x8a\x62\x2b\x12\x0f\x67\x21",
+        )
+        .unwrap();
+        let signer = MemorySigner::from(&secret_key);
+
+        let n = Modulus::new(NistP256::ORDER);
+        let k = n.parse(
+            b"\xa6\xe3\xc5\x7d\xd0\x1a\xbe\x90\x08\x65\x38\x39\x83\x55\xdd\x4c\
+              \x3b\x17\xaa\x87\x33\x82\xb0\xf2\x4d\x61\x29\x49\x3d\x8a\xad\x60",
+        );
+        let (_, y) = Curve::new(NistP256::CURVE_KIND).mul_base(&k).unwrap();
+        let is_even_y = y[0] & 1 == 0;
+
+        let mut digest = Sha256::default();
+        digest.input(b"sample");
+        let signature: FixedSignature<NistP256> = signer.sign(digest.clone()).unwrap();
+
+        let plain = signer
+            .sign_digest_with_recovery_parity(digest.clone(), is_even_y)
+            .unwrap();
+        assert_eq!(plain.recovery_id(), if is_even_y { 0 } else { 1 });
+        assert_eq!(plain.to_fixed_signature(), signature);
+
+        let ground = signer
+            .sign_digest_with_recovery_parity(digest, !is_even_y)
+            .unwrap();
+        assert_eq!(ground.recovery_id() & 1, plain.recovery_id() ^ 1);
+        assert_ne!(ground.to_fixed_signature(), signature);
+    }
+
+    #[test]
+    fn test_secret_key_out_of_range() {
+        let signer = MemorySigner::from(&SecretKey::<NistP256>::from_bytes([0u8; 32]).unwrap());
+        assert!(signer.public_key().is_err());
+
+        let result: Result<FixedSignature<NistP256>, _> = signer.sign(Sha256::default());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! Deterministic nonce generation as described in RFC 6979 section 3.2:
+//! <https://tools.ietf.org/html/rfc6979#section-3.2>
+//!
+//! Optionally with additional data `k'` as described in section 3.6:
+//! <https://tools.ietf.org/html/rfc6979#section-3.6>
+
+use digest::Digest;
+use generic_array::{typenum::Unsigned, GenericArray};
+use zeroize::Zeroize;
+
+/// HMAC_DRBG instantiated with the secret key and message digest, producing
+/// candidate nonces `k`
+pub(super) struct HmacDrbg<D: Digest> {
+    /// HMAC key (a.k.a. `K`)
+    k: GenericArray<u8, D::OutputSize>,
+
+    /// Chaining value (a.k.a. `V`)
+    v: GenericArray<u8, D::OutputSize>,
+}
+
+impl<D> HmacDrbg<D>
+where
+    D: Digest,
+{
+    /// Seed the DRBG with `int2octets(x)` (the secret key),
+    /// `bits2octets(h1)` (the reduced message digest), and the additional
+    /// data `k'` (empty for plain RFC 6979), i.e. steps b-g
+    pub fn new(secret_key: &[u8], digest: &[u8], added_entropy: &[u8]) -> Self {
+        let mut drbg = HmacDrbg {
+            k: GenericArray::default(),
+            v: GenericArray::default(),
+        };
+
+        for byte in drbg.v.iter_mut() {
+            *byte = 0x01;
+        }
+
+        for &separator in &[0x00, 0x01] {
+            drbg.k = hmac::<D>(
+                &drbg.k,
+                &[&drbg.v, &[separator], secret_key, digest, added_entropy],
+            );
+            drbg.v = hmac::<D>(&drbg.k, &[&drbg.v]);
+        }
+
+        drbg
+    }
+
+    /// Fill the buffer with the next candidate `T` (step h.2)
+    pub fn fill_bytes(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(D::OutputSize::to_usize()) {
+            self.v = hmac::<D>(&self.k, &[&self.v]);
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+    }
+
+    /// Update the state after an unsuitable candidate was rejected (step h.3)
+    pub fn retry(&mut self) {
+        self.k = hmac::<D>(&self.k, &[&self.v, &[0x00]]);
+        self.v = hmac::<D>(&self.k, &[&self.v]);
+    }
+}
+
+impl<D> Drop for HmacDrbg<D>
+where
+    D: Digest,
+{
+    fn drop(&mut self) {
+        self.k.as_mut_slice().zeroize();
+        self.v.as_mut_slice().zeroize();
+    }
+}
+
+/// Compute HMAC (RFC 2104) of the concatenated `data` under the given key,
+/// which must be no longer than the digest's block size
+fn hmac<D: Digest>(key: &[u8], data: &[&[u8]]) -> GenericArray<u8, D::OutputSize> {
+    let mut pad: GenericArray<u8, D::BlockSize> = GenericArray::default();
+    debug_assert!(key.len() <= pad.len());
+
+    pad[..key.len()].copy_from_slice(key);
+    for byte in pad.iter_mut() {
+        *byte ^= 0x36;
+    }
+
+    let mut inner = D::default();
+    inner.input(&pad);
+    for bytes in data {
+        inner.input(bytes);
+    }
+    let inner_result = inner.result();
+
+    // Turn the ipad into the opad: 0x36 ^ 0x5c == 0x6a
+    for byte in pad.iter_mut() {
+        *byte ^= 0x6a;
+    }
+
+    let mut outer = D::default();
+    outer.input(&pad);
+    outer.input(&inner_result);
+    let result = outer.result();
+
+    pad.as_mut_slice().zeroize();
+    result
+}
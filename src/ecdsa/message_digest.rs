@@ -0,0 +1,335 @@
+//! Message digests for verifying ECDSA signatures of raw (prehashed) input
+
+use core::marker::PhantomData;
+use digest::Digest;
+use generic_array::GenericArray;
+
+use super::curve::{digest_to_scalar, WeierstrassCurve};
+#[cfg(feature = "encoding")]
+use super::Asn1Signature;
+use super::{FixedSignature, PublicKey};
+use error::Error;
+#[cfg(feature = "alloc")]
+use prelude::*;
+use Signature;
+
+/// Digest of a message, sized to the scalars of the curve `C`.
+///
+/// This is normally produced by hashing (with `from_message` or
+/// `from_digest`), so an unhashed message can't be passed where a digest is
+/// expected by accident. The exception is `from_prehashed_scalar_unchecked`,
+/// for message scalars computed some other way, which takes any bytes of the
+/// right size: it's up to the caller to ensure they're actually a digest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MessageDigest<C: WeierstrassCurve> {
+    /// Digest output converted to a scalar (see `curve::digest_to_scalar`)
+    bytes: GenericArray<u8, C::ScalarSize>,
+
+    /// Placeholder for elliptic curve type
+    curve: PhantomData<C>,
+}
+
+impl<C> MessageDigest<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Hash the given message with the digest function `D`
+    pub fn from_message<D: Digest>(msg: &[u8]) -> Self {
+        let mut digest = D::default();
+        digest.input(msg);
+        Self::from_digest(digest)
+    }
+
+    /// Compute a message digest from the output of the given `Digest`,
+    /// converted to a scalar as described in `curve::digest_to_scalar`.
+    pub fn from_digest<D: Digest>(digest: D) -> Self {
+        Self {
+            bytes: digest_to_scalar::<C>(&digest.result()),
+            curve: PhantomData,
+        }
+    }
+
+    /// Use the given big endian integer as the message scalar `z` as-is, i.e.
+    /// without any of the truncation applied by `from_digest`.
+    ///
+    /// This is for protocols where the message representative is already the
+    /// correct scalar (e.g. computed with a custom hash-to-scalar function).
+    /// Nothing checks that `scalar` is the output of a hash function, so
+    /// prefer `from_message` or `from_digest` when hashing a message.
+    pub fn from_prehashed_scalar_unchecked(scalar: GenericArray<u8, C::ScalarSize>) -> Self {
+        Self {
+            bytes: scalar,
+            curve: PhantomData,
+        }
+    }
+
+    /// Borrow the digest as a byte slice
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+impl<C> AsRef<[u8]> for MessageDigest<C>
+where
+    C: WeierstrassCurve,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+/// Trait for ECDSA signers which take an already computed `MessageDigest`
+/// as input (e.g. a digest computed elsewhere, or a node of a Merkle tree).
+/// The `digest` cargo feature must be enabled for this to be available.
+pub trait RawDigestSigner<C, S>: Send + Sync
+where
+    C: WeierstrassCurve,
+    S: Signature,
+{
+    /// Sign the given message digest with this signer's private key,
+    /// returning a signature.
+    fn sign_raw_digest(&self, digest: &MessageDigest<C>) -> Result<S, Error>;
+
+    /// Hash the given message with the digest function `D` (converting its
+    /// output to a scalar as with `MessageDigest::from_message`), then sign
+    /// it with this signer's private key, returning a signature.
+    fn sign_message<D>(&self, msg: &[u8]) -> Result<S, Error>
+    where
+        D: Digest,
+        Self: Sized,
+    {
+        self.sign_raw_digest(&MessageDigest::from_message::<D>(msg))
+    }
+
+    /// Sign each of the given message digests, returning signatures in the
+    /// same order. Signing stops at the first error.
+    ///
+    /// The default implementation signs the digests one at a time. Providers
+    /// with per-key setup (e.g. initializing a signing context or an HSM
+    /// session) should override it to do that setup once per batch.
+    #[cfg(feature = "alloc")]
+    fn sign_digest_batch(&self, digests: &[MessageDigest<C>]) -> Result<Vec<S>, Error> {
+        digests
+            .iter()
+            .map(|digest| self.sign_raw_digest(digest))
+            .collect()
+    }
+}
+
+/// Sign the given `MessageDigest` with the given `RawDigestSigner`.
+/// This can be used to avoid importing the `RawDigestSigner` and `Signature` traits
+pub fn sign_raw_digest<C, S>(
+    signer: &RawDigestSigner<C, S>,
+    digest: &MessageDigest<C>,
+) -> Result<S, Error>
+where
+    C: WeierstrassCurve,
+    S: Signature,
+{
+    signer.sign_raw_digest(digest)
+}
+
+/// Hash the given message with the digest function `D` and sign it with the
+/// given `RawDigestSigner` (see `RawDigestSigner::sign_message`).
+/// This can be used to avoid importing the `RawDigestSigner` and `Signature` traits
+pub fn sign_message<D, C>(
+    signer: &RawDigestSigner<C, FixedSignature<C>>,
+    msg: &[u8],
+) -> Result<FixedSignature<C>, Error>
+where
+    D: Digest,
+    C: WeierstrassCurve,
+{
+    signer.sign_raw_digest(&MessageDigest::from_message::<D>(msg))
+}
+
+/// Hash the given message with the digest function `D` and sign it with the
+/// given `RawDigestSigner` like `sign_message`, serializing the signature as
+/// ASN.1 DER.
+///
+/// Signers need only produce a `FixedSignature`: it's converted to ASN.1 DER
+/// after signing.
+#[cfg(feature = "encoding")]
+pub fn sign_message_asn1<D, C>(
+    signer: &RawDigestSigner<C, FixedSignature<C>>,
+    msg: &[u8],
+) -> Result<Asn1Signature<C>, Error>
+where
+    D: Digest,
+    C: WeierstrassCurve,
+{
+    sign_message::<D, C>(signer, msg).map(|signature| Asn1Signature::from(&signature))
+}
+
+/// Sign each of the given message digests with the given `RawDigestSigner`
+/// (see `RawDigestSigner::sign_digest_batch`).
+/// This can be used to avoid importing the `RawDigestSigner` and `Signature` traits
+#[cfg(feature = "alloc")]
+pub fn sign_digest_batch<C, S>(
+    signer: &RawDigestSigner<C, S>,
+    digests: &[MessageDigest<C>],
+) -> Result<Vec<S>, Error>
+where
+    C: WeierstrassCurve,
+    S: Signature,
+{
+    signer.sign_digest_batch(digests)
+}
+
+/// Trait for ECDSA verifiers which take an already computed `MessageDigest`
+/// as input (e.g. a digest computed elsewhere, or with a hash function which
+/// doesn't impl `Digest`).
+/// The `digest` cargo feature must be enabled for this to be available.
+pub trait RawDigestVerifier<C, S>: Send + Sync
+where
+    C: WeierstrassCurve,
+    S: Signature,
+{
+    /// Verify the signature against the given message digest using the
+    /// public key this verifier was instantiated with.
+    fn verify_raw_digest(&self, digest: &MessageDigest<C>, signature: &S) -> Result<(), Error>;
+}
+
+/// Verify the given `MessageDigest` with the given `RawDigestVerifier`.
+/// This can be used to avoid importing the `RawDigestVerifier` and `Signature` traits
+pub fn verify_raw_digest<C, S>(
+    verifier: &RawDigestVerifier<C, S>,
+    digest: &MessageDigest<C>,
+    signature: &S,
+) -> Result<(), Error>
+where
+    C: WeierstrassCurve,
+    S: Signature,
+{
+    verifier.verify_raw_digest(digest, signature)
+}
+
+/// Verify a signature of a message scalar `z` against the given public key,
+/// using the provider's verifier type `V`.
+///
+/// The `MessageDigest` is used as the final message scalar with no further
+/// processing. Verification never truncates a `MessageDigest`: the only
+/// difference from verifying the output of `MessageDigest::from_digest` (or
+/// a `DigestVerifier`) is how it was constructed, as `from_digest` truncates
+/// digests longer than the curve's order, whereas
+/// `MessageDigest::from_prehashed_scalar_unchecked` takes `z` as-is.
+pub fn verify_prehashed_scalar<V, C, S>(
+    public_key: &PublicKey<C>,
+    z: &MessageDigest<C>,
+    signature: &S,
+) -> Result<(), Error>
+where
+    V: RawDigestVerifier<C, S> + for<'a> From<&'a PublicKey<C>>,
+    C: WeierstrassCurve,
+    S: Signature,
+{
+    V::from(public_key).verify_raw_digest(z, signature)
+}
+
+#[cfg(all(test, feature = "sha2"))]
+mod tests {
+    use super::MessageDigest;
+    #[cfg(feature = "alloc")]
+    use super::{sign_digest_batch, RawDigestSigner};
+    #[cfg(feature = "alloc")]
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use curve::{NistP256, NistP384};
+    use digest::Digest;
+    #[cfg(feature = "alloc")]
+    use ecdsa::FixedSignature;
+    #[cfg(feature = "alloc")]
+    use error::Error;
+    #[cfg(feature = "alloc")]
+    use prelude::*;
+    use sha2::{Sha256, Sha384, Sha512};
+    #[cfg(feature = "alloc")]
+    use Signature;
+
+    #[test]
+    fn test_from_message() {
+        let msg = b"hello world";
+
+        let digest = MessageDigest::<NistP256>::from_message::<Sha256>(msg);
+        assert_eq!(digest.as_bytes(), Sha256::digest(msg).as_slice());
+
+        // Longer output is truncated to the leftmost bytes
+        let digest = MessageDigest::<NistP384>::from_message::<Sha512>(msg);
+        assert_eq!(digest.as_bytes(), &Sha512::digest(msg)[..48]);
+
+        // Shorter output is left-padded with zeroes
+        let digest = MessageDigest::<NistP384>::from_message::<Sha256>(msg);
+        assert_eq!(&digest.as_bytes()[..16], &[0u8; 16]);
+        assert_eq!(&digest.as_bytes()[16..], Sha256::digest(msg).as_slice());
+
+        let mut sha384 = Sha384::default();
+        sha384.input(msg);
+        assert_eq!(
+            MessageDigest::<NistP384>::from_digest(sha384).as_bytes(),
+            Sha384::digest(msg).as_slice()
+        );
+    }
+
+    #[test]
+    fn test_empty_message() {
+        // SHA-256 of the empty message
+        let expected = b"\xe3\xb0\xc4\x42\x98\xfc\x1c\x14\x9a\xfb\xf4\xc8\x99\x6f\xb9\x24\
+                         \x27\xae\x41\xe4\x64\x9b\x93\x4c\xa4\x95\x99\x1b\x78\x52\xb8\x55";
+
+        let digest = MessageDigest::<NistP256>::from_message::<Sha256>(&[]);
+        assert_eq!(digest.as_bytes(), &expected[..]);
+    }
+
+    /// Signer which "signs" a digest by repeating it, and counts how many
+    /// batches it has set up
+    #[cfg(feature = "alloc")]
+    struct BatchSigner {
+        setups: AtomicUsize,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl RawDigestSigner<NistP256, FixedSignature<NistP256>> for BatchSigner {
+        fn sign_raw_digest(
+            &self,
+            digest: &MessageDigest<NistP256>,
+        ) -> Result<FixedSignature<NistP256>, Error> {
+            FixedSignature::from_bytes([digest.as_bytes(), digest.as_bytes()].concat())
+        }
+
+        fn sign_digest_batch(
+            &self,
+            digests: &[MessageDigest<NistP256>],
+        ) -> Result<Vec<FixedSignature<NistP256>>, Error> {
+            self.setups.fetch_add(1, Ordering::SeqCst);
+
+            digests
+                .iter()
+                .map(|digest| self.sign_raw_digest(digest))
+                .collect()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sign_digest_batch() {
+        let signer = BatchSigner {
+            setups: AtomicUsize::new(0),
+        };
+
+        let digests: Vec<_> = [&b"hello"[..], b"world"]
+            .iter()
+            .map(|msg| MessageDigest::from_message::<Sha256>(msg))
+            .collect();
+
+        // The free function uses the signer's own batch implementation
+        let signatures = sign_digest_batch(&signer, &digests).unwrap();
+        assert_eq!(signer.setups.load(Ordering::SeqCst), 1);
+        assert_eq!(signatures.len(), digests.len());
+
+        for (digest, signature) in digests.iter().zip(&signatures) {
+            assert_eq!(signature, &signer.sign_raw_digest(digest).unwrap());
+        }
+
+        assert!(sign_digest_batch(&signer, &[]).unwrap().is_empty());
+        assert_eq!(signer.setups.load(Ordering::SeqCst), 2);
+    }
+}
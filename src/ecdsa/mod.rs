@@ -1,11 +1,392 @@
 //! The Elliptic Curve Digital Signature Algorithm (ECDSA) as specified in
 //! FIPS 186-4 (Digital Signature Standard)
 
+#[cfg(feature = "async")]
+mod async_signer;
+#[cfg(feature = "digest")]
+mod batch;
+#[cfg(feature = "digest")]
+mod bundle;
+#[cfg(all(feature = "digest", feature = "sha2", feature = "std"))]
+mod cache;
 pub mod curve;
+mod dynamic;
+#[cfg(feature = "test-signer")]
+mod memory_signer;
+#[cfg(feature = "digest")]
+mod message_digest;
+mod oid;
+#[cfg(all(feature = "digest", feature = "encoding"))]
+mod options;
 mod public_key;
+#[cfg(feature = "rustcrypto")]
+mod rustcrypto;
 mod secret_key;
-mod signature;
+pub(crate) mod signature;
+#[cfg(all(
+    test,
+    feature = "digest",
+    feature = "sha2",
+    feature = "std",
+    feature = "test-vectors"
+))]
+pub(crate) mod test_verifier;
+#[cfg(all(feature = "digest", feature = "std"))]
+mod timestamped;
 
-pub use self::public_key::PublicKey;
+#[cfg(feature = "async")]
+pub use self::async_signer::{AsyncDigestSigner, SyncSigner};
+#[cfg(all(feature = "alloc", feature = "digest"))]
+pub use self::batch::{verify_batch, verify_chain_collecting};
+#[cfg(feature = "digest")]
+pub use self::batch::{verify_batch_bitmap, verify_threshold, VerifyStep, MAX_BATCH_BITMAP_SIZE};
+#[cfg(feature = "digest")]
+pub use self::bundle::{verify_signature_bytes, BundleVerifier, PublicKeyBundle};
+#[cfg(all(feature = "digest", feature = "sha2", feature = "std"))]
+pub use self::cache::CachingVerifier;
+pub use self::dynamic::{DynSignature, DynVerifier};
+#[cfg(feature = "test-signer")]
+pub use self::memory_signer::{MemorySigner, MAX_RECOVERY_PARITY_ATTEMPTS};
+#[cfg(all(feature = "alloc", feature = "digest"))]
+pub use self::message_digest::sign_digest_batch;
+#[cfg(all(feature = "digest", feature = "encoding"))]
+pub use self::message_digest::sign_message_asn1;
+#[cfg(feature = "digest")]
+pub use self::message_digest::{
+    sign_message, sign_raw_digest, verify_prehashed_scalar, verify_raw_digest, MessageDigest,
+    RawDigestSigner, RawDigestVerifier,
+};
+pub use self::oid::{
+    verify_by_oid, OidVerifiers, ECDSA_WITH_SHA224_OID, ECDSA_WITH_SHA256_OID,
+    ECDSA_WITH_SHA384_OID, ECDSA_WITH_SHA512_OID, ID_EC_PUBLIC_KEY_OID, ID_SHA224_OID,
+    ID_SHA256_OID, ID_SHA384_OID, ID_SHA512_OID,
+};
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+pub use self::oid::{verify_cert_signature, verify_cms_signer_info};
+#[cfg(all(feature = "digest", feature = "encoding"))]
+pub use self::options::{verify_digest_with_options, VerifyOptions};
+#[cfg(feature = "digest")]
+pub use self::public_key::verify_requiring_compressed_key;
+#[cfg(all(feature = "digest", feature = "sha2"))]
+pub use self::public_key::verify_with_pinned_key;
+pub use self::public_key::{PublicKey, PublicKeyValidator};
 pub use self::secret_key::SecretKey;
-pub use self::signature::{asn1::Asn1Signature, fixed::FixedSignature, Signature};
+#[cfg(all(feature = "alloc", feature = "encoding", feature = "testing"))]
+pub use self::signature::asn1::ParseStep;
+#[cfg(feature = "digest")]
+pub use self::signature::recoverable::DigestRecoverer;
+#[cfg(feature = "encoding")]
+pub use self::signature::scalars::ParseStats;
+pub use self::signature::{
+    asn1::Asn1Signature, fixed::FixedSignature, normalized::NormalizedSignature,
+    recoverable::RecoverableSignature, LengthPrefix, Signature, SignatureFormat,
+};
+#[cfg(all(feature = "digest", feature = "std"))]
+pub use self::timestamped::TimestampedVerifier;
+
+#[cfg(all(feature = "digest", feature = "encoding"))]
+use digest::Digest;
+#[cfg(feature = "encoding")]
+use generic_array::typenum::Unsigned;
+
+#[cfg(all(feature = "digest", feature = "encoding"))]
+use curve::RecoverableCurve;
+#[cfg(feature = "encoding")]
+use curve::WeierstrassCurve;
+#[cfg(all(feature = "digest", feature = "encoding"))]
+use encoding::asn1;
+#[cfg(all(feature = "digest", feature = "encoding"))]
+use error::Error;
+#[cfg(all(feature = "alloc", feature = "digest", feature = "encoding"))]
+use prelude::*;
+#[cfg(all(feature = "digest", feature = "encoding"))]
+use signer::DigestSigner;
+#[cfg(all(feature = "digest", feature = "encoding"))]
+use verifier::DigestVerifier;
+#[cfg(feature = "encoding")]
+use Signature as SignatureTrait;
+
+/// Sign the given prehashed `Digest` with a recoverable signer, returning both
+/// an ASN.1 DER signature and a recoverable signature.
+///
+/// The signature is only computed once, so both forms are guaranteed to
+/// contain the same `r` and `s` values (even with a non-deterministic signer).
+#[cfg(all(feature = "digest", feature = "encoding"))]
+pub fn sign_digest_all<D, C>(
+    signer: &DigestSigner<D, RecoverableSignature<C>>,
+    digest: D,
+) -> Result<(Asn1Signature<C>, RecoverableSignature<C>), Error>
+where
+    D: Digest,
+    C: RecoverableCurve,
+{
+    let recoverable_signature = signer.sign(digest)?;
+    let asn1_signature = Asn1Signature::from(&recoverable_signature.to_fixed_signature());
+    Ok((asn1_signature, recoverable_signature))
+}
+
+/// Sign the given prehashed `Digest`, serializing the resulting signature in
+/// the given `SignatureFormat` selected at runtime.
+///
+/// Signers need only produce a `FixedSignature`: it's converted to ASN.1 DER
+/// when `SignatureFormat::Asn1` is requested. Where the format is known at
+/// compile time, prefer signing directly as `Asn1Signature` or `FixedSignature`.
+#[cfg(all(feature = "alloc", feature = "digest", feature = "encoding"))]
+pub fn sign_digest_with_format<D, C>(
+    signer: &DigestSigner<D, FixedSignature<C>>,
+    digest: D,
+    format: SignatureFormat,
+) -> Result<Vec<u8>, Error>
+where
+    D: Digest,
+    C: WeierstrassCurve,
+{
+    let signature = signer.sign(digest)?;
+
+    Ok(match format {
+        SignatureFormat::Asn1 => Asn1Signature::from(&signature).as_ref().to_vec(),
+        SignatureFormat::Fixed => signature.as_ref().to_vec(),
+    })
+}
+
+/// Detect the serialization format of the given signature over the curve `C`
+/// (e.g. for reporting what was found in mixed-format input).
+///
+/// Returns `SignatureFormat::Asn1` if the input parses as an ASN.1 DER
+/// signature, otherwise `SignatureFormat::Fixed` if it's the size of a
+/// fixed-width signature (`C::FixedSignatureSize`), otherwise `None`.
+///
+/// Note the priority of DER over fixed-width signatures is the opposite of
+/// `FixedSignature::from_provider_bytes`: a fixed-width signature which also
+/// happens to be well-formed DER is reported as `Asn1` here.
+#[cfg(feature = "encoding")]
+pub fn detect_format<C>(bytes: &[u8]) -> Option<SignatureFormat>
+where
+    C: WeierstrassCurve,
+{
+    if Asn1Signature::<C>::from_bytes(bytes).is_ok() {
+        Some(SignatureFormat::Asn1)
+    } else if bytes.len() == C::FixedSignatureSize::to_usize() {
+        Some(SignatureFormat::Fixed)
+    } else {
+        None
+    }
+}
+
+/// Verify a signature of the given digest under the given public key with
+/// the provider's verifier type `V`, accepting the signature in any of the
+/// serializations this library knows of. This is the most lenient way to
+/// verify a signature, e.g. for tools ingesting signatures from many sources.
+///
+/// Each of the following interpretations of `bytes` is tried in order, and
+/// the signature is valid if any of them verifies:
+///
+/// 1. as parsed by `FixedSignature::from_provider_bytes`, i.e. fixed-width
+///    if `bytes` is `C::FixedSignatureSize` bytes, otherwise ASN.1 DER if it
+///    begins with a `SEQUENCE` tag (`0x30`)
+/// 2. as ASN.1 DER, if `bytes` is fixed-width sized but also begins with a
+///    `SEQUENCE` tag
+/// 3. as a recoverable signature (`r || s || v`), if `bytes` is one byte
+///    longer than `C::FixedSignatureSize`: `v` is dropped without being
+///    checked (so e.g. Ethereum's `27`/`28` encoding is also accepted), and
+///    `r || s` is verified as a fixed-width signature
+///
+/// Returns `ErrorKind::SignatureInvalid` if no interpretation verifies,
+/// including if `bytes` doesn't parse as any of them.
+#[cfg(all(feature = "digest", feature = "encoding"))]
+pub fn verify_any_format<V, C, D>(
+    public_key: &PublicKey<C>,
+    digest: D,
+    bytes: &[u8],
+) -> Result<(), Error>
+where
+    V: DigestVerifier<D, FixedSignature<C>> + for<'a> From<&'a PublicKey<C>>,
+    C: WeierstrassCurve,
+    D: Digest + Clone,
+{
+    let verifier = V::from(public_key);
+    let fixed_size = C::FixedSignatureSize::to_usize();
+    let is_sequence = !bytes.is_empty() && bytes[0] == asn1::Tag::Sequence as u8;
+
+    let candidates = [
+        FixedSignature::<C>::from_provider_bytes(bytes).ok(),
+        if bytes.len() == fixed_size && is_sequence {
+            Asn1Signature::<C>::from_bytes(bytes)
+                .and_then(|signature| signature.to_fixed_signature_checked())
+                .ok()
+        } else {
+            None
+        },
+        if bytes.len() == fixed_size + 1 {
+            FixedSignature::<C>::from_bytes(&bytes[..fixed_size]).ok()
+        } else {
+            None
+        },
+    ];
+
+    for signature in candidates.iter().filter_map(|candidate| candidate.as_ref()) {
+        if verifier.verify(digest.clone(), signature).is_ok() {
+            return Ok(());
+        }
+    }
+
+    fail!(
+        SignatureInvalid,
+        "signature not valid in any format ({} bytes)",
+        bytes.len()
+    )
+}
+
+#[cfg(all(test, feature = "encoding", feature = "test-vectors"))]
+mod tests {
+    use super::{detect_format, SignatureFormat};
+    use curve::nistp256::{Asn1Signature, FixedSignature, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use curve::{NistP256, NistP384};
+    use Signature;
+
+    #[cfg(all(feature = "digest", feature = "sha2"))]
+    mod signer {
+        use sha2::Sha256;
+
+        use curve::secp256k1::{FixedSignature, RecoverableSignature};
+        use error::Error;
+        use DigestSigner;
+
+        /// Signer which always returns the same signature
+        pub struct TestSigner(pub FixedSignature);
+
+        impl DigestSigner<Sha256, FixedSignature> for TestSigner {
+            fn sign(&self, _digest: Sha256) -> Result<FixedSignature, Error> {
+                Ok(self.0.clone())
+            }
+        }
+
+        impl DigestSigner<Sha256, RecoverableSignature> for TestSigner {
+            fn sign(&self, _digest: Sha256) -> Result<RecoverableSignature, Error> {
+                RecoverableSignature::new(&self.0, 1)
+            }
+        }
+    }
+
+    #[cfg(all(feature = "digest", feature = "sha2"))]
+    #[test]
+    fn test_sign_digest_all() {
+        use self::signer::TestSigner;
+        use super::sign_digest_all;
+        use curve::secp256k1;
+        use sha2::Sha256;
+
+        let vector = &secp256k1::SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signature = secp256k1::FixedSignature::from_bytes(vector.sig).unwrap();
+        let signer = TestSigner(signature.clone());
+
+        let (asn1_signature, recoverable_signature) =
+            sign_digest_all(&signer, Sha256::default()).unwrap();
+
+        assert_eq!(recoverable_signature.recovery_id(), 1);
+        assert_eq!(recoverable_signature.to_fixed_signature(), signature);
+        assert_eq!(asn1_signature, secp256k1::Asn1Signature::from(&signature));
+    }
+
+    #[cfg(all(feature = "alloc", feature = "digest", feature = "sha2"))]
+    #[test]
+    fn test_sign_digest_with_format() {
+        use self::signer::TestSigner;
+        use super::sign_digest_with_format;
+        use curve::secp256k1;
+        use sha2::Sha256;
+
+        let vector = &secp256k1::SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signature = secp256k1::FixedSignature::from_bytes(vector.sig).unwrap();
+        let signer = TestSigner(signature.clone());
+
+        let fixed_bytes =
+            sign_digest_with_format(&signer, Sha256::default(), SignatureFormat::Fixed).unwrap();
+        assert_eq!(fixed_bytes.as_slice(), vector.sig);
+
+        let asn1_bytes =
+            sign_digest_with_format(&signer, Sha256::default(), SignatureFormat::Asn1).unwrap();
+        assert_eq!(
+            asn1_bytes.as_slice(),
+            secp256k1::Asn1Signature::from(&signature).as_ref()
+        );
+    }
+
+    #[cfg(all(feature = "sha2", feature = "std", feature = "test-signer"))]
+    #[test]
+    fn test_verify_any_format() {
+        use super::{verify_any_format, MemorySigner};
+        use curve::nistp256::SecretKey;
+        use digest::Digest;
+        use ecdsa::test_verifier::ResigningVerifier;
+        use error::ErrorKind;
+        use sha2::Sha256;
+        use {DigestSigner, PublicKeyed};
+
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = MemorySigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let public_key = signer.public_key().unwrap();
+        let mut digest = Sha256::default();
+        digest.input(vector.msg);
+
+        let fixed_signature: FixedSignature = signer.sign(digest.clone()).unwrap();
+        let asn1_signature = Asn1Signature::from(&fixed_signature);
+
+        // Recoverable signatures with a raw or Ethereum-style (27/28) `v`
+        let mut recoverable_signature = fixed_signature.as_ref().to_vec();
+        recoverable_signature.push(1);
+        let mut eth_signature = recoverable_signature.clone();
+        eth_signature[64] += 27;
+
+        let verify = |digest: &Sha256, bytes: &[u8]| {
+            verify_any_format::<ResigningVerifier, _, _>(&public_key, digest.clone(), bytes)
+        };
+
+        for bytes in &[
+            fixed_signature.as_ref(),
+            asn1_signature.as_ref(),
+            &recoverable_signature,
+            &eth_signature,
+        ] {
+            assert!(verify(&digest, bytes).is_ok());
+
+            let mut wrong_digest = Sha256::default();
+            wrong_digest.input(&vector.msg[1..]);
+            let err = verify(&wrong_digest, bytes).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+        }
+
+        for bytes in &[
+            &asn1_signature.as_ref()[1..],
+            b"",
+            &fixed_signature.as_ref()[..63],
+            &recoverable_signature[1..],
+        ] {
+            let err = verify(&digest, bytes).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+        }
+    }
+
+    #[test]
+    fn test_detect_format() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let fixed_signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let asn1_signature = Asn1Signature::from(&fixed_signature);
+
+            assert_eq!(
+                detect_format::<NistP256>(fixed_signature.as_ref()),
+                Some(SignatureFormat::Fixed)
+            );
+
+            assert_eq!(
+                detect_format::<NistP256>(asn1_signature.as_ref()),
+                Some(SignatureFormat::Asn1)
+            );
+
+            // P-256 fixed-width signatures are the wrong size for P-384
+            assert_eq!(detect_format::<NistP384>(fixed_signature.as_ref()), None);
+        }
+
+        assert_eq!(detect_format::<NistP256>(b""), None);
+    }
+}
@@ -0,0 +1,298 @@
+//! Verification of ECDSA signatures whose digest algorithm is identified by
+//! an ASN.1 object identifier (OID), e.g. the `signatureAlgorithm` of an
+//! X.509 certificate or PKCS#7 `SignerInfo` (see RFC 5758)
+
+use core::fmt::{self, Debug};
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+use core::marker::PhantomData;
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+use digest::Digest;
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+use sha2::{Sha224, Sha256, Sha384, Sha512};
+
+use super::Signature;
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+use super::{Asn1Signature, FixedSignature, MessageDigest, PublicKey, RawDigestVerifier};
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+use curve::WeierstrassCurve;
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+use encoding::asn1;
+use error::Error;
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+use verifier::DigestVerifier;
+use verifier::{Sha224Verifier, Sha256Verifier, Sha384Verifier, Sha512Verifier};
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+use Signature as SignatureTrait;
+
+/// DER-encoded `ecdsa-with-SHA224` OID (1.2.840.10045.4.3.1), sans tag and length
+pub const ECDSA_WITH_SHA224_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x01];
+
+/// DER-encoded `ecdsa-with-SHA256` OID (1.2.840.10045.4.3.2), sans tag and length
+pub const ECDSA_WITH_SHA256_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+
+/// DER-encoded `ecdsa-with-SHA384` OID (1.2.840.10045.4.3.3), sans tag and length
+pub const ECDSA_WITH_SHA384_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+
+/// DER-encoded `ecdsa-with-SHA512` OID (1.2.840.10045.4.3.4), sans tag and length
+pub const ECDSA_WITH_SHA512_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x04];
+
+/// DER-encoded `id-sha224` digest algorithm OID (2.16.840.1.101.3.4.2.4),
+/// sans tag and length
+pub const ID_SHA224_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x04];
+
+/// DER-encoded `id-sha256` digest algorithm OID (2.16.840.1.101.3.4.2.1),
+/// sans tag and length
+pub const ID_SHA256_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+
+/// DER-encoded `id-sha384` digest algorithm OID (2.16.840.1.101.3.4.2.2),
+/// sans tag and length
+pub const ID_SHA384_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02];
+
+/// DER-encoded `id-sha512` digest algorithm OID (2.16.840.1.101.3.4.2.3),
+/// sans tag and length
+pub const ID_SHA512_OID: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03];
+
+/// DER-encoded `id-ecPublicKey` OID (1.2.840.10045.2.1), sans tag and length
+pub const ID_EC_PUBLIC_KEY_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+
+/// Verifiers for each of the SHA-2 digests supported by `verify_by_oid`.
+///
+/// Providers typically only support some digests for a given curve (e.g.
+/// SHA-256 for P-256), so any of these may be `None`. A provider verifier
+/// which supports several digests can be used for each of them.
+pub struct OidVerifiers<'a, S: Signature + 'a> {
+    /// Verifier for `ecdsa-with-SHA224` signatures
+    pub sha224: Option<&'a Sha224Verifier<S>>,
+
+    /// Verifier for `ecdsa-with-SHA256` signatures
+    pub sha256: Option<&'a Sha256Verifier<S>>,
+
+    /// Verifier for `ecdsa-with-SHA384` signatures
+    pub sha384: Option<&'a Sha384Verifier<S>>,
+
+    /// Verifier for `ecdsa-with-SHA512` signatures
+    pub sha512: Option<&'a Sha512Verifier<S>>,
+}
+
+impl<'a, S> Default for OidVerifiers<'a, S>
+where
+    S: Signature,
+{
+    fn default() -> Self {
+        Self {
+            sha224: None,
+            sha256: None,
+            sha384: None,
+            sha512: None,
+        }
+    }
+}
+
+impl<'a, S> Debug for OidVerifiers<'a, S>
+where
+    S: Signature,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "signatory::ecdsa::OidVerifiers {{ sha224: {}, sha256: {}, sha384: {}, sha512: {} }}",
+            self.sha224.is_some(),
+            self.sha256.is_some(),
+            self.sha384.is_some(),
+            self.sha512.is_some()
+        )
+    }
+}
+
+/// Verify a signature of the given message using the digest algorithm
+/// identified by the given DER-encoded signature algorithm OID (i.e. the
+/// contents of the ASN.1 `OBJECT IDENTIFIER`, sans tag and length).
+///
+/// Returns `ErrorKind::Unsupported` if the OID isn't one of the
+/// `ecdsa-with-SHA*` OIDs above, or if `verifiers` doesn't include a verifier
+/// for its digest.
+pub fn verify_by_oid<S>(
+    verifiers: &OidVerifiers<S>,
+    algorithm_oid: &[u8],
+    msg: &[u8],
+    signature: &S,
+) -> Result<(), Error>
+where
+    S: Signature,
+{
+    if algorithm_oid == ECDSA_WITH_SHA224_OID {
+        match verifiers.sha224 {
+            Some(verifier) => verifier.verify_sha224(msg, signature),
+            None => fail!(Unsupported, "no verifier for ecdsa-with-SHA224"),
+        }
+    } else if algorithm_oid == ECDSA_WITH_SHA256_OID {
+        match verifiers.sha256 {
+            Some(verifier) => verifier.verify_sha256(msg, signature),
+            None => fail!(Unsupported, "no verifier for ecdsa-with-SHA256"),
+        }
+    } else if algorithm_oid == ECDSA_WITH_SHA384_OID {
+        match verifiers.sha384 {
+            Some(verifier) => verifier.verify_sha384(msg, signature),
+            None => fail!(Unsupported, "no verifier for ecdsa-with-SHA384"),
+        }
+    } else if algorithm_oid == ECDSA_WITH_SHA512_OID {
+        match verifiers.sha512 {
+            Some(verifier) => verifier.verify_sha512(msg, signature),
+            None => fail!(Unsupported, "no verifier for ecdsa-with-SHA512"),
+        }
+    } else {
+        fail!(
+            Unsupported,
+            "unsupported signature algorithm OID: {:?}",
+            algorithm_oid
+        );
+    }
+}
+
+/// Verify the ECDSA signature of an X.509 certificate (or CRL, etc) with its
+/// issuer's public key, i.e. check that `signature_der` is a signature of
+/// `tbs_bytes` (the DER-encoded `tbsCertificate`) using the digest algorithm
+/// identified by `algorithm_oid` (the certificate's `signatureAlgorithm` OID,
+/// sans tag and length). For a chain, call this for each certificate with the
+/// public key of the next one.
+///
+/// `signature_der` is the ASN.1 DER signature, i.e. the `signatureValue` BIT
+/// STRING sans its leading unused bits byte (see
+/// `Asn1Signature::from_bit_string`). The verifier type `V` is the provider's.
+/// Digests are converted to the curve's scalar size as described in
+/// `curve::digest_to_scalar` (e.g. for SHA-384 with P-256), so the provider
+/// only needs to support verifying a `MessageDigest`. Errors are as in
+/// `verify_by_oid`.
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+pub fn verify_cert_signature<V, C>(
+    tbs_bytes: &[u8],
+    algorithm_oid: &[u8],
+    signature_der: &[u8],
+    issuer_key: &PublicKey<C>,
+) -> Result<(), Error>
+where
+    V: RawDigestVerifier<C, FixedSignature<C>> + for<'a> From<&'a PublicKey<C>>,
+    C: WeierstrassCurve,
+{
+    let signature = Asn1Signature::<C>::from_bytes(signature_der)?.to_fixed_signature_checked()?;
+
+    let verifier = MessageDigestVerifier {
+        verifier: V::from(issuer_key),
+        curve: PhantomData,
+    };
+
+    let verifiers = OidVerifiers {
+        sha224: Some(&verifier),
+        sha256: Some(&verifier),
+        sha384: Some(&verifier),
+        sha512: Some(&verifier),
+    };
+
+    verify_by_oid(&verifiers, algorithm_oid, tbs_bytes, &signature)
+}
+
+/// Verify the ECDSA signature of a CMS (a.k.a. PKCS#7) `SignerInfo` with
+/// signed attributes (as used by e.g. S/MIME and CAdES) with the signer's
+/// public key, using the digest algorithm identified by `digest_oid` (the
+/// `SignerInfo`'s `digestAlgorithm` OID, sans tag and length, e.g.
+/// `ID_SHA256_OID`).
+///
+/// This is a building block rather than a CMS parser: `signed_attrs` is the
+/// DER-encoded `signedAttrs` field exactly as it appears in the `SignerInfo`
+/// (i.e. with its `[0] IMPLICIT` tag), and `signature_der` is the ASN.1 DER
+/// signature from the `signature` field (sans `OCTET STRING` tag and length).
+/// As specified in RFC 5652 section 5.4, the signature is verified against
+/// `signedAttrs` re-tagged as a `SET OF` (which may also be passed as-is).
+/// Checking the `message-digest` attribute against the content is left to
+/// the caller. `SignerInfo`s without signed attributes can be verified
+/// against the content with a `DigestVerifier` directly.
+///
+/// Returns `ErrorKind::Unsupported` if `digest_oid` isn't one of the
+/// `id-sha*` OIDs above, and `ErrorKind::ParseError` if `signed_attrs` or
+/// `signature_der` are malformed. Digests are converted to the curve's
+/// scalar size as in `verify_cert_signature`.
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+pub fn verify_cms_signer_info<V, C>(
+    signed_attrs: &[u8],
+    digest_oid: &[u8],
+    signature_der: &[u8],
+    signer_key: &PublicKey<C>,
+) -> Result<(), Error>
+where
+    V: RawDigestVerifier<C, FixedSignature<C>> + for<'a> From<&'a PublicKey<C>>,
+    C: WeierstrassCurve,
+{
+    let tag = match signed_attrs.first() {
+        Some(&b) if b == asn1::Tag::ContextSpecific0 as u8 => asn1::Tag::ContextSpecific0,
+        Some(&b) if b == asn1::Tag::Set as u8 => asn1::Tag::Set,
+        _ => fail!(
+            ParseError,
+            "expected CMS signedAttrs to be tagged [0] or SET OF"
+        ),
+    };
+
+    let (_, rest) = asn1::parse_tlv(tag, signed_attrs)?;
+    ensure!(
+        rest.is_empty(),
+        ParseError,
+        "trailing data after CMS signedAttrs"
+    );
+
+    let signature = Asn1Signature::<C>::from_bytes(signature_der)?.to_fixed_signature_checked()?;
+
+    let digest = if digest_oid == ID_SHA224_OID {
+        signed_attrs_digest::<Sha224, C>(signed_attrs)
+    } else if digest_oid == ID_SHA256_OID {
+        signed_attrs_digest::<Sha256, C>(signed_attrs)
+    } else if digest_oid == ID_SHA384_OID {
+        signed_attrs_digest::<Sha384, C>(signed_attrs)
+    } else if digest_oid == ID_SHA512_OID {
+        signed_attrs_digest::<Sha512, C>(signed_attrs)
+    } else {
+        fail!(
+            Unsupported,
+            "unsupported digest algorithm OID: {:?}",
+            digest_oid
+        );
+    };
+
+    V::from(signer_key).verify_raw_digest(&digest, &signature)
+}
+
+/// Hash the given CMS `signedAttrs` with the digest function `D` as a
+/// `SET OF` (i.e. with its first, tag byte replaced)
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+fn signed_attrs_digest<D, C>(signed_attrs: &[u8]) -> MessageDigest<C>
+where
+    D: Digest,
+    C: WeierstrassCurve,
+{
+    let mut digest = D::default();
+    digest.input(&[asn1::Tag::Set as u8]);
+    digest.input(&signed_attrs[1..]);
+    MessageDigest::from_digest(digest)
+}
+
+/// Adapter which verifies the output of any `Digest` with a
+/// `RawDigestVerifier`, converting it to a `MessageDigest`
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+struct MessageDigestVerifier<V, C> {
+    /// Provider's verifier
+    verifier: V,
+
+    /// Placeholder for elliptic curve type
+    curve: PhantomData<C>,
+}
+
+#[cfg(all(feature = "digest", feature = "encoding", feature = "sha2"))]
+impl<V, C, D> DigestVerifier<D, FixedSignature<C>> for MessageDigestVerifier<V, C>
+where
+    V: RawDigestVerifier<C, FixedSignature<C>>,
+    C: WeierstrassCurve,
+    D: Digest,
+{
+    fn verify(&self, digest: D, signature: &FixedSignature<C>) -> Result<(), Error> {
+        self.verifier
+            .verify_raw_digest(&MessageDigest::from_digest(digest), signature)
+    }
+}
@@ -0,0 +1,76 @@
+//! Configurable validation policy for verifying ECDSA signatures, e.g. for
+//! consensus-critical code which must reject malleable signatures
+
+use digest::Digest;
+
+use super::{Asn1Signature, FixedSignature};
+use curve::WeierstrassCurve;
+use error::Error;
+use verifier::DigestVerifier;
+use Signature;
+
+/// Options controlling which signatures `verify_digest_with_options`
+/// accepts, in addition to the signature being valid for the given digest.
+///
+/// The default options are all `false`, which accepts the same signatures as
+/// parsing with `Asn1Signature::from_bytes` and verifying them normally.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct VerifyOptions {
+    /// Reject signatures whose `s` value is greater than `n/2` (i.e. which
+    /// aren't in "low S" normalized form) with `ErrorKind::SignatureInvalid`.
+    /// For every valid signature `(r, s)`, `(r, n - s)` is also valid, so
+    /// this is needed to make signatures non-malleable.
+    pub require_low_s: bool,
+
+    /// Reject signatures which aren't strictly valid DER (e.g. non-minimal
+    /// or negative INTEGERs) with `ErrorKind::ParseError`, as described in
+    /// `Asn1Signature::from_bytes_strict`.
+    pub strict_der: bool,
+
+    /// Reject signatures where `r` or `s` is zero with
+    /// `ErrorKind::SignatureInvalid` before invoking the verifier. Such
+    /// signatures are never valid, but this avoids relying on the provider
+    /// to reject them.
+    pub reject_zero_scalars: bool,
+}
+
+/// Verify an ASN.1 DER-encoded signature of the given prehashed `Digest`
+/// with the given verifier, applying the additional validation policy in
+/// `VerifyOptions` before the signature itself is verified.
+pub fn verify_digest_with_options<D, C>(
+    verifier: &DigestVerifier<D, FixedSignature<C>>,
+    digest: D,
+    signature: &[u8],
+    options: &VerifyOptions,
+) -> Result<(), Error>
+where
+    D: Digest,
+    C: WeierstrassCurve,
+{
+    let asn1_signature = if options.strict_der {
+        Asn1Signature::<C>::from_bytes_strict(signature)?
+    } else {
+        Asn1Signature::<C>::from_bytes(signature)?
+    };
+
+    let fixed_signature = asn1_signature.to_fixed_signature_checked()?;
+
+    if options.reject_zero_scalars {
+        ensure!(
+            !fixed_signature.r_bigint_bytes().is_empty()
+                && !fixed_signature.s_bigint_bytes().is_empty(),
+            SignatureInvalid,
+            "signature 'r' or 's' value is zero"
+        );
+    }
+
+    if options.require_low_s {
+        ensure!(
+            fixed_signature.is_low_s(),
+            SignatureInvalid,
+            "signature 's' value is not low-S normalized"
+        );
+    }
+
+    verifier.verify(digest, &fixed_signature)
+}
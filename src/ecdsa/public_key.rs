@@ -2,20 +2,37 @@
 //! curve points.
 
 use core::fmt::{self, Debug};
+#[cfg(feature = "digest")]
+use digest::Digest;
 use generic_array::{typenum::Unsigned, GenericArray};
+#[cfg(all(feature = "digest", feature = "sha2"))]
+use sha2::Sha256;
+#[cfg(all(feature = "digest", feature = "sha2"))]
+use subtle::ConstantTimeEq;
 #[cfg(feature = "encoding")]
 use subtle_encoding::Encoding;
 
+use curve::arithmetic::Curve;
 use curve::point::{CompressedCurvePoint, UncompressedCurvePoint};
 use curve::WeierstrassCurve;
+use ecdsa;
+#[cfg(feature = "encoding")]
+use ecdsa::ID_EC_PUBLIC_KEY_OID;
+#[cfg(feature = "encoding")]
+use encoding::asn1;
+#[cfg(all(feature = "alloc", feature = "encoding"))]
+use encoding::decode_pem;
 #[cfg(feature = "encoding")]
 use encoding::Decode;
 #[cfg(all(feature = "alloc", feature = "encoding"))]
-use encoding::Encode;
+use encoding::{encode_vec, Encode};
 use error::Error;
 #[allow(unused_imports)]
 use prelude::*;
 use util::fmt_colon_delimited_hex;
+#[cfg(feature = "digest")]
+use verifier::DigestVerifier;
+use verifier::Verifier;
 
 /// ECDSA public keys
 #[derive(Clone, Eq, PartialEq)]
@@ -60,6 +77,33 @@ where
         }
     }
 
+    /// Parse an ECDSA public key from a PEM-encoded `SubjectPublicKeyInfo`
+    /// (i.e. a `-----BEGIN PUBLIC KEY-----` document, as described in
+    /// RFC 7468 section 13), with an `id-ecPublicKey` algorithm whose
+    /// `namedCurve` parameters are the curve `C` (see RFC 5480).
+    ///
+    /// Malformed documents, and keys for other algorithms or curves, are
+    /// a `KeyInvalid` error.
+    #[cfg(all(feature = "alloc", feature = "encoding"))]
+    pub fn from_pem(pem: &str) -> Result<Self, Error> {
+        let der = decode_pem(pem, PEM_PUBLIC_KEY_BEGIN, PEM_PUBLIC_KEY_END)?;
+        Self::from_spki_der(&der)
+    }
+
+    /// Parse an ECDSA public key from a DER-encoded `SubjectPublicKeyInfo`
+    /// with an `id-ecPublicKey` algorithm whose `namedCurve` parameters are
+    /// the curve `C` (see RFC 5480), e.g. the body of a PEM public key.
+    ///
+    /// Malformed structures, and keys for other algorithms or curves, are
+    /// a `KeyInvalid` error.
+    #[cfg(feature = "encoding")]
+    pub fn from_spki_der(der: &[u8]) -> Result<Self, Error> {
+        let point = spki_point::<C>(der)
+            .map_err(|e| err!(KeyInvalid, "malformed SubjectPublicKeyInfo: {}", e))?;
+
+        Self::from_bytes(point)
+    }
+
     /// Create an ECDSA public key from an compressed elliptic curve point
     /// encoded using the `Elliptic-Curve-Point-to-Octet-String` algorithm
     /// described in SEC 1: Elliptic Curve Cryptography (Version 2.0) section
@@ -74,6 +118,43 @@ where
         Ok(PublicKey::Compressed(point))
     }
 
+    /// Create an ECDSA public key from its `x` coordinate (a big endian
+    /// integer `C::ScalarSize` bytes long) and the parity of its `y`
+    /// coordinate, e.g. where the parity is transmitted separately rather
+    /// than as a SEC 1 `0x02`/`0x03` tag.
+    ///
+    /// The point is decompressed, so the result is an uncompressed key. It's a
+    /// `KeyInvalid` error if `x` isn't the `x` coordinate of a curve point.
+    pub fn from_x_and_parity(x: &[u8], y_is_odd: bool) -> Result<Self, Error> {
+        let coordinate_size = C::ScalarSize::to_usize();
+
+        ensure!(
+            x.len() == coordinate_size,
+            KeyInvalid,
+            "expected {}-byte x coordinate for {:?} public key (got {})",
+            coordinate_size,
+            C::CURVE_KIND,
+            x.len()
+        );
+
+        let curve = Curve::new(C::CURVE_KIND);
+        let y = curve
+            .decompress(&curve.p.parse(x), y_is_odd)
+            .ok_or_else(|| {
+                err!(
+                    KeyInvalid,
+                    "{:?} public key is not on the curve",
+                    C::CURVE_KIND
+                )
+            })?;
+
+        let mut bytes: GenericArray<u8, C::UntaggedPointSize> = GenericArray::default();
+        bytes[..coordinate_size].copy_from_slice(x);
+        curve.p.serialize(&y, &mut bytes[coordinate_size..]);
+
+        Ok(Self::from_untagged_point(&bytes))
+    }
+
     /// Create an ECDSA public key from a raw uncompressed point serialized
     /// as a bytestring, without a `0x04`-byte tag.
     ///
@@ -96,8 +177,270 @@ where
             PublicKey::Uncompressed(ref point) => point.as_bytes(),
         }
     }
+
+    /// Was this public key supplied as a compressed point?
+    pub fn is_compressed(&self) -> bool {
+        match self {
+            PublicKey::Compressed(_) => true,
+            PublicKey::Uncompressed(_) => false,
+        }
+    }
+
+    /// Is this public key the same elliptic curve point as `other`, regardless
+    /// of whether either is compressed or uncompressed?
+    ///
+    /// Unlike `PartialEq`, which compares the serialized bytes (so a compressed
+    /// key never equals an uncompressed one), this compares the `x` coordinates
+    /// and the parity of the `y` coordinates (which determines `y` given `x`).
+    /// Neither key is checked to actually be a point on the curve.
+    pub fn same_point(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PublicKey::Uncompressed(ref a), PublicKey::Uncompressed(ref b)) => a == b,
+            (PublicKey::Compressed(ref a), PublicKey::Compressed(ref b)) => a == b,
+            (PublicKey::Compressed(ref a), PublicKey::Uncompressed(ref b))
+            | (PublicKey::Uncompressed(ref b), PublicKey::Compressed(ref a)) => {
+                *a == b.to_compressed()
+            }
+        }
+    }
+
+    /// Validate this public key is a point on the curve `C`, and not the
+    /// point at infinity (a.k.a. the identity), returning `KeyInvalid` if not.
+    ///
+    /// This is the public key validation described in SEC 1 section 3.2.2.1
+    /// (the `n * Q = O` step is implied, as all supported curves have a
+    /// cofactor of 1). Checking keys up front gives a clear error for
+    /// malicious or corrupt keys, rather than a failure at verification time.
+    ///
+    /// This uses Signatory's own (non-constant-time) curve arithmetic. Use
+    /// `validate_with` to validate keys with a provider's instead.
+    pub fn validate(&self) -> Result<(), Error> {
+        let curve = Curve::new(C::CURVE_KIND);
+        let coordinate_size = C::ScalarSize::to_usize();
+
+        match self {
+            PublicKey::Compressed(ref point) => {
+                let x = curve.p.parse(&point.as_bytes()[1..]);
+
+                ensure!(
+                    curve.contains_x(&x),
+                    KeyInvalid,
+                    "{:?} public key is not a point on the curve",
+                    C::CURVE_KIND
+                );
+            }
+            PublicKey::Uncompressed(ref point) => {
+                let coordinates = &point.as_bytes()[1..];
+
+                // Some encoders serialize the identity as `(0, 0)`
+                ensure!(
+                    coordinates.iter().any(|&byte| byte != 0),
+                    KeyInvalid,
+                    "{:?} public key is the point at infinity",
+                    C::CURVE_KIND
+                );
+
+                let x = curve.p.parse(&coordinates[..coordinate_size]);
+                let y = curve.p.parse(&coordinates[coordinate_size..]);
+
+                ensure!(
+                    curve.contains(&x, &y),
+                    KeyInvalid,
+                    "{:?} public key is not a point on the curve",
+                    C::CURVE_KIND
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Obtain the uncompressed form of this public key, decompressing it if
+    /// it was supplied as a compressed point (see `from_x_and_parity`).
+    ///
+    /// It's a `KeyInvalid` error if a compressed key isn't a curve point.
+    pub fn decompress(&self) -> Result<Self, Error> {
+        match self {
+            PublicKey::Compressed(ref point) => {
+                let bytes = point.as_bytes();
+                Self::from_x_and_parity(&bytes[1..], bytes[0] == 0x03)
+            }
+            PublicKey::Uncompressed(_) => Ok(self.clone()),
+        }
+    }
+
+    /// Compute a SHA-256 fingerprint of this public key, i.e. the SHA-256
+    /// digest of its SEC 1 uncompressed point encoding (including the `0x04`
+    /// tag), e.g. for pinning an expected key.
+    ///
+    /// Compressed keys are decompressed first, so both forms of a key have
+    /// the same fingerprint. It's a `KeyInvalid` error if a compressed key
+    /// isn't a curve point.
+    #[cfg(all(feature = "digest", feature = "sha2"))]
+    pub fn fingerprint(&self) -> Result<[u8; 32], Error> {
+        match self {
+            PublicKey::Uncompressed(ref point) => {
+                let mut sha256 = Sha256::default();
+                sha256.input(point.as_bytes());
+
+                let mut fingerprint = [0u8; 32];
+                fingerprint.copy_from_slice(&sha256.result());
+                Ok(fingerprint)
+            }
+            PublicKey::Compressed(_) => self.decompress()?.fingerprint(),
+        }
+    }
+
+    /// Verify a signature of the given message against this public key,
+    /// using the provider's verifier type `V`, e.g.:
+    ///
+    /// `public_key.verify::<EcdsaVerifier, _>(msg, &signature)`
+    pub fn verify<V, S>(&self, msg: &[u8], signature: &S) -> Result<(), Error>
+    where
+        V: Verifier<S> + for<'a> From<&'a Self>,
+        S: ecdsa::Signature,
+    {
+        V::from(self).verify(msg, signature)
+    }
+
+    /// Verify a signature of the given prehashed `Digest` against this
+    /// public key, using the provider's verifier type `V`, e.g.:
+    ///
+    /// `public_key.verify_digest::<EcdsaVerifier, _, _>(digest, &signature)`
+    #[cfg(feature = "digest")]
+    pub fn verify_digest<V, D, S>(&self, digest: D, signature: &S) -> Result<(), Error>
+    where
+        V: DigestVerifier<D, S> + for<'a> From<&'a Self>,
+        D: Digest,
+        S: ecdsa::Signature,
+    {
+        V::from(self).verify(digest, signature)
+    }
+
+    /// Validate this public key like `validate`, but using the given
+    /// provider's `PublicKeyValidator`, e.g.
+    /// `public_key.validate_with::<EcdsaVerifier>()`
+    pub fn validate_with<V>(&self) -> Result<(), Error>
+    where
+        V: PublicKeyValidator<C>,
+    {
+        V::validate_public_key(self)
+    }
+}
+
+/// Provider hook for validating public keys with the provider's own elliptic
+/// curve arithmetic (e.g. where it's constant-time or hardware-backed).
+///
+/// Providers which don't implement `validate_public_key` fall back to
+/// `PublicKey::validate`.
+pub trait PublicKeyValidator<C: WeierstrassCurve> {
+    /// Check the given public key is a point on the curve `C`, and not the
+    /// point at infinity, returning `KeyInvalid` if not
+    fn validate_public_key(public_key: &PublicKey<C>) -> Result<(), Error> {
+        public_key.validate()
+    }
+}
+
+/// Verify a signature of the given prehashed `Digest` against a supplied
+/// public key, but only if it matches an expected (pinned) fingerprint as
+/// computed by `PublicKey::fingerprint`. Fingerprints are compared in
+/// constant time, and a mismatch is a `KeyInvalid` error.
+///
+/// The verifier type `V` is the provider's, as in `PublicKey::verify_digest`.
+#[cfg(all(feature = "digest", feature = "sha2"))]
+pub fn verify_with_pinned_key<V, C, D, S>(
+    supplied_key: &PublicKey<C>,
+    expected_fingerprint: &[u8; 32],
+    digest: D,
+    signature: &S,
+) -> Result<(), Error>
+where
+    V: DigestVerifier<D, S> + for<'a> From<&'a PublicKey<C>>,
+    C: WeierstrassCurve,
+    D: Digest,
+    S: ecdsa::Signature,
+{
+    let fingerprint = supplied_key.fingerprint()?;
+
+    ensure!(
+        bool::from(fingerprint.ct_eq(expected_fingerprint)),
+        KeyInvalid,
+        "public key doesn't match pinned fingerprint"
+    );
+
+    supplied_key.verify_digest::<V, D, S>(digest, signature)
+}
+
+/// Verify a signature of the given prehashed `Digest` against a public key,
+/// but only if the key was supplied in compressed form (e.g. for consensus
+/// systems which hash public keys, and so require a single canonical
+/// encoding). An uncompressed key is a `KeyInvalid` error, even if the
+/// signature is valid.
+///
+/// The verifier type `V` is the provider's, as in `PublicKey::verify_digest`.
+#[cfg(feature = "digest")]
+pub fn verify_requiring_compressed_key<V, C, D, S>(
+    public_key: &PublicKey<C>,
+    digest: D,
+    signature: &S,
+) -> Result<(), Error>
+where
+    V: DigestVerifier<D, S> + for<'a> From<&'a PublicKey<C>>,
+    C: WeierstrassCurve,
+    D: Digest,
+    S: ecdsa::Signature,
+{
+    ensure!(
+        public_key.is_compressed(),
+        KeyInvalid,
+        "expected compressed {:?} public key",
+        C::CURVE_KIND
+    );
+
+    public_key.verify_digest::<V, D, S>(digest, signature)
+}
+
+/// Parse the SEC 1 point of an EC `SubjectPublicKeyInfo` over curve `C`
+#[cfg(feature = "encoding")]
+fn spki_point<C: WeierstrassCurve>(der: &[u8]) -> Result<&[u8], Error> {
+    let (spki, rest) = asn1::parse_tlv(asn1::Tag::Sequence, der)?;
+    ensure!(rest.is_empty(), ParseError, "trailing data");
+
+    let (algorithm, point) = asn1::parse_tlv(asn1::Tag::Sequence, spki)?;
+    let (algorithm_oid, params) = asn1::parse_tlv(asn1::Tag::ObjectIdentifier, algorithm)?;
+    ensure!(
+        algorithm_oid == ID_EC_PUBLIC_KEY_OID,
+        ParseError,
+        "not an id-ecPublicKey"
+    );
+
+    let (curve_oid, rest) = asn1::parse_tlv(asn1::Tag::ObjectIdentifier, params)?;
+    ensure!(
+        curve_oid == C::CURVE_KIND.to_oid() && rest.is_empty(),
+        ParseError,
+        "expected {:?} namedCurve",
+        C::CURVE_KIND
+    );
+
+    let (point, rest) = asn1::parse_tlv(asn1::Tag::BitString, point)?;
+    ensure!(rest.is_empty(), ParseError, "trailing data");
+
+    // The point must be a whole number of bytes (i.e. no unused bits)
+    ensure!(
+        point.first() == Some(&0),
+        ParseError,
+        "invalid subjectPublicKey"
+    );
+
+    Ok(&point[1..])
 }
 
+/// Encapsulation boundaries of a PEM-encoded `SubjectPublicKeyInfo`
+#[cfg(all(feature = "alloc", feature = "encoding"))]
+const PEM_PUBLIC_KEY_BEGIN: &str = "-----BEGIN PUBLIC KEY-----";
+#[cfg(all(feature = "alloc", feature = "encoding"))]
+const PEM_PUBLIC_KEY_END: &str = "-----END PUBLIC KEY-----";
+
 impl<C> AsRef<[u8]> for PublicKey<C>
 where
     C: WeierstrassCurve,
@@ -152,8 +495,404 @@ where
     ///
     /// <http://www.secg.org/sec1-v2.pdf>
     fn encode<E: Encoding>(&self, encoding: &E) -> Vec<u8> {
-        encoding.encode(self.as_ref())
+        encode_vec(encoding, self.as_ref())
     }
 }
 
 impl<C: WeierstrassCurve> ::PublicKey for PublicKey<C> {}
+
+#[cfg(all(test, feature = "digest", feature = "sha2", feature = "test-vectors"))]
+pub(crate) mod tests {
+    use curve::nistp256::{PublicKey, SHA256_FIXED_SIZE_TEST_VECTORS};
+    #[cfg(all(feature = "alloc", feature = "encoding"))]
+    use curve::nistp384::PublicKey as NistP384PublicKey;
+    use curve::secp256k1::{self, PublicKey as Secp256k1PublicKey};
+    use digest::Digest;
+    use error::{Error, ErrorKind};
+    use generic_array::GenericArray;
+    use sha2::Sha256;
+    use test_vector::TestVector;
+
+    /// Public key of the given NIST P-256 test vector in compressed form
+    pub(crate) fn compressed_key(vector: &TestVector) -> PublicKey {
+        let mut compressed_point = [0u8; 33];
+        compressed_point[0] = 0x02 | (vector.pk[63] & 1);
+        compressed_point[1..].copy_from_slice(&vector.pk[..32]);
+        PublicKey::from_bytes(&compressed_point[..]).unwrap()
+    }
+
+    /// Public key with the same `x` as `public_key` (in compressed form), but
+    /// the other `y`, i.e. its negation
+    fn negated_key(public_key: &PublicKey) -> PublicKey {
+        let mut compressed_point = [0u8; 33];
+        compressed_point.copy_from_slice(public_key.as_bytes());
+        compressed_point[0] ^= 1;
+        PublicKey::from_bytes(&compressed_point[..]).unwrap()
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+
+        let fingerprint = public_key.fingerprint().unwrap();
+        assert_eq!(
+            &fingerprint[..],
+            Sha256::digest(public_key.as_bytes()).as_slice()
+        );
+
+        let compressed_key = compressed_key(vector);
+        assert_eq!(compressed_key.fingerprint().unwrap(), fingerprint);
+
+        // The other `y` is a different key
+        let negated_key = negated_key(&compressed_key);
+        assert_ne!(negated_key.fingerprint().unwrap(), fingerprint);
+
+        // There's no secp256k1 point with `x = 5`, as `5^3 + 7` isn't a square
+        let mut compressed_point = [0u8; 33];
+        compressed_point[0] = 0x02;
+        compressed_point[32] = 5;
+        let not_on_curve = Secp256k1PublicKey::from_bytes(&compressed_point[..]).unwrap();
+        let err = not_on_curve.fingerprint().err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn test_decompress() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+        assert_eq!(public_key.decompress().unwrap(), public_key);
+
+        let compressed_key = compressed_key(vector);
+        assert_eq!(compressed_key.decompress().unwrap(), public_key);
+    }
+
+    #[test]
+    fn test_same_point() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let uncompressed = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+
+        let compressed = compressed_key(vector);
+
+        assert_ne!(compressed, uncompressed);
+        assert!(compressed.same_point(&uncompressed));
+        assert!(uncompressed.same_point(&compressed));
+        assert!(uncompressed.same_point(&uncompressed.clone()));
+
+        // Negating the point (i.e. flipping the parity of y) is a different point
+        let negated = negated_key(&compressed);
+        assert!(!negated.same_point(&uncompressed));
+        assert!(!negated.same_point(&compressed));
+    }
+
+    #[test]
+    fn test_validate() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            assert!(public_key.validate().is_ok());
+
+            assert!(compressed_key(vector).validate().is_ok());
+
+            // Perturbing `y` moves the point off the curve
+            let mut untagged_point = [0u8; 64];
+            untagged_point.copy_from_slice(vector.pk);
+            untagged_point[63] ^= 1;
+            let off_curve =
+                PublicKey::from_untagged_point(GenericArray::from_slice(&untagged_point));
+            assert_eq!(
+                off_curve.validate().unwrap_err().kind(),
+                ErrorKind::KeyInvalid
+            );
+        }
+
+        let identity = PublicKey::from_untagged_point(&GenericArray::default());
+        assert_eq!(
+            identity.validate().unwrap_err().kind(),
+            ErrorKind::KeyInvalid
+        );
+
+        // `x` must be less than the field modulus `p`
+        let unreduced = PublicKey::from_bytes(
+            &b"\x02\xff\xff\xff\xff\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\
+               \x00\x00\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff"[..],
+        )
+        .unwrap();
+        assert_eq!(
+            unreduced.validate().unwrap_err().kind(),
+            ErrorKind::KeyInvalid
+        );
+
+        // There's no secp256k1 point with `x = 5`, as `5^3 + 7` isn't a square
+        let mut compressed_point = [0u8; 33];
+        compressed_point[0] = 0x02;
+        compressed_point[32] = 5;
+        let not_on_curve = Secp256k1PublicKey::from_bytes(&compressed_point[..]).unwrap();
+        assert_eq!(
+            not_on_curve.validate().unwrap_err().kind(),
+            ErrorKind::KeyInvalid
+        );
+
+        compressed_point[32] = 1;
+        assert!(Secp256k1PublicKey::from_bytes(&compressed_point[..])
+            .unwrap()
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_with() {
+        use curve::NistP256;
+        use ecdsa::PublicKeyValidator;
+
+        /// Provider which uses the default `PublicKey::validate`
+        struct DefaultValidator;
+
+        impl PublicKeyValidator<NistP256> for DefaultValidator {}
+
+        /// Provider which rejects every key
+        struct RejectingValidator;
+
+        impl PublicKeyValidator<NistP256> for RejectingValidator {
+            fn validate_public_key(_public_key: &PublicKey) -> Result<(), Error> {
+                fail!(KeyInvalid, "rejected by provider");
+            }
+        }
+
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+        assert!(public_key.validate_with::<DefaultValidator>().is_ok());
+        assert_eq!(
+            public_key
+                .validate_with::<RejectingValidator>()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::KeyInvalid
+        );
+
+        let identity = PublicKey::from_untagged_point(&GenericArray::default());
+        assert_eq!(
+            identity
+                .validate_with::<DefaultValidator>()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::KeyInvalid
+        );
+    }
+
+    #[test]
+    fn test_from_x_and_parity() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            let y_is_odd = vector.pk[63] & 1 == 1;
+
+            assert_eq!(
+                PublicKey::from_x_and_parity(&vector.pk[..32], y_is_odd).unwrap(),
+                public_key
+            );
+
+            // The other `y` is the negated point, which is also valid
+            let negated = PublicKey::from_x_and_parity(&vector.pk[..32], !y_is_odd).unwrap();
+            assert!(negated.validate().is_ok());
+            assert!(!negated.same_point(&public_key));
+        }
+
+        let compressed = &secp256k1::SHA256_FIXED_SIZE_TEST_VECTORS[0].pk;
+        let public_key =
+            Secp256k1PublicKey::from_x_and_parity(&compressed[1..], compressed[0] == 0x03).unwrap();
+        assert!(public_key.same_point(&Secp256k1PublicKey::from_bytes(compressed).unwrap()));
+
+        // There's no secp256k1 point with `x = 5`
+        let mut x = [0u8; 32];
+        x[31] = 5;
+        assert_eq!(
+            Secp256k1PublicKey::from_x_and_parity(&x, false)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::KeyInvalid
+        );
+
+        assert_eq!(
+            PublicKey::from_x_and_parity(&x[1..], false)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::KeyInvalid
+        );
+    }
+
+    /// Uncompressed and compressed public key of the first test vector, the
+    /// SHA-256 digest of its message, and the vector's signature of it
+    #[cfg(all(feature = "std", feature = "test-signer"))]
+    fn signed_digest() -> (
+        PublicKey,
+        PublicKey,
+        Sha256,
+        ::curve::nistp256::FixedSignature,
+    ) {
+        use curve::nistp256::SecretKey;
+        use ecdsa::MemorySigner;
+        use {DigestSigner, PublicKeyed};
+
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = MemorySigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+        let public_key = signer.public_key().unwrap();
+
+        let compressed_key = compressed_key(vector);
+
+        let mut digest = Sha256::default();
+        digest.input(vector.msg);
+        let signature = signer.sign(digest.clone()).unwrap();
+
+        (public_key, compressed_key, digest, signature)
+    }
+
+    #[cfg(all(feature = "std", feature = "test-signer"))]
+    #[test]
+    fn test_verify_with_pinned_key() {
+        use super::verify_with_pinned_key;
+        use ecdsa::test_verifier::ResigningVerifier;
+
+        let (public_key, compressed_key, digest, signature) = signed_digest();
+        let fingerprint = public_key.fingerprint().unwrap();
+
+        // Either form of the pinned key is accepted
+        for supplied_key in &[&public_key, &compressed_key] {
+            assert!(verify_with_pinned_key::<ResigningVerifier, _, _, _>(
+                supplied_key,
+                &fingerprint,
+                digest.clone(),
+                &signature
+            )
+            .is_ok());
+        }
+
+        // The pinned key's signature is still checked
+        let mut wrong_digest = Sha256::default();
+        wrong_digest.input(b"another message");
+        let err = verify_with_pinned_key::<ResigningVerifier, _, _, _>(
+            &public_key,
+            &fingerprint,
+            wrong_digest,
+            &signature,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[cfg(all(feature = "std", feature = "test-signer"))]
+    #[test]
+    fn test_verify_with_pinned_key_rejects_other_keys() {
+        use super::verify_with_pinned_key;
+        use ecdsa::test_verifier::ResigningVerifier;
+
+        let (public_key, _, digest, signature) = signed_digest();
+        let mut wrong_fingerprint = public_key.fingerprint().unwrap();
+        wrong_fingerprint[0] ^= 1;
+
+        let err = verify_with_pinned_key::<ResigningVerifier, _, _, _>(
+            &public_key,
+            &wrong_fingerprint,
+            digest,
+            &signature,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[cfg(all(feature = "std", feature = "test-signer"))]
+    #[test]
+    fn test_verify_requiring_compressed_key() {
+        use super::verify_requiring_compressed_key;
+        use ecdsa::test_verifier::ResigningVerifier;
+
+        let (_, compressed_key, digest, signature) = signed_digest();
+
+        assert!(
+            verify_requiring_compressed_key::<ResigningVerifier, _, _, _>(
+                &compressed_key,
+                digest.clone(),
+                &signature
+            )
+            .is_ok()
+        );
+
+        let mut wrong_digest = Sha256::default();
+        wrong_digest.input(b"another message");
+        let err = verify_requiring_compressed_key::<ResigningVerifier, _, _, _>(
+            &compressed_key,
+            wrong_digest,
+            &signature,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[cfg(all(feature = "std", feature = "test-signer"))]
+    #[test]
+    fn test_verify_requiring_compressed_key_rejects_uncompressed_key() {
+        use super::verify_requiring_compressed_key;
+        use ecdsa::test_verifier::ResigningVerifier;
+
+        let (public_key, _, digest, signature) = signed_digest();
+
+        // The signature is valid under the uncompressed key...
+        assert!(public_key
+            .verify_digest::<ResigningVerifier, _, _>(digest.clone(), &signature)
+            .is_ok());
+
+        // ...but the key isn't accepted in that form
+        let err = verify_requiring_compressed_key::<ResigningVerifier, _, _, _>(
+            &public_key,
+            digest,
+            &signature,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+
+    /// NIST P-256 public key generated with `openssl ec -pubout`
+    #[cfg(all(feature = "alloc", feature = "encoding"))]
+    const NISTP256_PEM: &str = "-----BEGIN PUBLIC KEY-----
+MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEj8+06P5iUGF+EEdv9gRA/ZSMgU0r
+gU+jhkXPnCWLf/1LOkEc4yHr38tnP3r3PgUTYdYTHnk9XIkwcmnFrlvVfw==
+-----END PUBLIC KEY-----
+";
+
+    /// secp256k1 public key generated with `openssl ec -pubout`
+    #[cfg(all(feature = "alloc", feature = "encoding"))]
+    const SECP256K1_PEM: &str = "-----BEGIN PUBLIC KEY-----
+MFYwEAYHKoZIzj0CAQYFK4EEAAoDQgAEfkBnNJtiNzFNwn3otUueeig5PcvldhwY
+G8aXeL0Mje7MCLTQhV3kCUpJsL4VCMoUNjto/2F8eMljNDh0KKzQWg==
+-----END PUBLIC KEY-----
+";
+
+    #[cfg(all(feature = "alloc", feature = "encoding"))]
+    #[test]
+    fn test_from_pem() {
+        let public_key = PublicKey::from_pem(NISTP256_PEM).unwrap();
+        assert_eq!(
+            public_key.as_bytes(),
+            &b"\x04\x8f\xcf\xb4\xe8\xfe\x62\x50\x61\x7e\x10\x47\x6f\xf6\x04\x40\
+               \xfd\x94\x8c\x81\x4d\x2b\x81\x4f\xa3\x86\x45\xcf\x9c\x25\x8b\x7f\
+               \xfd\x4b\x3a\x41\x1c\xe3\x21\xeb\xdf\xcb\x67\x3f\x7a\xf7\x3e\x05\
+               \x13\x61\xd6\x13\x1e\x79\x3d\x5c\x89\x30\x72\x69\xc5\xae\x5b\xd5\x7f"[..]
+        );
+
+        assert!(Secp256k1PublicKey::from_pem(SECP256K1_PEM).is_ok());
+
+        // Curve mismatch, and a key for another curve's size
+        let err = PublicKey::from_pem(SECP256K1_PEM).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+        let err = NistP384PublicKey::from_pem(NISTP256_PEM).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+
+        // Truncated body and missing encapsulation boundaries
+        let truncated = NISTP256_PEM.replace("rlvVfw==", "");
+        let err = PublicKey::from_pem(&truncated).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+        let err = PublicKey::from_pem(&NISTP256_PEM[27..]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+        let private_key = NISTP256_PEM.replace("PUBLIC KEY", "EC PRIVATE KEY");
+        let err = PublicKey::from_pem(&private_key).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+    }
+}
@@ -0,0 +1,104 @@
+//! Conversions between Signatory's ECDSA signatures and those of the
+//! RustCrypto `ecdsa` crate, e.g. for signing with a Signatory provider (such
+//! as an HSM) and verifying with RustCrypto. Enable the `rustcrypto` cargo
+//! feature to use them.
+//!
+//! Conversions are only implemented between signatures over the same curve,
+//! e.g. `FixedSignature<NistP256>` and `ecdsa::Signature<p256::NistP256>`,
+//! so converting a signature over one curve to another is a type error.
+//!
+//! RustCrypto signatures always have non-zero `r` and `s` values less than
+//! the curve's order, so converting to them is fallible (`TryFrom`), whereas
+//! converting from them always succeeds (`From`).
+
+use core::convert::TryFrom;
+use k256;
+use p256;
+use p384;
+use rustcrypto_ecdsa;
+
+use super::{Asn1Signature, FixedSignature};
+use curve::{NistP256, NistP384, Secp256k1, WeierstrassCurve};
+use error::Error;
+use Signature;
+
+macro_rules! impl_rustcrypto_conversions {
+    ($curve:ty, $rustcrypto_curve:ty) => {
+        impl From<rustcrypto_ecdsa::Signature<$rustcrypto_curve>> for FixedSignature<$curve> {
+            fn from(signature: rustcrypto_ecdsa::Signature<$rustcrypto_curve>) -> Self {
+                Self::from_bytes(signature.to_bytes().as_slice()).unwrap()
+            }
+        }
+
+        impl From<rustcrypto_ecdsa::der::Signature<$rustcrypto_curve>> for Asn1Signature<$curve> {
+            fn from(signature: rustcrypto_ecdsa::der::Signature<$rustcrypto_curve>) -> Self {
+                Self::from_bytes(signature.as_bytes()).unwrap()
+            }
+        }
+
+        impl<'a> TryFrom<&'a FixedSignature<$curve>>
+            for rustcrypto_ecdsa::Signature<$rustcrypto_curve>
+        {
+            type Error = Error;
+
+            fn try_from(signature: &'a FixedSignature<$curve>) -> Result<Self, Error> {
+                Self::from_slice(signature.as_ref()).map_err(|_| {
+                    err!(
+                        SignatureInvalid,
+                        "'r' or 's' out of range for {:?}",
+                        <$curve as WeierstrassCurve>::CURVE_KIND
+                    )
+                })
+            }
+        }
+
+        impl<'a> TryFrom<&'a Asn1Signature<$curve>>
+            for rustcrypto_ecdsa::der::Signature<$rustcrypto_curve>
+        {
+            type Error = Error;
+
+            fn try_from(signature: &'a Asn1Signature<$curve>) -> Result<Self, Error> {
+                Self::from_bytes(signature.as_ref()).map_err(|_| {
+                    err!(
+                        SignatureInvalid,
+                        "'r' or 's' out of range for {:?}",
+                        <$curve as WeierstrassCurve>::CURVE_KIND
+                    )
+                })
+            }
+        }
+    };
+}
+
+impl_rustcrypto_conversions!(NistP256, p256::NistP256);
+impl_rustcrypto_conversions!(NistP384, p384::NistP384);
+impl_rustcrypto_conversions!(Secp256k1, k256::Secp256k1);
+
+#[cfg(all(test, feature = "test-vectors"))]
+mod tests {
+    use core::convert::TryFrom;
+    use curve::nistp256::{Asn1Signature, FixedSignature, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use p256;
+    use rustcrypto_ecdsa;
+    use Signature;
+
+    #[test]
+    fn test_rustcrypto_roundtrip() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let fixed_signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let rustcrypto_signature =
+                rustcrypto_ecdsa::Signature::<p256::NistP256>::try_from(&fixed_signature).unwrap();
+            assert_eq!(FixedSignature::from(rustcrypto_signature), fixed_signature);
+
+            let asn1_signature = Asn1Signature::from(&fixed_signature);
+            let rustcrypto_der =
+                rustcrypto_ecdsa::der::Signature::<p256::NistP256>::try_from(&asn1_signature)
+                    .unwrap();
+            assert_eq!(Asn1Signature::from(rustcrypto_der), asn1_signature);
+        }
+
+        // RustCrypto rejects zero scalars
+        let zero = FixedSignature::default();
+        assert!(rustcrypto_ecdsa::Signature::<p256::NistP256>::try_from(&zero).is_err());
+    }
+}
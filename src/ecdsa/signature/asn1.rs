@@ -1,24 +1,37 @@
 //! ASN.1 DER-encoded ECDSA signatures
 
+#[cfg(feature = "encoding")]
+use core::cmp;
 use core::fmt::{self, Debug};
 use core::marker::PhantomData;
 use generic_array::typenum::Unsigned;
 use generic_array::GenericArray;
+#[cfg(all(feature = "encoding", feature = "std"))]
+use std::io::Read;
 #[cfg(feature = "encoding")]
 use subtle_encoding::Encoding;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 #[cfg(feature = "encoding")]
-use super::{fixed::FixedSignature, scalars::ScalarPair};
+use super::{
+    fixed::FixedSignature,
+    scalars::{ParseStats, ScalarPair},
+};
 use curve::WeierstrassCurve;
 use ecdsa;
 #[cfg(feature = "encoding")]
+use encoding::asn1;
+#[cfg(feature = "encoding")]
 use encoding::Decode;
 #[cfg(all(feature = "alloc", feature = "encoding"))]
-use encoding::Encode;
+use encoding::{encode_vec, Encode};
 use error::Error;
 #[allow(unused_imports)]
 use prelude::*;
 use util::fmt_colon_delimited_hex;
+#[cfg(feature = "encoding")]
+use Signature;
 
 /// ECDSA signatures encoded as ASN.1 DER
 #[derive(Clone, PartialEq, Eq)]
@@ -37,14 +50,18 @@ impl<C> ::Signature for Asn1Signature<C>
 where
     C: WeierstrassCurve,
 {
-    /// Decode an ASN.1 DER-serialized ECDSA signature
+    /// Decode an ASN.1 DER-serialized ECDSA signature.
+    ///
+    /// When the `encoding` feature is enabled, the input is parsed to ensure
+    /// it's well-formed DER which consists of exactly one `SEQUENCE`, so the
+    /// length of the signature is always the length of the DER document and
+    /// any trailing data after it is rejected with a `ParseError`.
     fn from_bytes<B>(bytes: B) -> Result<Self, Error>
     where
         B: AsRef<[u8]>,
     {
         let length = bytes.as_ref().len();
 
-        // TODO: better validate signature is well-formed ASN.1 DER
         ensure!(
             length <= C::Asn1SignatureMaxSize::to_usize(),
             SignatureInvalid,
@@ -62,7 +79,8 @@ where
             curve: PhantomData,
         };
 
-        // Ensure result is well-formed ASN.1 DER
+        // Ensure result is well-formed ASN.1 DER, including that the SEQUENCE
+        // length covers the entire input (i.e. there's no trailing data)
         #[cfg(feature = "encoding")]
         ScalarPair::from_asn1_signature(&result)?;
 
@@ -72,6 +90,238 @@ where
 
 impl<C: WeierstrassCurve> ecdsa::Signature for Asn1Signature<C> {}
 
+impl<C> Asn1Signature<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Create an ASN.1 DER signature from separate big endian `r` and `s`
+    /// values (e.g. as returned by an HSM), without going through an
+    /// intermediate `FixedSignature`.
+    ///
+    /// The values may be either fixed-width (i.e. zero-padded) or minimal,
+    /// but can't be larger than `C::ScalarSize` bytes once leading zeroes are
+    /// removed. The resulting DER is always minimally encoded.
+    #[cfg(feature = "encoding")]
+    pub fn from_scalars(r: &[u8], s: &[u8]) -> Result<Self, Error> {
+        ScalarPair::from_scalars(r, s)?.to_asn1_signature_checked()
+    }
+
+    /// Compute the length of the ASN.1 DER signature `from_scalars` would
+    /// produce for the given `r` and `s` values without serializing it, e.g.
+    /// to write a protocol's length prefix ahead of the signature.
+    ///
+    /// The values are subject to the same restrictions as in `from_scalars`.
+    #[cfg(feature = "encoding")]
+    pub fn encoded_len_for(r: &[u8], s: &[u8]) -> Result<usize, Error> {
+        Ok(ScalarPair::<C>::from_scalars(r, s)?.asn1_encoded_len())
+    }
+
+    /// Decode an ASN.1 DER-serialized ECDSA signature from the contents of
+    /// an ASN.1 `BIT STRING` (i.e. sans tag and length), as in the
+    /// `signatureValue` of an X.509 certificate (see RFC 5480 section 2.2).
+    ///
+    /// The first byte of a `BIT STRING` is the number of unused bits, which
+    /// must be zero (with anything else being a `ParseError`), and the rest is
+    /// the DER-encoded signature.
+    #[cfg(feature = "encoding")]
+    pub fn from_bit_string(bytes: &[u8]) -> Result<Self, Error> {
+        ensure!(
+            !bytes.is_empty(),
+            ParseError,
+            "ASN.1 error: empty BIT STRING"
+        );
+
+        ensure!(
+            bytes[0] == 0,
+            ParseError,
+            "ASN.1 error: expected 0 unused bits in BIT STRING (got {})",
+            bytes[0]
+        );
+
+        Self::from_bytes(&bytes[1..])
+    }
+
+    /// Decode an ASN.1 DER-serialized ECDSA signature, rejecting signatures
+    /// which `from_bytes` tolerates but which aren't strictly valid DER, i.e.
+    /// negative or non-minimally encoded `r` and `s` values, a long-form
+    /// `SEQUENCE` length which would fit in the short form, or INTEGER
+    /// lengths which don't exactly add up to the `SEQUENCE` length.
+    #[cfg(feature = "encoding")]
+    pub fn from_bytes_strict<B: AsRef<[u8]>>(bytes: B) -> Result<Self, Error> {
+        let signature = Self::from_bytes(bytes)?;
+        ScalarPair::from_asn1_signature_strict(&signature)?;
+        Ok(signature)
+    }
+
+    /// Convert this signature to a fixed-width signature, returning an error
+    /// instead of panicking if its `r` and `s` values can't be parsed.
+    ///
+    /// `FixedSignature::from(&asn1_signature)` panics in that case, so code
+    /// built with the `no-panic` feature should use this instead.
+    #[cfg(feature = "encoding")]
+    pub fn to_fixed_signature_checked(&self) -> Result<FixedSignature<C>, Error> {
+        ScalarPair::from_asn1_signature(self)?.to_fixed_signature()
+    }
+
+    /// Convert this signature to a fixed-width signature like
+    /// `to_fixed_signature_checked`, also returning `ParseStats` describing
+    /// how `r` and `s` were encoded (e.g. superfluous leading zeroes), which
+    /// strictness checkers can use to flag non-minimal signatures.
+    #[cfg(feature = "encoding")]
+    pub fn to_fixed_signature_with_stats(&self) -> Result<(FixedSignature<C>, ParseStats), Error> {
+        let (scalars, stats) = ScalarPair::from_asn1_signature_with_stats(self)?;
+        Ok((scalars.to_fixed_signature()?, stats))
+    }
+
+    /// Compare the `r` and `s` values of two signatures, ignoring differences
+    /// in their DER encoding (e.g. superfluous leading zeroes tolerated by
+    /// `from_bytes`), as opposed to `PartialEq` which compares bytes.
+    ///
+    /// Returns `false` if either signature's `r` and `s` can't be parsed.
+    #[cfg(feature = "encoding")]
+    pub fn canonical_eq(&self, other: &Self) -> bool {
+        match (
+            self.to_fixed_signature_checked(),
+            other.to_fixed_signature_checked(),
+        ) {
+            (Ok(a), Ok(b)) => a.as_ref() == b.as_ref(),
+            _ => false,
+        }
+    }
+
+    /// Decode an ASN.1 DER-serialized ECDSA signature (as in `from_bytes`),
+    /// additionally returning a trace of the structural elements (i.e. the
+    /// `SEQUENCE` and `r` and `s` INTEGERs) encountered while parsing it.
+    ///
+    /// The trace ends at the first element whose header is missing or whose
+    /// declared length overruns the input, so the last step shows where a
+    /// malformed signature went wrong. This is a diagnostic aid for debugging
+    /// interop problems, and is only available with the `testing` feature.
+    #[cfg(all(feature = "alloc", feature = "encoding", feature = "testing"))]
+    pub fn parse_with_trace(bytes: &[u8]) -> (Result<Self, Error>, Vec<ParseStep>) {
+        let mut trace = vec![];
+        trace_signature(bytes, &mut trace);
+        (Self::from_bytes(bytes), trace)
+    }
+
+    /// Get the `(min, max)` length in bytes of a DER-encoded signature over
+    /// this curve, e.g. for bounds checking when parsing a framed protocol.
+    ///
+    /// The minimum is when `r` and `s` are both a single byte, and the
+    /// maximum is when both are `C::ScalarSize` bytes plus a leading zero byte
+    /// (for the sign). The maximum is at most `C::Asn1SignatureMaxSize`.
+    ///
+    /// This can't (yet) be a `const fn`, as it's computed from `C::ScalarSize`.
+    pub fn length_bounds() -> (usize, usize) {
+        // SEQUENCE header, plus INTEGER tag and length for `r` and `s`
+        let min = 2 + 2 * (2 + 1);
+
+        let max_content_len = 2 * (2 + C::ScalarSize::to_usize() + 1);
+        let max_header_len = if max_content_len < 0x80 { 2 } else { 3 };
+
+        (min, max_header_len + max_content_len)
+    }
+
+    /// Write this signature's ASN.1 DER serialization into the given
+    /// (reusable) buffer, returning its length in bytes. The DER-encoded
+    /// signature is `&buffer[..length]`, and the rest of the buffer is left
+    /// as-is.
+    ///
+    /// This avoids allocating when serializing many signatures, e.g. in a
+    /// signing loop on an embedded device.
+    pub fn encode_reusing(&self, buffer: &mut GenericArray<u8, C::Asn1SignatureMaxSize>) -> usize {
+        buffer[..self.length].copy_from_slice(self.as_ref());
+        self.length
+    }
+
+    /// Decode an ASN.1 DER-encoded signature which may be followed by
+    /// trailing data (as emitted by some buggy encoders), ignoring anything
+    /// after the `SEQUENCE`.
+    ///
+    /// The length of the signature is determined from the `SEQUENCE` header,
+    /// and the signature itself must be accepted by `from_bytes` (so, like
+    /// `from_bytes`, a non-minimal long-form length is allowed). This is an
+    /// opt-in lenient alternative to `from_bytes`, which rejects trailing data.
+    #[cfg(feature = "encoding")]
+    pub fn from_der_ignore_trailing(bytes: &[u8]) -> Result<Self, Error> {
+        let (content_len, header_len) = asn1::parse_header(asn1::Tag::Sequence, bytes, false)?;
+        let length = header_len + content_len;
+
+        ensure!(
+            length <= bytes.len(),
+            ParseError,
+            "ASN.1 error: truncated signature (expected {} bytes, got {})",
+            length,
+            bytes.len()
+        );
+
+        Self::from_bytes(&bytes[..length])
+    }
+
+    /// Decode an ASN.1 DER-serialized ECDSA signature from untrusted input,
+    /// bounding the work done on it: if the total length declared by the
+    /// `SEQUENCE` header exceeds `max_len` bytes, it's rejected with a
+    /// `ParseError` before any further parsing.
+    ///
+    /// A malicious header claiming a huge length is rejected up front, as
+    /// are lengths encoded in more than two bytes. The bound is always capped at
+    /// `C::Asn1SignatureMaxSize`, which is the natural default for `max_len`.
+    /// Otherwise, this is equivalent to `from_bytes`.
+    #[cfg(feature = "encoding")]
+    pub fn from_bytes_bounded(bytes: &[u8], max_len: usize) -> Result<Self, Error> {
+        let max_len = cmp::min(max_len, C::Asn1SignatureMaxSize::to_usize());
+        let (content_len, header_len) = asn1::parse_header(asn1::Tag::Sequence, bytes, false)?;
+        let length = header_len + content_len;
+
+        ensure!(
+            length <= max_len,
+            ParseError,
+            "ASN.1 error: declared length exceeds max {}-byte signature (got {})",
+            max_len,
+            length
+        );
+
+        Self::from_bytes(bytes)
+    }
+
+    /// Read an ASN.1 DER-encoded signature from the given `io::Read`,
+    /// consuming exactly the bytes of the signature and nothing more.
+    ///
+    /// The length is determined from the `SEQUENCE` header (in either its
+    /// short or long form) and capped at `C::Asn1SignatureMaxSize`. Longer
+    /// signatures are rejected with a `ParseError` before reading their
+    /// contents.
+    #[cfg(all(feature = "encoding", feature = "std"))]
+    pub fn read_der<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        let mut bytes: GenericArray<u8, C::Asn1SignatureMaxSize> = GenericArray::default();
+        reader.read_exact(&mut bytes[..2])?;
+
+        // Read the rest of a long-form length (if any) before decoding it
+        let header_len = match bytes[1] {
+            0x81 => 3,
+            0x82 => 4,
+            _ => 2,
+        };
+
+        reader.read_exact(&mut bytes[2..header_len])?;
+
+        let (content_len, header_len) =
+            asn1::parse_header(asn1::Tag::Sequence, &bytes[..header_len], false)?;
+        let length = header_len + content_len;
+
+        ensure!(
+            length <= C::Asn1SignatureMaxSize::to_usize(),
+            ParseError,
+            "max {}-byte signature (got {})",
+            C::Asn1SignatureMaxSize::to_usize(),
+            length
+        );
+
+        reader.read_exact(&mut bytes[header_len..length])?;
+        Self::from_bytes(&bytes[..length])
+    }
+}
+
 impl<C> AsRef<[u8]> for Asn1Signature<C>
 where
     C: WeierstrassCurve,
@@ -124,7 +374,29 @@ where
     /// Encode an ASN.1 encoded ECDSA signature with the given encoding
     /// (e.g. hex, Base64)
     fn encode<E: Encoding>(&self, encoding: &E) -> Vec<u8> {
-        encoding.encode(self.as_ref())
+        encode_vec(encoding, self.as_ref())
+    }
+}
+
+/// Overwrite the signature with zeroes, for policies which require scrubbing
+/// all cryptographic material (even though signatures aren't secret)
+#[cfg(feature = "zeroize")]
+impl<C> Zeroize for Asn1Signature<C>
+where
+    C: WeierstrassCurve,
+{
+    fn zeroize(&mut self) {
+        self.bytes.as_mut_slice().zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C> Drop for Asn1Signature<C>
+where
+    C: WeierstrassCurve,
+{
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
 
@@ -145,11 +417,108 @@ impl<'s, C> From<&'s Asn1Signature<C>> for FixedSignature<C>
 where
     C: WeierstrassCurve,
 {
+    /// Parse `r` and `s` values from an ASN.1 DER signature, panicking if
+    /// they can't be (which `Asn1Signature`'s constructors should always
+    /// prevent). Use `Asn1Signature::to_fixed_signature_checked` where a
+    /// panic is unacceptable (e.g. with the `no-panic` feature).
     fn from(asn1_signature: &Asn1Signature<C>) -> FixedSignature<C> {
-        // We always ensure `Asn1Signature`s parse successfully, so this should always work
-        ScalarPair::from_asn1_signature(asn1_signature)
-            .unwrap()
-            .to_fixed_signature()
+        asn1_signature.to_fixed_signature_checked().unwrap()
+    }
+}
+
+/// Structural element encountered by `Asn1Signature::parse_with_trace`
+#[cfg(all(feature = "alloc", feature = "encoding", feature = "testing"))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseStep {
+    /// Offset of the element's first byte in the input
+    pub offset: usize,
+
+    /// Tag byte of the element, which may not be the expected one
+    pub tag: u8,
+
+    /// Length of the element's contents (or of trailing data), which may
+    /// overrun the input
+    pub length: usize,
+
+    /// What the element was expected to be, e.g. `"INTEGER r"`
+    pub description: &'static str,
+}
+
+#[cfg(all(feature = "alloc", feature = "encoding", feature = "testing"))]
+impl fmt::Display for ParseStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "offset {}: {} (tag 0x{:02x}, length {})",
+            self.offset, self.description, self.tag, self.length
+        )
+    }
+}
+
+/// Trace the `SEQUENCE` of two INTEGERs of a DER-encoded signature, followed
+/// by any trailing data (within the `SEQUENCE`, or after it)
+#[cfg(all(feature = "alloc", feature = "encoding", feature = "testing"))]
+fn trace_signature(bytes: &[u8], trace: &mut Vec<ParseStep>) {
+    let (mut offset, end) = match trace_element(bytes, 0, "SEQUENCE", trace) {
+        Some(contents) => contents,
+        None => return,
+    };
+
+    for description in &["INTEGER r", "INTEGER s"] {
+        match trace_element(&bytes[..end], offset, description, trace) {
+            Some((_, element_end)) => offset = element_end,
+            None => return,
+        }
+    }
+
+    for &(start, end, description) in &[
+        (offset, end, "trailing data in SEQUENCE"),
+        (end, bytes.len(), "trailing data after SEQUENCE"),
+    ] {
+        if end > start {
+            trace.push(ParseStep {
+                offset: start,
+                tag: bytes[start],
+                length: end - start,
+                description,
+            });
+        }
+    }
+}
+
+/// Trace the tag-length-value element at the given offset, returning the
+/// offsets of its contents, or `None` if its header is missing or malformed,
+/// or its contents overrun the input.
+///
+/// Lengths are parsed leniently (i.e. non-minimal lengths are accepted),
+/// as the trace describes the input rather than validating it.
+#[cfg(all(feature = "alloc", feature = "encoding", feature = "testing"))]
+fn trace_element(
+    bytes: &[u8],
+    offset: usize,
+    description: &'static str,
+    trace: &mut Vec<ParseStep>,
+) -> Option<(usize, usize)> {
+    let header = bytes.get(offset..)?;
+    let tag = *header.get(0)?;
+
+    let (length, length_len) = asn1::parse_length(&header[1..], false).ok()?;
+    let header_len = 1 + length_len;
+
+    trace.push(ParseStep {
+        offset,
+        tag,
+        length,
+        description,
+    });
+
+    let start = offset + header_len;
+    let end = start.checked_add(length)?;
+
+    if end <= bytes.len() {
+        Some((start, end))
+    } else {
+        None
     }
 }
 
@@ -157,6 +526,9 @@ where
 #[allow(unused_imports)]
 mod tests {
     use curve::nistp256::{Asn1Signature, FixedSignature, SHA256_FIXED_SIZE_TEST_VECTORS};
+    #[cfg(all(feature = "alloc", feature = "testing"))]
+    use ecdsa::ParseStep;
+    use generic_array::GenericArray;
     use Signature;
 
     #[test]
@@ -171,4 +543,289 @@ mod tests {
             assert_eq!(fixed_signature, fixed_signature2);
         }
     }
+
+    #[test]
+    fn test_length_bounds() {
+        use curve::{NistP384, WeierstrassCurve};
+        use generic_array::typenum::Unsigned;
+
+        assert_eq!(Asn1Signature::length_bounds(), (8, 72));
+        assert_eq!(
+            ::ecdsa::Asn1Signature::<NistP384>::length_bounds(),
+            (8, 104)
+        );
+
+        let max_size = <NistP384 as WeierstrassCurve>::Asn1SignatureMaxSize::to_usize();
+        assert!(::ecdsa::Asn1Signature::<NistP384>::length_bounds().1 <= max_size);
+
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let fixed_signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let len = Asn1Signature::from(&fixed_signature).as_ref().len();
+            let (min, max) = Asn1Signature::length_bounds();
+            assert!(min <= len && len <= max);
+        }
+    }
+
+    #[test]
+    fn test_encode_reusing() {
+        let mut buffer = GenericArray::default();
+
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let fixed_signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let asn1_signature = Asn1Signature::from(&fixed_signature);
+
+            let length = asn1_signature.encode_reusing(&mut buffer);
+            assert_eq!(&buffer[..length], asn1_signature.as_ref());
+        }
+    }
+
+    #[test]
+    fn test_from_scalars() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let fixed_signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let asn1_signature =
+                Asn1Signature::from_scalars(&vector.sig[..32], &vector.sig[32..]).unwrap();
+
+            assert_eq!(asn1_signature, Asn1Signature::from(&fixed_signature));
+        }
+
+        // Minimal scalars are accepted too, but overlong ones are rejected
+        let asn1_signature = Asn1Signature::from_scalars(b"\x01", b"\x00\x7f").unwrap();
+        assert_eq!(asn1_signature.as_ref(), b"\x30\x06\x02\x01\x01\x02\x01\x7f");
+        assert!(Asn1Signature::from_scalars(&[0x01; 33], b"\x01").is_err());
+    }
+
+    #[test]
+    fn test_encoded_len_for() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let (r, s) = vector.sig.split_at(32);
+            let asn1_signature = Asn1Signature::from_scalars(r, s).unwrap();
+            assert_eq!(
+                Asn1Signature::encoded_len_for(r, s).unwrap(),
+                asn1_signature.as_ref().len()
+            );
+        }
+
+        // Minimal scalars, and full-width scalars which need sign padding
+        assert_eq!(Asn1Signature::encoded_len_for(b"\x01", b"\x7f").unwrap(), 8);
+        assert_eq!(
+            Asn1Signature::encoded_len_for(&[0x80; 32], &[0xff; 32]).unwrap(),
+            72
+        );
+        assert!(Asn1Signature::encoded_len_for(&[0x01; 33], b"\x01").is_err());
+    }
+
+    #[test]
+    fn test_canonical_eq() {
+        let minimal = Asn1Signature::from_bytes(b"\x30\x06\x02\x01\x01\x02\x01\x7f").unwrap();
+        let padded =
+            Asn1Signature::from_bytes(b"\x30\x08\x02\x02\x00\x01\x02\x02\x00\x7f").unwrap();
+        let other = Asn1Signature::from_bytes(b"\x30\x06\x02\x01\x01\x02\x01\x7e").unwrap();
+
+        assert_ne!(minimal, padded);
+        assert!(minimal.canonical_eq(&padded));
+        assert!(padded.canonical_eq(&minimal));
+        assert!(!minimal.canonical_eq(&other));
+
+        // A 33-byte `r` with a nonzero leading byte can't be converted (and
+        // is rejected by `from_bytes`, so the signature is built directly)
+        let mut bytes = GenericArray::default();
+        bytes[..5].copy_from_slice(b"\x30\x26\x02\x21\x01");
+        bytes[37..40].copy_from_slice(b"\x02\x01\x7f");
+        let overlong_r = Asn1Signature {
+            bytes,
+            length: 40,
+            curve: super::PhantomData,
+        };
+        assert!(overlong_r.to_fixed_signature_checked().is_err());
+        assert!(!overlong_r.canonical_eq(&minimal));
+        assert!(!minimal.canonical_eq(&overlong_r));
+    }
+
+    #[test]
+    fn test_from_bit_string() {
+        use error::ErrorKind;
+
+        let der = b"\x30\x06\x02\x01\x01\x02\x01\x7f";
+        let mut bit_string = vec![0x00];
+        bit_string.extend_from_slice(der);
+
+        let signature = Asn1Signature::from_bit_string(&bit_string).unwrap();
+        assert_eq!(signature.as_ref(), &der[..]);
+
+        bit_string[0] = 0x01;
+
+        for bytes in &[&b""[..], &der[..], &bit_string[..]] {
+            let err = Asn1Signature::from_bit_string(bytes).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+
+    #[test]
+    fn test_rejects_trailing_data() {
+        use error::ErrorKind;
+
+        let valid = b"\x30\x06\x02\x01\x01\x02\x01\x01";
+        let signature = Asn1Signature::from_bytes(&valid[..]).unwrap();
+        assert_eq!(signature.as_ref(), &valid[..]);
+
+        let mut bytes = valid.to_vec();
+        bytes.extend_from_slice(b"junk");
+
+        let err = Asn1Signature::from_bytes(&bytes).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn test_from_der_ignore_trailing() {
+        let valid = b"\x30\x06\x02\x01\x01\x02\x01\x01";
+        let with_junk = b"\x30\x06\x02\x01\x01\x02\x01\x01junk";
+
+        for bytes in &[&valid[..], &with_junk[..]] {
+            let signature = Asn1Signature::from_der_ignore_trailing(bytes).unwrap();
+            assert_eq!(signature.as_ref(), &valid[..]);
+        }
+
+        // Truncated or malformed signatures are still rejected
+        for bytes in &[&b"\x30"[..], &b"\x30\x07\x02\x01\x01\x02\x01\x01"[..]] {
+            assert!(Asn1Signature::from_der_ignore_trailing(bytes).is_err());
+        }
+
+        assert!(Asn1Signature::from_der_ignore_trailing(b"\x30\x03\x02\x01\x01junk").is_err());
+
+        // Non-minimal lengths are accepted, as they are by `from_bytes`
+        let non_minimal = b"\x30\x81\x06\x02\x01\x01\x02\x01\x01";
+        assert!(Asn1Signature::from_bytes(&non_minimal[..]).is_ok());
+
+        let signature =
+            Asn1Signature::from_der_ignore_trailing(b"\x30\x81\x06\x02\x01\x01\x02\x01\x01junk")
+                .unwrap();
+        assert_eq!(signature.as_ref(), &non_minimal[..]);
+    }
+
+    #[test]
+    fn test_from_bytes_bounded() {
+        use error::ErrorKind;
+
+        let valid = b"\x30\x06\x02\x01\x01\x02\x01\x01";
+        let signature = Asn1Signature::from_bytes_bounded(valid, 8).unwrap();
+        assert_eq!(signature.as_ref(), &valid[..]);
+
+        let err = Asn1Signature::from_bytes_bounded(valid, 7).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+
+        // Huge long-form lengths are rejected before parsing the contents
+        for bytes in &[
+            &b"\x30\x84\xff\xff\xff\xff\x02\x01\x01"[..],
+            &b"\x30\x89\xff\xff\xff\xff\xff\xff\xff\xff\xff"[..],
+            &b"\x30\x81\x80"[..],
+        ] {
+            let err = Asn1Signature::from_bytes_bounded(bytes, usize::max_value()).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+
+    #[test]
+    fn test_rejects_truncated_signature() {
+        for bytes in &[&b""[..], &b"\x30"[..], &b"\x30\x81"[..]] {
+            assert!(Asn1Signature::from_bytes(bytes).is_err());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_der() {
+        use std::io::{Cursor, Read};
+
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let fixed_signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let asn1_signature = Asn1Signature::from(&fixed_signature);
+
+            let mut stream = asn1_signature.as_ref().to_vec();
+            stream.extend_from_slice(b"trailing");
+
+            let mut reader = Cursor::new(stream);
+            assert_eq!(
+                Asn1Signature::read_der(&mut reader).unwrap(),
+                asn1_signature
+            );
+
+            let mut remaining = vec![];
+            reader.read_to_end(&mut remaining).unwrap();
+            assert_eq!(remaining, b"trailing");
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_read_der_rejects_overlength() {
+        use error::ErrorKind;
+        use std::io::Cursor;
+
+        let mut reader = Cursor::new(b"\x30\x81\xff".to_vec());
+        let err = Asn1Signature::read_der(&mut reader).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "testing"))]
+    #[test]
+    fn test_parse_with_trace() {
+        let (result, trace) = Asn1Signature::parse_with_trace(b"\x30\x06\x02\x01\x01\x02\x01\x7f");
+        assert!(result.is_ok());
+
+        let step = |offset, length, description| ParseStep {
+            offset,
+            tag: if description == "SEQUENCE" {
+                0x30
+            } else {
+                0x02
+            },
+            length,
+            description,
+        };
+
+        assert_eq!(
+            trace,
+            [
+                step(0, 6, "SEQUENCE"),
+                step(2, 1, "INTEGER r"),
+                step(5, 1, "INTEGER s"),
+            ]
+        );
+
+        // Trailing data within the SEQUENCE is traced, and is an error
+        let (result, trace) =
+            Asn1Signature::parse_with_trace(b"\x30\x08\x02\x01\x01\x02\x01\x7f\x05\x00");
+        assert!(result.is_err());
+        assert_eq!(trace.len(), 4);
+        assert_eq!(trace[3].offset, 8);
+        assert_eq!(trace[3].length, 2);
+        assert_eq!(trace[3].description, "trailing data in SEQUENCE");
+
+        // The trace ends at the element which overruns the input
+        let (result, trace) = Asn1Signature::parse_with_trace(b"\x30\x06\x02\x01\x01\x02\x05\x7f");
+        assert!(result.is_err());
+        assert_eq!(trace.len(), 3);
+        assert_eq!(trace[2].description, "INTEGER s");
+        assert_eq!(trace[2].length, 5);
+
+        let (result, trace) = Asn1Signature::parse_with_trace(b"\x30");
+        assert!(result.is_err());
+        assert!(trace.is_empty());
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let fixed_signature =
+            FixedSignature::from_bytes(&SHA256_FIXED_SIZE_TEST_VECTORS[0].sig).unwrap();
+        let mut asn1_signature = Asn1Signature::from(&fixed_signature);
+        let asn1_len = asn1_signature.as_ref().len();
+
+        asn1_signature.zeroize();
+        assert_eq!(asn1_signature.as_ref().len(), asn1_len);
+        assert!(asn1_signature.as_ref().iter().all(|&byte| byte == 0));
+    }
 }
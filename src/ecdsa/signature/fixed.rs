@@ -1,23 +1,44 @@
 //! Fixed-size, compact ECDSA signatures (as used in e.g. PKCS#11)
 
+use core::convert::TryFrom;
 use core::fmt::{self, Debug};
 use core::marker::PhantomData;
+use core::mem::size_of;
+use core::slice;
+#[cfg(all(feature = "digest", feature = "sha2"))]
+use digest::{Digest, Input};
 use generic_array::{typenum::Unsigned, GenericArray};
+#[cfg(all(feature = "digest", feature = "sha2"))]
+use sha2::Sha256;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 #[cfg(feature = "encoding")]
 use subtle_encoding::Encoding;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
+use super::normalized::NormalizedSignature;
+use super::LengthPrefix;
+#[cfg(feature = "encoding")]
+use super::{asn1::Asn1Signature, scalars::ScalarPair};
 use curve::WeierstrassCurve;
 use ecdsa;
 #[cfg(feature = "encoding")]
+use encoding::asn1;
+#[cfg(feature = "encoding")]
 use encoding::Decode;
 #[cfg(all(feature = "alloc", feature = "encoding"))]
-use encoding::Encode;
+use encoding::{encode_vec, Encode};
 use error::Error;
 #[allow(unused_imports)]
 use prelude::*;
-use util::fmt_colon_delimited_hex;
+use util::{be_sub, be_wrapping_sub, ct_scalar_less_than, fmt_colon_delimited_hex};
+use Signature;
 
 /// ECDSA signatures serialized in a compact, fixed-sized form
+///
+/// Fixed-sized signatures are zeroized on drop (as are `Asn1Signature`s), and
+/// can also be zeroized explicitly. The `ecdsa` feature always enables
+/// `zeroize`, so this is unconditional.
 #[derive(Clone, PartialEq, Eq)]
 pub struct FixedSignature<C: WeierstrassCurve> {
     /// Signature data as bytes
@@ -51,10 +72,511 @@ impl<C> FixedSignature<C>
 where
     C: WeierstrassCurve,
 {
+    /// Size of this type in bytes (e.g. when held on the stack), i.e. the
+    /// size of the signature itself (`C::FixedSignatureSize`), with no
+    /// additional overhead. For embedded users budgeting stack usage.
+    ///
+    /// The other fixed-size ECDSA types are similarly laid out: a `SecretKey`
+    /// is `C::ScalarSize` bytes, and an `Asn1Signature` is
+    /// `C::Asn1SignatureMaxSize` bytes plus a `usize` length (plus padding).
+    /// Stack usage of signing and verification depends on the provider.
+    pub const STACK_SIZE: usize = size_of::<Self>();
+
+    /// Create a fixed-width signature from separate big endian `r` and `s`
+    /// values (e.g. as returned by an HSM or a provider which strips
+    /// leading zeroes).
+    ///
+    /// Values shorter than `C::ScalarSize` bytes are left-padded with zeroes,
+    /// i.e. right-aligned in their half of the signature. Leading zeroes
+    /// beyond `C::ScalarSize` are ignored, but the significant bytes of each
+    /// value can't be longer than `C::ScalarSize`.
+    pub fn from_scalars(r: &[u8], s: &[u8]) -> Result<Self, Error> {
+        let r = strip_leading_zeroes(r);
+        let s = strip_leading_zeroes(s);
+        let scalar_size = C::ScalarSize::to_usize();
+
+        ensure!(
+            r.len() <= scalar_size && s.len() <= scalar_size,
+            SignatureInvalid,
+            "expected 'r' and 's' of at most {} bytes (got {} and {})",
+            scalar_size,
+            r.len(),
+            s.len()
+        );
+
+        let mut bytes = GenericArray::default();
+        bytes[(scalar_size - r.len())..scalar_size].copy_from_slice(r);
+        bytes[(scalar_size * 2 - s.len())..].copy_from_slice(s);
+
+        Ok(Self::from(bytes))
+    }
+
+    /// Create a fixed-width signature from `r` and `s` values given as
+    /// unsigned decimal strings (as some JSON APIs encode them).
+    ///
+    /// Strings which are empty, contain anything other than ASCII digits, or
+    /// encode values which don't fit in `C::ScalarSize` bytes are a
+    /// `ParseError`.
+    #[cfg(feature = "alloc")]
+    pub fn from_decimal_strs(r: &str, s: &str) -> Result<Self, Error> {
+        let r = decimal_to_be_bytes(r, C::ScalarSize::to_usize())?;
+        let s = decimal_to_be_bytes(s, C::ScalarSize::to_usize())?;
+        Self::from_scalars(&r, &s)
+    }
+
+    /// Parse a signature produced by the W3C Web Cryptography API, i.e.
+    /// `crypto.subtle.sign({ name: "ECDSA", hash: ... }, key, data)`.
+    ///
+    /// WebCrypto ECDSA signatures are fixed-width `r || s` (not ASN.1 DER),
+    /// with the curve identified by the key's `namedCurve` (e.g. `"P-256"`
+    /// for `NistP256`, see `WeierstrassCurveKind::to_webcrypto_name`). This is
+    /// `from_bytes` with errors which name WebCrypto, and returns
+    /// `ErrorKind::Unsupported` for curves WebCrypto doesn't support.
+    pub fn from_webcrypto(bytes: &[u8]) -> Result<Self, Error> {
+        let named_curve = match C::CURVE_KIND.to_webcrypto_name() {
+            Some(name) => name,
+            None => fail!(
+                Unsupported,
+                "{:?} is not supported by WebCrypto",
+                C::CURVE_KIND
+            ),
+        };
+
+        ensure!(
+            bytes.len() == C::FixedSignatureSize::to_usize(),
+            SignatureInvalid,
+            "expected {}-byte WebCrypto ECDSA signature for namedCurve {} (got {} bytes; \
+             WebCrypto signatures are raw r || s, not ASN.1 DER)",
+            C::FixedSignatureSize::to_usize(),
+            named_curve,
+            bytes.len()
+        );
+
+        Ok(Self::from(GenericArray::clone_from_slice(bytes)))
+    }
+
+    /// Parse a signature obtained from a provider which may return either
+    /// fixed-width or ASN.1 DER signatures, normalizing it to fixed-width.
+    ///
+    /// Inputs which are exactly `C::FixedSignatureSize` bytes are always
+    /// treated as fixed-width signatures, even if they also happen to be
+    /// well-formed DER. Otherwise, inputs beginning with an ASN.1 `SEQUENCE`
+    /// tag (`0x30`) are parsed as DER and converted. Anything else is a
+    /// `ParseError`.
+    #[cfg(feature = "encoding")]
+    pub fn from_provider_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() == C::FixedSignatureSize::to_usize() {
+            Self::from_bytes(bytes)
+        } else if !bytes.is_empty() && bytes[0] == asn1::Tag::Sequence as u8 {
+            Asn1Signature::from_bytes(bytes)?.to_fixed_signature_checked()
+        } else {
+            fail!(
+                ParseError,
+                "expected {}-byte fixed signature or ASN.1 DER (got {} bytes)",
+                C::FixedSignatureSize::to_usize(),
+                bytes.len()
+            );
+        }
+    }
+
+    /// Parse a buffer of fixed-width signatures concatenated back-to-back
+    /// (e.g. an aggregated multisignature blob).
+    ///
+    /// The length of the buffer must be a multiple of `C::FixedSignatureSize`,
+    /// otherwise a `ParseError` is returned.
+    #[cfg(feature = "alloc")]
+    pub fn parse_many(bytes: &[u8]) -> Result<Vec<Self>, Error> {
+        let signature_size = C::FixedSignatureSize::to_usize();
+
+        ensure!(
+            bytes.len() % signature_size == 0,
+            ParseError,
+            "expected a multiple of {}-byte signatures (got {} bytes)",
+            signature_size,
+            bytes.len()
+        );
+
+        Ok(bytes
+            .chunks(signature_size)
+            .map(|chunk| Self::from(GenericArray::clone_from_slice(chunk)))
+            .collect())
+    }
+
+    /// Parse a signature whose `r` and `s` scalars are each serialized as
+    /// little endian (as expected by some legacy embedded devices).
+    ///
+    /// NOTE: this is non-standard! Fixed-width ECDSA signatures are normally
+    /// big endian `r || s` (as parsed by `from_bytes`).
+    pub fn from_le_scalars(bytes: &[u8]) -> Result<Self, Error> {
+        let mut signature = Self::from_bytes(bytes)?;
+        signature.reverse_scalars();
+        Ok(signature)
+    }
+
+    /// Serialize this signature with its `r` and `s` scalars each byte-reversed
+    /// to little endian (as expected by some legacy embedded devices).
+    ///
+    /// NOTE: this is non-standard! See `from_le_scalars`.
+    #[cfg(feature = "alloc")]
+    pub fn to_le_scalars(&self) -> Vec<u8> {
+        let mut signature = self.clone();
+        signature.reverse_scalars();
+        signature.as_ref().to_vec()
+    }
+
+    /// Reverse the bytes of `r` and `s` in place (converting between big and
+    /// little endian)
+    fn reverse_scalars(&mut self) {
+        let (r, s) = self.bytes.split_at_mut(C::ScalarSize::to_usize());
+        r.reverse();
+        s.reverse();
+    }
+
+    /// Serialize this signature prefixed with its length, for framing it in
+    /// a length-delimited protocol.
+    ///
+    /// Returns `Unsupported` if the signature is too long for the prefix.
+    #[cfg(feature = "alloc")]
+    pub fn to_length_prefixed(&self, prefix: LengthPrefix) -> Result<Vec<u8>, Error> {
+        let mut prefix_bytes = [0u8; 3];
+        let prefix_len = prefix.encode(self.bytes.len(), &mut prefix_bytes)?;
+
+        let mut result = Vec::with_capacity(prefix_len + self.bytes.len());
+        result.extend_from_slice(&prefix_bytes[..prefix_len]);
+        result.extend_from_slice(self.as_ref());
+        Ok(result)
+    }
+
+    /// Parse a length-prefixed signature from the start of the given bytes,
+    /// returning the signature and the total number of bytes consumed
+    /// (i.e. the length of the prefix plus the signature). Any data after
+    /// the signature is ignored.
+    ///
+    /// Returns a `ParseError` if the input is truncated, or `SignatureInvalid`
+    /// if the prefix isn't the length of a fixed-width signature.
+    pub fn from_length_prefixed(
+        bytes: &[u8],
+        prefix: LengthPrefix,
+    ) -> Result<(Self, usize), Error> {
+        let (length, prefix_len) = prefix.decode(bytes)?;
+
+        ensure!(
+            length == C::FixedSignatureSize::to_usize(),
+            SignatureInvalid,
+            "expected {}-byte signature (got length prefix of {})",
+            C::FixedSignatureSize::to_usize(),
+            length
+        );
+
+        let end = prefix_len + length;
+
+        ensure!(
+            bytes.len() >= end,
+            ParseError,
+            "truncated signature: expected {} bytes (got {})",
+            end,
+            bytes.len()
+        );
+
+        let signature = Self::from(GenericArray::clone_from_slice(&bytes[prefix_len..end]));
+        Ok((signature, end))
+    }
+
+    /// Convert this signature to ASN.1 DER, always checking the serialized
+    /// signature is well-formed (even in release builds) and returning an
+    /// error instead of panicking if it isn't.
+    ///
+    /// `Asn1Signature::from(&fixed_signature)` only performs this check when
+    /// debug assertions are enabled.
+    #[cfg(feature = "encoding")]
+    pub fn to_asn1_signature_checked(&self) -> Result<Asn1Signature<C>, Error> {
+        ScalarPair::from_fixed_signature(self).to_asn1_signature_checked()
+    }
+
+    /// Self-test of the DER conversions: convert this signature to ASN.1 DER,
+    /// check the DER is strictly valid (i.e. minimally encoded), then convert
+    /// it back and check the result is identical to this signature.
+    ///
+    /// This should always be `true`: `false` indicates an asymmetry between
+    /// the conversions, e.g. for use as a runtime invariant check.
+    #[cfg(feature = "encoding")]
+    pub fn is_canonical_der_roundtrip(&self) -> bool {
+        self.to_asn1_signature_checked()
+            .and_then(|asn1_signature| Asn1Signature::<C>::from_bytes_strict(asn1_signature))
+            .and_then(|asn1_signature| asn1_signature.to_fixed_signature_checked())
+            .map(|fixed_signature| self.eq_bytes(fixed_signature.as_ref()))
+            .unwrap_or(false)
+    }
+
+    /// Convert this signature to valid but *non-minimal* ASN.1 DER, with `r`
+    /// and `s` always padded to `C::ScalarSize` bytes plus a leading zero
+    /// (sign) byte.
+    ///
+    /// This is NOT a canonical encoding (and is rejected by strict parsers,
+    /// e.g. `Asn1Signature::from_bytes_strict`). It's only intended for
+    /// interop/stress testing of DER parsers' handling of sign-padding.
+    #[cfg(all(feature = "encoding", feature = "testing"))]
+    pub fn to_asn1_signature_padded(&self) -> Asn1Signature<C> {
+        ScalarPair::from_fixed_signature(self).to_asn1_signature_padded()
+    }
+
     /// Convert signature into owned byte array
+    ///
+    /// This copies the bytes out, since the signature is zeroized when
+    /// it's dropped.
     #[inline]
     pub fn into_bytes(self) -> GenericArray<u8, C::FixedSignatureSize> {
-        self.bytes
+        self.bytes.clone()
+    }
+
+    /// Iterate over the bytes of this signature (`r || s`)
+    pub fn iter(&self) -> slice::Iter<'_, u8> {
+        self.bytes.iter()
+    }
+
+    /// Get the `r` component of this signature as a minimal big endian
+    /// integer, i.e. with leading zeroes stripped (an empty slice if `r` is
+    /// zero).
+    ///
+    /// ECDSA signatures provide three different views of `r` and `s`:
+    ///
+    /// - fixed-width: each scalar is left padded with zeroes to exactly
+    ///   `C::ScalarSize` bytes, as serialized in a `FixedSignature`
+    /// - DER: each scalar is an ASN.1 `INTEGER`, with leading zeroes stripped
+    ///   but an extra `0x00` prepended if the high bit is set (so it isn't
+    ///   negative), as serialized in an `Asn1Signature`
+    /// - minimal integer: leading zeroes stripped with no sign padding, as
+    ///   returned by this method and `s_bigint_bytes()`, e.g. for printing
+    ///   the integer values of a signature
+    pub fn r_bigint_bytes(&self) -> &[u8] {
+        strip_leading_zeroes(&self.bytes[..C::ScalarSize::to_usize()])
+    }
+
+    /// Get the `s` component of this signature as a minimal big endian
+    /// integer, i.e. with leading zeroes stripped (an empty slice if `s` is
+    /// zero).
+    ///
+    /// See `r_bigint_bytes()` for how this differs from the fixed-width and
+    /// DER representations.
+    pub fn s_bigint_bytes(&self) -> &[u8] {
+        strip_leading_zeroes(&self.bytes[C::ScalarSize::to_usize()..])
+    }
+
+    /// Get the number of significant bits in the `r` component of this
+    /// signature, i.e. ignoring leading zeroes (0 if `r` is zero).
+    ///
+    /// Useful for checking `r` against protocol-specific bit length limits.
+    pub fn r_bit_length(&self) -> usize {
+        bit_length(self.r_bigint_bytes())
+    }
+
+    /// Get the number of significant bits in the `s` component of this
+    /// signature, i.e. ignoring leading zeroes (0 if `s` is zero).
+    pub fn s_bit_length(&self) -> usize {
+        bit_length(self.s_bigint_bytes())
+    }
+
+    /// Is the `s` value of this signature "low", i.e. `s <= n/2` where `n`
+    /// is the order of the curve?
+    ///
+    /// For any valid signature `(r, s)`, `(r, n - s)` is also a valid
+    /// signature of the same message. Protocols which are sensitive to this
+    /// malleability (e.g. Bitcoin) require "low S" signatures.
+    ///
+    /// `s` is compared in constant time against the precomputed
+    /// `C::ORDER_HALF`.
+    pub fn is_low_s(&self) -> bool {
+        let scalar_size = C::ScalarSize::to_usize();
+        !bool::from(ct_scalar_less_than(
+            C::ORDER_HALF,
+            &self.bytes[scalar_size..],
+        ))
+    }
+
+    /// Normalize this signature to its "low S" form, replacing `s` with
+    /// `n - s` if `s` is greater than `n/2`.
+    ///
+    /// This is variable-time: whether `s` was high can be observed from how
+    /// long it takes. Use `conditional_normalize_s` to avoid that.
+    ///
+    /// An out-of-range `s` (i.e. `s >= n`, which is never valid) is left
+    /// as-is, as it has no "low S" form.
+    pub fn normalize_s(&mut self) {
+        let scalar_size = C::ScalarSize::to_usize();
+
+        if self.is_low_s() || !bool::from(ct_scalar_less_than(&self.bytes[scalar_size..], C::ORDER))
+        {
+            return;
+        }
+
+        let mut s: GenericArray<u8, C::ScalarSize> = GenericArray::default();
+        s.copy_from_slice(&self.bytes[scalar_size..]);
+        be_sub(C::ORDER, &s, &mut self.bytes[scalar_size..]);
+    }
+
+    /// Normalize this signature to its "low S" form like `normalize_s`, but
+    /// in constant time: `n - s` is always computed, and selected in place of
+    /// `s` without branching on whether `s` is greater than `n/2`.
+    ///
+    /// This is a hardened variant for protocols where whether a signature was
+    /// high-S is considered secret (e.g. before the signature is released).
+    ///
+    /// As with `normalize_s`, an out-of-range `s` (i.e. `s >= n`) is left
+    /// as-is: `n - s` wraps around for it, and is masked out.
+    pub fn conditional_normalize_s(&mut self) {
+        let scalar_size = C::ScalarSize::to_usize();
+        let s = &self.bytes[scalar_size..];
+
+        let mut negated_s: GenericArray<u8, C::ScalarSize> = GenericArray::default();
+        be_wrapping_sub(C::ORDER, s, &mut negated_s);
+
+        let is_high_s = ct_scalar_less_than(C::ORDER_HALF, s) & ct_scalar_less_than(s, C::ORDER);
+
+        for (byte, negated_byte) in self.bytes[scalar_size..].iter_mut().zip(negated_s.iter()) {
+            byte.conditional_assign(negated_byte, is_high_s);
+        }
+    }
+
+    /// Get the "high S" form of this signature, i.e. with `s` replaced by
+    /// `n - s` if it's currently low (see `is_low_s`). This is the inverse of
+    /// `normalize_s`, for constructing malleated signatures to test that
+    /// "low S" policies reject them.
+    ///
+    /// An `s` of zero (which is never valid) is left as-is.
+    #[cfg(feature = "testing")]
+    pub fn force_high_s(&self) -> Self {
+        let mut signature = self.clone();
+        let scalar_size = C::ScalarSize::to_usize();
+        let s = &self.bytes[scalar_size..];
+
+        if self.is_low_s() && s.iter().any(|&byte| byte != 0) {
+            be_sub(C::ORDER, s, &mut signature.bytes[scalar_size..]);
+        }
+
+        signature
+    }
+
+    /// Normalize this signature to "low S" form (see `normalize_s`),
+    /// returning a `NormalizedSignature` which is guaranteed to be canonical.
+    ///
+    /// Returns `ErrorKind::SignatureInvalid` if `s` is zero or `s >= n`.
+    pub fn into_normalized(self) -> Result<NormalizedSignature<C>, Error> {
+        NormalizedSignature::try_from(self)
+    }
+
+    /// Compute a short fingerprint which identifies this signature, e.g. for
+    /// correlating log messages without including the entire signature.
+    ///
+    /// The fingerprint is the first 8 bytes of the SHA-256 digest of the
+    /// signature's "low S" normalized form, so malleated variants of the same
+    /// signature have the same fingerprint. Signatures with an out-of-range
+    /// `s` are fingerprinted as-is (see `normalize_s`).
+    #[cfg(all(feature = "digest", feature = "sha2"))]
+    pub fn fingerprint(&self) -> [u8; 8] {
+        let mut normalized = self.clone();
+        normalized.normalize_s();
+
+        let mut sha256 = Sha256::default();
+        sha256.process(normalized.as_ref());
+
+        let mut fingerprint = [0u8; 8];
+        fingerprint.copy_from_slice(&sha256.result()[..8]);
+        fingerprint
+    }
+
+    /// Compare this signature against the given bytes in constant time
+    /// (e.g. when checking attacker-supplied data against an expected
+    /// signature), without parsing them as a `FixedSignature`.
+    ///
+    /// Returns `false` if the length of `bytes` isn't `C::FixedSignatureSize`
+    /// (which isn't secret, so this isn't done in constant time).
+    pub fn eq_bytes(&self, bytes: &[u8]) -> bool {
+        bytes.len() == self.bytes.len() && self.bytes.as_slice().ct_eq(bytes).into()
+    }
+
+    /// Is this signature all zeroes? (i.e. a placeholder created with
+    /// `FixedSignature::default()` which has yet to be filled in)
+    pub fn is_zero(&self) -> bool {
+        self.bytes.iter().all(|&byte| byte == 0)
+    }
+
+    /// Select `a` or `b` in constant time: `a` if `choice` is 0, `b` if it's
+    /// 1, without branching on `choice` (e.g. when choosing between two
+    /// candidate signatures based on secret data).
+    ///
+    /// This is `subtle::ConditionallySelectable::conditional_select`, which
+    /// can't be implemented for `FixedSignature` as it isn't `Copy`.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut signature = a.clone();
+        signature.conditional_assign(b, choice);
+        signature
+    }
+
+    /// Overwrite this signature with `other` in constant time if `choice`
+    /// is 1, or leave it unchanged if it's 0 (see `conditional_select`)
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        for (byte, other_byte) in self.bytes.iter_mut().zip(other.bytes.iter()) {
+            byte.conditional_assign(other_byte, choice);
+        }
+    }
+}
+
+/// Strip leading zeroes from a big endian integer
+fn strip_leading_zeroes(bytes: &[u8]) -> &[u8] {
+    let leading_zeroes = bytes.iter().take_while(|&&byte| byte == 0).count();
+    &bytes[leading_zeroes..]
+}
+
+/// Parse an unsigned decimal string as a big endian integer of `len` bytes
+#[cfg(feature = "alloc")]
+fn decimal_to_be_bytes(decimal: &str, len: usize) -> Result<Vec<u8>, Error> {
+    ensure!(!decimal.is_empty(), ParseError, "empty decimal string");
+
+    let mut bytes = vec![0u8; len];
+
+    for c in decimal.chars() {
+        let mut carry = match c.to_digit(10) {
+            Some(digit) => digit,
+            None => fail!(ParseError, "invalid decimal digit: {:?}", c),
+        };
+
+        // bytes = bytes * 10 + digit
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 10;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        ensure!(
+            carry == 0,
+            ParseError,
+            "decimal value too large for {}-byte scalar",
+            len
+        );
+    }
+
+    Ok(bytes)
+}
+
+/// Number of significant bits in a minimal big endian integer
+pub(super) fn bit_length(bytes: &[u8]) -> usize {
+    match bytes.first() {
+        Some(&leading_byte) => bytes.len() * 8 - leading_byte.leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+impl<C> Default for FixedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Create an all-zero placeholder signature, e.g. for initializing
+    /// buffers of signatures which will be filled in later.
+    ///
+    /// NOTE: an all-zero signature is *never* valid (both `r` and `s` must be
+    /// nonzero) and will be rejected by any verifier.
+    fn default() -> Self {
+        Self::from(GenericArray::default())
     }
 }
 
@@ -71,13 +593,39 @@ impl<C> Debug for FixedSignature<C>
 where
     C: WeierstrassCurve,
 {
+    /// Format this signature as colon-delimited hex, or with `{:#?}` as the
+    /// curve and a contiguous lowercase hex string which can be pasted back
+    /// into code (e.g. `FixedSignature(NistP256, "f3ac...")`), which is stable
+    /// for use in snapshot tests
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "FixedSignature({:?}, \"", C::default())?;
+
+            for byte in self.iter() {
+                write!(f, "{:02x}", byte)?;
+            }
+
+            return write!(f, "\")");
+        }
+
         write!(f, "signatory::ecdsa::FixedSignature<{:?}>(", C::default())?;
         fmt_colon_delimited_hex(f, self.as_ref())?;
         write!(f, ")")
     }
 }
 
+impl<'a, C> IntoIterator for &'a FixedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    type Item = &'a u8;
+    type IntoIter = slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> slice::Iter<'a, u8> {
+        self.iter()
+    }
+}
+
 #[cfg(feature = "encoding")]
 impl<C> Decode for FixedSignature<C>
 where
@@ -109,7 +657,29 @@ where
     /// Encode an ASN.1 encoded ECDSA signature with the given encoding
     /// (e.g. hex, Base64)
     fn encode<E: Encoding>(&self, encoding: &E) -> Vec<u8> {
-        encoding.encode(self.as_ref())
+        encode_vec(encoding, self.as_ref())
+    }
+}
+
+/// Overwrite the signature with zeroes, for policies which require scrubbing
+/// all cryptographic material (even though signatures aren't secret)
+#[cfg(feature = "zeroize")]
+impl<C> Zeroize for FixedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    fn zeroize(&mut self) {
+        self.bytes.as_mut_slice().zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C> Drop for FixedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
 
@@ -124,3 +694,505 @@ where
         }
     }
 }
+
+#[cfg(all(test, feature = "encoding", feature = "test-vectors"))]
+mod tests {
+    use curve::nistp256::{Asn1Signature, FixedSignature, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use error::ErrorKind;
+    use subtle::Choice;
+    use Signature;
+
+    #[test]
+    fn test_is_canonical_der_roundtrip() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            assert!(signature.is_canonical_der_roundtrip());
+        }
+
+        // Edge cases for DER INTEGERs: high bits set (sign padding), leading
+        // zero bytes (stripped), and zero values
+        let mut bytes = [0u8; 64];
+        bytes[0] = 0x80;
+        bytes[33] = 0x01;
+        assert!(FixedSignature::from_bytes(&bytes[..])
+            .unwrap()
+            .is_canonical_der_roundtrip());
+
+        assert!(FixedSignature::from_bytes(&[0xffu8; 64][..])
+            .unwrap()
+            .is_canonical_der_roundtrip());
+
+        assert!(FixedSignature::default().is_canonical_der_roundtrip());
+    }
+
+    #[test]
+    fn test_normalize_s() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+
+            let mut normalized = signature.clone();
+            normalized.normalize_s();
+            assert!(normalized.is_low_s());
+            assert_eq!(normalized.as_ref()[..32], signature.as_ref()[..32]);
+
+            if signature.is_low_s() {
+                assert_eq!(normalized, signature);
+            } else {
+                assert_ne!(normalized, signature);
+            }
+
+            assert_eq!(*signature.into_normalized().unwrap(), normalized);
+        }
+    }
+
+    #[test]
+    fn test_stack_size() {
+        use core::mem::size_of;
+        use curve::nistp256::SecretKey;
+
+        assert_eq!(FixedSignature::STACK_SIZE, 64);
+        assert_eq!(size_of::<FixedSignature>(), FixedSignature::STACK_SIZE);
+        assert_eq!(size_of::<SecretKey>(), 32);
+
+        // 73 bytes of DER, padded to the alignment of its `usize` length
+        let length_size = size_of::<usize>();
+        let padded_der_size = (73 + length_size - 1) / length_size * length_size;
+        assert_eq!(size_of::<Asn1Signature>(), padded_der_size + length_size);
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_force_high_s() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let high_s = signature.force_high_s();
+            assert!(!high_s.is_low_s());
+            assert_eq!(high_s.as_ref()[..32], signature.as_ref()[..32]);
+
+            // Already high-S signatures are unchanged
+            assert_eq!(high_s.force_high_s(), high_s);
+            assert_eq!(
+                *high_s.into_normalized().unwrap(),
+                *signature.into_normalized().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_s_out_of_range() {
+        use curve::{NistP256, WeierstrassCurve};
+
+        let mut order = [0u8; 64];
+        order[32..].copy_from_slice(NistP256::ORDER);
+
+        for bytes in &[[0xff; 64], order] {
+            let mut signature = FixedSignature::from_bytes(&bytes[..]).unwrap();
+            signature.normalize_s();
+            assert_eq!(signature.as_ref(), &bytes[..]);
+
+            let mut signature = FixedSignature::from_bytes(&bytes[..]).unwrap();
+            signature.conditional_normalize_s();
+            assert_eq!(signature.as_ref(), &bytes[..]);
+        }
+    }
+
+    #[test]
+    fn test_into_normalized_rejects_invalid_s() {
+        use curve::{NistP256, WeierstrassCurve};
+
+        let mut zero_s = [0u8; 64];
+        zero_s[31] = 1;
+
+        let mut order = [0u8; 64];
+        order[31] = 1;
+        order[32..].copy_from_slice(NistP256::ORDER);
+
+        for bytes in &[zero_s, order, [0xffu8; 64]] {
+            let signature = FixedSignature::from_bytes(&bytes[..]).unwrap();
+            let err = signature.into_normalized().err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+        }
+
+        // n - 1 is the largest valid `s`, and is normalized to 1
+        let mut max_s = order;
+        max_s[63] -= 1;
+
+        let normalized = FixedSignature::from_bytes(&max_s[..])
+            .unwrap()
+            .into_normalized()
+            .unwrap();
+        assert_eq!(normalized.s_bigint_bytes(), &[1]);
+    }
+
+    #[test]
+    fn test_conditional_normalize_s() {
+        use curve::{NistP256, WeierstrassCurve};
+        use util::be_sub;
+
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+
+            // Malleate the signature to its other form, so both are tested
+            let mut malleated = signature.clone();
+            let (_, s) = vector.sig.split_at(32);
+            be_sub(NistP256::ORDER, s, &mut malleated.bytes[32..]);
+
+            for original in &[signature, malleated] {
+                let mut expected = original.clone();
+                expected.normalize_s();
+
+                let mut normalized = original.clone();
+                normalized.conditional_normalize_s();
+
+                assert!(normalized.is_low_s());
+                assert_eq!(normalized.as_ref(), expected.as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn test_eq_bytes() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            assert!(signature.eq_bytes(vector.sig));
+            assert!(!signature.eq_bytes(&vector.sig[..63]));
+
+            let mut tweaked = vector.sig.to_vec();
+            tweaked[63] ^= 1;
+            assert!(!signature.eq_bytes(&tweaked));
+        }
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        let a = FixedSignature::from_bytes(SHA256_FIXED_SIZE_TEST_VECTORS[0].sig).unwrap();
+        let b = FixedSignature::from_bytes(SHA256_FIXED_SIZE_TEST_VECTORS[1].sig).unwrap();
+
+        assert_eq!(
+            FixedSignature::conditional_select(&a, &b, Choice::from(0)),
+            a
+        );
+        assert_eq!(
+            FixedSignature::conditional_select(&a, &b, Choice::from(1)),
+            b
+        );
+
+        let mut c = a.clone();
+        c.conditional_assign(&b, Choice::from(0));
+        assert_eq!(c, a);
+
+        c.conditional_assign(&b, Choice::from(1));
+        assert_eq!(c, b);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_parse_many() {
+        let mut bytes = vec![];
+
+        for vector in &SHA256_FIXED_SIZE_TEST_VECTORS[..3] {
+            bytes.extend_from_slice(vector.sig);
+        }
+
+        let signatures = FixedSignature::parse_many(&bytes).unwrap();
+        assert_eq!(signatures.len(), 3);
+
+        for (signature, vector) in signatures.iter().zip(SHA256_FIXED_SIZE_TEST_VECTORS) {
+            assert_eq!(signature.as_ref(), vector.sig);
+        }
+
+        let err = FixedSignature::parse_many(&bytes[1..]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_length_prefixed() {
+        use ecdsa::LengthPrefix;
+
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+
+        for &(prefix, prefix_bytes) in &[
+            (LengthPrefix::U8, &b"\x40"[..]),
+            (LengthPrefix::U16, &b"\x00\x40"[..]),
+            (LengthPrefix::Varint, &b"\x40"[..]),
+        ] {
+            let mut bytes = signature.to_length_prefixed(prefix).unwrap();
+            assert_eq!(&bytes[..prefix_bytes.len()], prefix_bytes);
+            assert_eq!(&bytes[prefix_bytes.len()..], vector.sig);
+
+            bytes.extend_from_slice(b"trailing");
+            let (parsed, consumed) = FixedSignature::from_length_prefixed(&bytes, prefix).unwrap();
+            assert_eq!(parsed, signature);
+            assert_eq!(consumed, prefix_bytes.len() + 64);
+
+            let truncated = &bytes[..prefix_bytes.len() + 63];
+            let err = FixedSignature::from_length_prefixed(truncated, prefix)
+                .err()
+                .unwrap();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+
+        let err = FixedSignature::from_length_prefixed(b"\x3f", LengthPrefix::U8)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+
+        // 64 as a non-minimal varint
+        let mut bytes = b"\xc0\x00".to_vec();
+        bytes.extend_from_slice(vector.sig);
+        let err = FixedSignature::from_length_prefixed(&bytes, LengthPrefix::Varint)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+    }
+
+    #[test]
+    fn test_from_webcrypto() {
+        use curve::secp256k1;
+
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_webcrypto(vector.sig).unwrap();
+            assert_eq!(signature.as_ref(), vector.sig);
+
+            let asn1_signature = Asn1Signature::from(&signature);
+            let err = FixedSignature::from_webcrypto(asn1_signature.as_ref())
+                .err()
+                .unwrap();
+            assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+        }
+
+        let err = secp256k1::FixedSignature::from_webcrypto(&[0u8; 64])
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_bigint_bytes() {
+        let mut bytes = [0u8; 64];
+        bytes[2] = 0x80;
+        bytes[31] = 0x01;
+        bytes[32] = 0x42;
+
+        let signature = FixedSignature::from_bytes(&bytes[..]).unwrap();
+        assert_eq!(signature.r_bigint_bytes(), &bytes[2..32]);
+        assert_eq!(signature.s_bigint_bytes(), &bytes[32..]);
+
+        let zero = FixedSignature::default();
+        assert!(zero.r_bigint_bytes().is_empty());
+        assert!(zero.s_bigint_bytes().is_empty());
+    }
+
+    #[test]
+    fn test_from_scalars() {
+        let r = [0x81u8; 30];
+        let s = [0x7fu8; 32];
+
+        let signature = FixedSignature::from_scalars(&r, &s).unwrap();
+        assert_eq!(&signature.as_ref()[..2], &[0, 0]);
+        assert_eq!(&signature.as_ref()[2..32], &r[..]);
+        assert_eq!(&signature.as_ref()[32..], &s[..]);
+
+        // Leading zeroes beyond `ScalarSize` are fine, but overlong values aren't
+        let mut padded_s = [0u8; 33];
+        padded_s[1..].copy_from_slice(&s);
+        assert_eq!(
+            FixedSignature::from_scalars(&r, &padded_s).unwrap(),
+            signature
+        );
+        assert!(FixedSignature::from_scalars(&[0xffu8; 33], &s).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_from_decimal_strs() {
+        let signature = FixedSignature::from_decimal_strs("258", "0").unwrap();
+        assert_eq!(&signature.as_ref()[30..32], &[1, 2]);
+        assert!(signature.as_ref()[..30].iter().all(|&byte| byte == 0));
+        assert!(signature.as_ref()[32..].iter().all(|&byte| byte == 0));
+
+        // 2^256 - 1 is the largest value which fits, 2^256 doesn't
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        let overflow =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        let signature = FixedSignature::from_decimal_strs(max, "00042").unwrap();
+        assert!(signature.as_ref()[..32].iter().all(|&byte| byte == 0xff));
+        assert_eq!(signature.as_ref()[63], 42);
+
+        let err = FixedSignature::from_decimal_strs(overflow, "1")
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+
+        for invalid in &["", "-1", "+1", "1.0", "0x10", " 1", "\u{661}"] {
+            let err = FixedSignature::from_decimal_strs("1", invalid)
+                .err()
+                .unwrap();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+
+    #[test]
+    fn test_le_scalars() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let le_bytes = signature.to_le_scalars();
+
+            let mut expected = vector.sig.to_vec();
+            expected[..32].reverse();
+            expected[32..].reverse();
+            assert_eq!(le_bytes, expected);
+
+            let decoded = FixedSignature::from_le_scalars(&le_bytes).unwrap();
+            assert_eq!(decoded, signature);
+        }
+    }
+
+    #[test]
+    fn test_bit_length() {
+        let mut bytes = [0u8; 64];
+        bytes[2] = 0x80;
+        bytes[63] = 0x01;
+
+        let signature = FixedSignature::from_bytes(&bytes[..]).unwrap();
+        assert_eq!(signature.r_bit_length(), 240);
+        assert_eq!(signature.s_bit_length(), 1);
+
+        let signature = FixedSignature::from_bytes(&[0xffu8; 64][..]).unwrap();
+        assert_eq!(signature.r_bit_length(), 256);
+        assert_eq!(signature.s_bit_length(), 256);
+
+        let zero = FixedSignature::default();
+        assert_eq!(zero.r_bit_length(), 0);
+        assert_eq!(zero.s_bit_length(), 0);
+    }
+
+    #[cfg(all(feature = "digest", feature = "sha2"))]
+    #[test]
+    fn test_fingerprint_ignores_malleability() {
+        use curve::{NistP256, WeierstrassCurve};
+        use util::be_sub;
+
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+
+            // Compute the malleated signature (r, n - s)
+            let mut malleated_bytes = [0u8; 64];
+            malleated_bytes[..32].copy_from_slice(&vector.sig[..32]);
+            be_sub(
+                NistP256::ORDER,
+                &vector.sig[32..],
+                &mut malleated_bytes[32..],
+            );
+            let malleated = FixedSignature::from_bytes(&malleated_bytes[..]).unwrap();
+
+            assert_ne!(signature.is_low_s(), malleated.is_low_s());
+            assert_eq!(signature.fingerprint(), malleated.fingerprint());
+        }
+    }
+
+    #[cfg(all(feature = "digest", feature = "sha2"))]
+    #[test]
+    fn test_fingerprint_out_of_range() {
+        use curve::{NistP256, WeierstrassCurve};
+        use digest::Digest;
+        use sha2::Sha256;
+
+        let mut order = [0u8; 64];
+        order[32..].copy_from_slice(NistP256::ORDER);
+
+        for bytes in &[[0xff; 64], order] {
+            let signature = FixedSignature::from_bytes(&bytes[..]).unwrap();
+            assert_eq!(signature.fingerprint(), Sha256::digest(&bytes[..])[..8]);
+        }
+    }
+
+    #[test]
+    fn test_from_provider_bytes_fixed() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_provider_bytes(vector.sig).unwrap();
+            assert_eq!(signature.as_ref(), vector.sig);
+        }
+    }
+
+    #[test]
+    fn test_from_provider_bytes_asn1() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let fixed_signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let asn1_signature = Asn1Signature::from(&fixed_signature);
+
+            let signature = FixedSignature::from_provider_bytes(asn1_signature.as_ref()).unwrap();
+            assert_eq!(signature, fixed_signature);
+        }
+    }
+
+    #[test]
+    fn test_from_provider_bytes_ambiguous_length() {
+        // 29-byte `r` and `s` values encode as exactly 64 bytes of DER
+        let mut bytes = [0u8; 64];
+        bytes[3] = 0x42;
+        bytes[35] = 0x42;
+
+        let asn1_signature = Asn1Signature::from(&FixedSignature::from_bytes(&bytes[..]).unwrap());
+        assert_eq!(asn1_signature.as_ref().len(), 64);
+
+        // Inputs of the fixed signature size are always treated as fixed
+        let signature = FixedSignature::from_provider_bytes(asn1_signature.as_ref()).unwrap();
+        assert_eq!(signature.as_ref(), asn1_signature.as_ref());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_debug() {
+        use prelude::*;
+
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+
+        assert_eq!(
+            format!("{:#?}", signature),
+            concat!(
+                "FixedSignature(NistP256, \"",
+                "f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac",
+                "8bf77819ca05a6b2786c76262bf7371cef97b218e96f175a3ccdda2acc058903",
+                "\")"
+            )
+        );
+
+        let debug = format!("{:?}", signature);
+        assert!(debug.starts_with("signatory::ecdsa::FixedSignature<NistP256>(f3:ac:80:"));
+
+        assert_eq!((&signature).into_iter().count(), 64);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut signature =
+            FixedSignature::from_bytes(&SHA256_FIXED_SIZE_TEST_VECTORS[0].sig).unwrap();
+
+        signature.zeroize();
+        assert!(signature.as_ref().iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn test_into_bytes() {
+        // Returns a copy of the bytes, not the zeroized originals
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            assert_eq!(signature.into_bytes().as_slice(), vector.sig);
+        }
+    }
+
+    #[test]
+    fn test_from_provider_bytes_invalid() {
+        for bytes in &[&b""[..], &b"\x02\x01\x01"[..], &[0x42; 63][..]] {
+            let err = FixedSignature::from_provider_bytes(bytes).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+}
@@ -6,9 +6,214 @@ pub(crate) mod asn1;
 /// Fixed sized signatures
 pub(crate) mod fixed;
 
+/// Fixed sized signatures in "low S" normalized form
+pub(crate) mod normalized;
+
+/// Fixed sized signatures with a recovery ID
+pub(crate) mod recoverable;
+
+/// OpenPGP multiprecision integer (MPI) signatures
+#[cfg(feature = "alloc")]
+pub(crate) mod openpgp;
+
 /// Signature `r` and `s` values parsed as `ScalarPair`
 #[cfg(feature = "encoding")]
 pub(crate) mod scalars;
 
+/// SSH wire format signatures
+#[cfg(feature = "alloc")]
+pub(crate) mod ssh;
+
+use error::Error;
+
 /// Marker trait for ECDSA signatures
 pub trait Signature: ::Signature {}
+
+/// Serialization formats for ECDSA signatures, for selecting the format of
+/// a signature at runtime (e.g. from configuration)
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SignatureFormat {
+    /// ASN.1 DER-encoded signature (see `Asn1Signature`)
+    Asn1,
+
+    /// Fixed-sized `r || s` signature (see `FixedSignature`)
+    Fixed,
+}
+
+/// Length prefixes for framing signatures in length-delimited protocols
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum LengthPrefix {
+    /// 1-byte length
+    U8,
+
+    /// 2-byte big endian length
+    U16,
+
+    /// Unsigned LEB128 variable-length integer (as in e.g. Protocol Buffers)
+    Varint,
+}
+
+impl LengthPrefix {
+    /// Maximum length which can be encoded as this kind of prefix (varints
+    /// are limited to 3 bytes, like when decoding them)
+    #[cfg(feature = "alloc")]
+    pub(crate) fn max_length(self) -> usize {
+        match self {
+            LengthPrefix::U8 => 0xff,
+            LengthPrefix::U16 => 0xffff,
+            LengthPrefix::Varint => (1 << 21) - 1,
+        }
+    }
+
+    /// Encode the given length as this kind of prefix, returning the number
+    /// of bytes written to `out` (which must be at least 3 bytes).
+    ///
+    /// Returns `Unsupported` if the length exceeds `max_length`, rather than
+    /// truncating it.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn encode(self, length: usize, out: &mut [u8]) -> Result<usize, Error> {
+        ensure!(
+            length <= self.max_length(),
+            Unsupported,
+            "length {} too large for {:?} length prefix (max {})",
+            length,
+            self,
+            self.max_length()
+        );
+
+        match self {
+            LengthPrefix::U8 => {
+                out[0] = length as u8;
+                Ok(1)
+            }
+            LengthPrefix::U16 => {
+                out[0] = (length >> 8) as u8;
+                out[1] = length as u8;
+                Ok(2)
+            }
+            LengthPrefix::Varint => {
+                let mut remaining = length;
+                let mut i = 0;
+
+                loop {
+                    let byte = (remaining & 0x7f) as u8;
+                    remaining >>= 7;
+
+                    if remaining == 0 {
+                        out[i] = byte;
+                        return Ok(i + 1);
+                    }
+
+                    out[i] = byte | 0x80;
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    /// Decode this kind of prefix from the start of the given bytes,
+    /// returning the length and the size of the prefix in bytes.
+    ///
+    /// Varints must be minimally encoded: a `ParseError` is returned if the
+    /// last byte of a multi-byte varint is zero (e.g. `0x80 0x00` for `0`).
+    pub(crate) fn decode(self, bytes: &[u8]) -> Result<(usize, usize), Error> {
+        match self {
+            LengthPrefix::U8 => {
+                ensure!(!bytes.is_empty(), ParseError, "missing length prefix");
+                Ok((bytes[0] as usize, 1))
+            }
+            LengthPrefix::U16 => {
+                ensure!(bytes.len() >= 2, ParseError, "truncated length prefix");
+                Ok(((bytes[0] as usize) << 8 | bytes[1] as usize, 2))
+            }
+            LengthPrefix::Varint => {
+                let mut length = 0usize;
+
+                // Lengths are at most 21 bits, i.e. 3 varint bytes (see `max_length`)
+                for (i, &byte) in bytes.iter().take(3).enumerate() {
+                    length |= ((byte & 0x7f) as usize) << (7 * i);
+
+                    if byte & 0x80 == 0 {
+                        ensure!(
+                            i == 0 || byte != 0,
+                            ParseError,
+                            "non-minimal varint length prefix"
+                        );
+
+                        return Ok((length, i + 1));
+                    }
+                }
+
+                fail!(ParseError, "truncated or overlong varint length prefix");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LengthPrefix;
+    use error::ErrorKind;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_length_prefix_roundtrip() {
+        for &(prefix, length, encoded) in &[
+            (LengthPrefix::U8, 0xff, &b"\xff"[..]),
+            (LengthPrefix::U16, 0x1234, &b"\x12\x34"[..]),
+            (LengthPrefix::U16, 0xffff, &b"\xff\xff"[..]),
+            (LengthPrefix::Varint, 0, &b"\x00"[..]),
+            (LengthPrefix::Varint, 0x7f, &b"\x7f"[..]),
+            (LengthPrefix::Varint, 0x80, &b"\x80\x01"[..]),
+            (LengthPrefix::Varint, 0x3fff, &b"\xff\x7f"[..]),
+            (LengthPrefix::Varint, 0x4000, &b"\x80\x80\x01"[..]),
+            (LengthPrefix::Varint, 0x1f_ffff, &b"\xff\xff\x7f"[..]),
+        ] {
+            let mut out = [0u8; 3];
+            let len = prefix.encode(length, &mut out).unwrap();
+            assert_eq!(&out[..len], encoded);
+            assert_eq!(prefix.decode(encoded).unwrap(), (length, encoded.len()));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_length_prefix_rejects_lengths_too_large() {
+        for &prefix in &[LengthPrefix::U8, LengthPrefix::U16, LengthPrefix::Varint] {
+            let mut out = [0u8; 3];
+            let err = prefix
+                .encode(prefix.max_length() + 1, &mut out)
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Unsupported);
+        }
+
+        // Lengths over 255 aren't truncated to a single byte
+        let mut out = [0u8; 3];
+        let err = LengthPrefix::U8.encode(0x140, &mut out).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_varint_rejects_non_minimal_encodings() {
+        for encoded in &[
+            &b"\x80\x00"[..],
+            b"\xc0\x00",
+            b"\x80\x80\x00",
+            b"\xff\x80\x00",
+        ] {
+            let err = LengthPrefix::Varint.decode(encoded).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+
+        // A zero varint is only minimal as a single byte
+        assert_eq!(LengthPrefix::Varint.decode(b"\x00").unwrap(), (0, 1));
+    }
+
+    #[test]
+    fn test_varint_rejects_truncated_or_overlong_encodings() {
+        for encoded in &[&b""[..], b"\x80", b"\xff\xff", b"\x80\x80\x80\x01"] {
+            let err = LengthPrefix::Varint.decode(encoded).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+}
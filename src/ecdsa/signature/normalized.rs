@@ -0,0 +1,109 @@
+//! Fixed-sized ECDSA signatures which are guaranteed to be in "low S"
+//! normalized form
+
+use core::convert::TryFrom;
+use core::fmt::{self, Debug};
+use core::ops::Deref;
+use generic_array::typenum::Unsigned;
+
+use super::fixed::FixedSignature;
+use curve::WeierstrassCurve;
+use error::Error;
+use util::{ct_scalar_less_than, fmt_colon_delimited_hex};
+
+/// Fixed-sized ECDSA signatures which have been normalized to "low S" form,
+/// i.e. their canonical form which is immune to malleability.
+///
+/// These can only be constructed by normalizing a `FixedSignature`, so
+/// functions which require canonical signatures can take a
+/// `NormalizedSignature` to ensure their callers have done so.
+#[derive(Clone)]
+pub struct NormalizedSignature<C: WeierstrassCurve>(FixedSignature<C>);
+
+impl<C> NormalizedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Obtain the inner `FixedSignature` (which is in "low S" form)
+    pub fn into_fixed_signature(self) -> FixedSignature<C> {
+        self.0
+    }
+}
+
+impl<C> TryFrom<FixedSignature<C>> for NormalizedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    type Error = Error;
+
+    /// Normalize the given signature to "low S" form.
+    ///
+    /// Returns `ErrorKind::SignatureInvalid` if `s` is zero or out of range
+    /// (i.e. `s >= n`), as such signatures are never valid and have no
+    /// "low S" form.
+    fn try_from(mut signature: FixedSignature<C>) -> Result<Self, Error> {
+        let s = &signature.as_ref()[C::ScalarSize::to_usize()..];
+
+        ensure!(
+            s.iter().any(|&byte| byte != 0),
+            SignatureInvalid,
+            "'s' is zero"
+        );
+
+        ensure!(
+            bool::from(ct_scalar_less_than(s, C::ORDER)),
+            SignatureInvalid,
+            "'s' is out of range for {:?}",
+            C::CURVE_KIND
+        );
+
+        signature.normalize_s();
+        Ok(NormalizedSignature(signature))
+    }
+}
+
+impl<C> Deref for NormalizedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    type Target = FixedSignature<C>;
+
+    fn deref(&self) -> &FixedSignature<C> {
+        &self.0
+    }
+}
+
+impl<C> AsRef<[u8]> for NormalizedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<C> PartialEq for NormalizedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<C: WeierstrassCurve> Eq for NormalizedSignature<C> {}
+
+impl<C> Debug for NormalizedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "signatory::ecdsa::NormalizedSignature<{:?}>(",
+            C::default()
+        )?;
+        fmt_colon_delimited_hex(f, self.as_ref())?;
+        write!(f, ")")
+    }
+}
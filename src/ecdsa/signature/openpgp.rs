@@ -0,0 +1,137 @@
+//! Signatures in the OpenPGP format described in RFC 4880 section 5.2.2:
+//! `r` and `s` as consecutive multiprecision integers (MPIs), each a 2-byte
+//! big endian bit count followed by the big endian integer itself.
+//!
+//! <https://tools.ietf.org/html/rfc4880#section-3.2>
+//! <https://tools.ietf.org/html/rfc6637#section-6>
+
+use super::fixed::{bit_length, FixedSignature};
+use curve::WeierstrassCurve;
+use error::Error;
+use prelude::*;
+
+impl<C> FixedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Serialize this signature as two OpenPGP MPIs (`r` then `s`), as found
+    /// in the algorithm-specific fields of an ECDSA signature packet.
+    pub fn to_openpgp_mpis(&self) -> Vec<u8> {
+        let mut result = vec![];
+        write_mpi(&mut result, self.r_bit_length(), self.r_bigint_bytes());
+        write_mpi(&mut result, self.s_bit_length(), self.s_bigint_bytes());
+        result
+    }
+
+    /// Parse a signature serialized as two OpenPGP MPIs (see
+    /// `to_openpgp_mpis`).
+    ///
+    /// Each MPI's bit count must be exact (i.e. no leading zero bits), and
+    /// there can't be any trailing data: anything else is a `ParseError`.
+    /// Values which don't fit in `C::ScalarSize` bytes are `SignatureInvalid`.
+    pub fn from_openpgp_mpis(bytes: &[u8]) -> Result<Self, Error> {
+        let (r, rest) = read_mpi(bytes)?;
+        let (s, rest) = read_mpi(rest)?;
+
+        ensure!(
+            rest.is_empty(),
+            ParseError,
+            "trailing data after OpenPGP MPIs"
+        );
+
+        Self::from_scalars(r, s)
+    }
+}
+
+/// Write an OpenPGP MPI: the 2-byte big endian bit count of the given
+/// minimal big endian integer, followed by the integer itself
+fn write_mpi(out: &mut Vec<u8>, bit_length: usize, integer: &[u8]) {
+    debug_assert!(bit_length <= 0xffff);
+    out.extend_from_slice(&[(bit_length >> 8) as u8, bit_length as u8]);
+    out.extend_from_slice(integer);
+}
+
+/// Read an OpenPGP MPI, returning its big endian value and the remaining bytes
+fn read_mpi(bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    ensure!(bytes.len() >= 2, ParseError, "truncated OpenPGP MPI length");
+
+    let declared_bits = (bytes[0] as usize) << 8 | bytes[1] as usize;
+    let len = (declared_bits + 7) / 8;
+
+    ensure!(
+        bytes.len() - 2 >= len,
+        ParseError,
+        "truncated OpenPGP MPI (expected {} bytes, got {})",
+        len,
+        bytes.len() - 2
+    );
+
+    let (integer, rest) = bytes[2..].split_at(len);
+
+    ensure!(
+        bit_length(integer) == declared_bits,
+        ParseError,
+        "OpenPGP MPI bit count mismatch (declared {}, got {})",
+        declared_bits,
+        bit_length(integer)
+    );
+
+    Ok((integer, rest))
+}
+
+#[cfg(all(test, feature = "test-vectors"))]
+mod tests {
+    use curve::nistp256::{FixedSignature, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use error::ErrorKind;
+    use Signature;
+
+    #[test]
+    fn test_openpgp_mpis_roundtrip() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+            let mpis = signature.to_openpgp_mpis();
+
+            assert_eq!(FixedSignature::from_openpgp_mpis(&mpis).unwrap(), signature);
+        }
+    }
+
+    #[test]
+    fn test_to_openpgp_mpis() {
+        // `r` has its high bit set, and `s` has leading zeroes
+        let mut bytes = [0u8; 64];
+        bytes[0] = 0x80;
+        bytes[63] = 0x05;
+
+        let mpis = FixedSignature::from_bytes(&bytes[..])
+            .unwrap()
+            .to_openpgp_mpis();
+
+        let mut expected = b"\x01\x00".to_vec();
+        expected.extend_from_slice(&bytes[..32]);
+        expected.extend_from_slice(b"\x00\x03\x05");
+        assert_eq!(mpis, expected);
+    }
+
+    #[test]
+    fn test_from_openpgp_mpis_invalid() {
+        let signature = FixedSignature::from_bytes(SHA256_FIXED_SIZE_TEST_VECTORS[0].sig).unwrap();
+        let mpis = signature.to_openpgp_mpis();
+
+        // Truncated, trailing data, and non-exact bit count
+        for bad in &[
+            mpis[..mpis.len() - 1].to_vec(),
+            [&mpis[..], b"\x00"].concat(),
+            b"\x00\x01\x01\x00\x04\x05".to_vec(),
+        ] {
+            let err = FixedSignature::from_openpgp_mpis(bad).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+
+        // `r` is larger than the scalar size
+        let mut too_large = b"\x01\x01\x01".to_vec();
+        too_large.extend_from_slice(&[0u8; 32]);
+        too_large.extend_from_slice(b"\x00\x01\x01");
+        let err = FixedSignature::from_openpgp_mpis(&too_large).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+}
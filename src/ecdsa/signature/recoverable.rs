@@ -0,0 +1,182 @@
+//! Recoverable ECDSA signatures: a fixed-size `r || s` signature followed by
+//! a 1-byte recovery ID (a.k.a. `v`) which allows the signer's public key to
+//! be recovered from the signature and message (as used in e.g. Ethereum)
+
+use core::fmt::{self, Debug};
+use core::marker::PhantomData;
+#[cfg(feature = "digest")]
+use digest::Digest;
+use generic_array::{typenum::Unsigned, GenericArray};
+
+use super::fixed::FixedSignature;
+use curve::RecoverableCurve;
+use ecdsa;
+#[cfg(feature = "digest")]
+use ecdsa::PublicKey;
+use error::Error;
+use util::fmt_colon_delimited_hex;
+#[cfg(feature = "digest")]
+use verifier::DigestVerifier;
+
+/// Maximum value of a recovery ID
+pub const MAX_RECOVERY_ID: u8 = 3;
+
+/// ECDSA signatures with a trailing recovery ID
+#[derive(Clone, PartialEq, Eq)]
+pub struct RecoverableSignature<C: RecoverableCurve> {
+    /// Signature data as bytes (`r || s || v`)
+    bytes: GenericArray<u8, C::RecoverableSignatureSize>,
+
+    /// Placeholder for elliptic curve type
+    curve: PhantomData<C>,
+}
+
+impl<C> ::Signature for RecoverableSignature<C>
+where
+    C: RecoverableCurve,
+{
+    /// Create a recoverable ECDSA signature from its serialized byte
+    /// representation (`r || s || v`)
+    fn from_bytes<B: AsRef<[u8]>>(bytes: B) -> Result<Self, Error> {
+        ensure!(
+            bytes.as_ref().len() == C::RecoverableSignatureSize::to_usize(),
+            SignatureInvalid,
+            "expected {}-byte signature (got {})",
+            C::RecoverableSignatureSize::to_usize(),
+            bytes.as_ref().len()
+        );
+
+        let recovery_id = bytes.as_ref()[C::FixedSignatureSize::to_usize()];
+
+        ensure!(
+            recovery_id <= MAX_RECOVERY_ID,
+            SignatureInvalid,
+            "invalid recovery ID: {}",
+            recovery_id
+        );
+
+        Ok(Self {
+            bytes: GenericArray::clone_from_slice(bytes.as_ref()),
+            curve: PhantomData,
+        })
+    }
+}
+
+impl<C: RecoverableCurve> ecdsa::Signature for RecoverableSignature<C> {}
+
+impl<C> RecoverableSignature<C>
+where
+    C: RecoverableCurve,
+{
+    /// Create a recoverable signature from a fixed-sized signature and
+    /// a recovery ID
+    pub fn new(signature: &FixedSignature<C>, recovery_id: u8) -> Result<Self, Error> {
+        ensure!(
+            recovery_id <= MAX_RECOVERY_ID,
+            SignatureInvalid,
+            "invalid recovery ID: {}",
+            recovery_id
+        );
+
+        let fixed_size = C::FixedSignatureSize::to_usize();
+        let mut bytes = GenericArray::default();
+        bytes.as_mut_slice()[..fixed_size].copy_from_slice(signature.as_ref());
+        bytes.as_mut_slice()[fixed_size] = recovery_id;
+
+        Ok(Self {
+            bytes,
+            curve: PhantomData,
+        })
+    }
+
+    /// Get the recovery ID (`v`) for this signature
+    pub fn recovery_id(&self) -> u8 {
+        self.bytes[C::FixedSignatureSize::to_usize()]
+    }
+
+    /// Obtain the fixed-sized signature (`r || s`), sans recovery ID
+    pub fn to_fixed_signature(&self) -> FixedSignature<C> {
+        FixedSignature::from(GenericArray::clone_from_slice(
+            &self.bytes[..C::FixedSignatureSize::to_usize()],
+        ))
+    }
+
+    /// Convert signature into owned byte array
+    #[inline]
+    pub fn into_bytes(self) -> GenericArray<u8, C::RecoverableSignatureSize> {
+        self.bytes
+    }
+
+    /// Recover the public key from this signature of the given prehashed
+    /// `Digest`, and verify the signature against it, using the provider's
+    /// type `R`, e.g.:
+    ///
+    /// `signature.verify_and_recover::<EcdsaVerifier, _>(digest)`
+    ///
+    /// This does NOT authenticate the signer: a well-formed signature
+    /// recovers *some* public key for any digest, and always verifies under
+    /// it. Callers must compare the returned public key against a trusted one
+    /// (e.g. an expected address) before treating the signature as valid.
+    ///
+    /// Verifying under the recovered key only guards against providers whose
+    /// recovery is inconsistent with their verification, in which case this
+    /// returns `ErrorKind::SignatureInvalid`.
+    #[cfg(feature = "digest")]
+    pub fn verify_and_recover<R, D>(&self, digest: D) -> Result<PublicKey<C>, Error>
+    where
+        R: DigestRecoverer<D, C>
+            + DigestVerifier<D, FixedSignature<C>>
+            + for<'a> From<&'a PublicKey<C>>,
+        D: Digest + Clone,
+    {
+        let public_key = R::recover(digest.clone(), self)?;
+
+        R::from(&public_key)
+            .verify(digest, &self.to_fixed_signature())
+            .map_err(|_| {
+                err!(
+                    SignatureInvalid,
+                    "signature doesn't verify under recovered public key"
+                )
+            })?;
+
+        Ok(public_key)
+    }
+}
+
+/// Trait for providers which can recover the signer's public key from a
+/// recoverable signature of a prehashed `Digest`.
+/// The `digest` cargo feature must be enabled for this to be available.
+#[cfg(feature = "digest")]
+pub trait DigestRecoverer<D, C>
+where
+    D: Digest,
+    C: RecoverableCurve,
+{
+    /// Recover the public key which produced the given signature of `digest`
+    fn recover(digest: D, signature: &RecoverableSignature<C>) -> Result<PublicKey<C>, Error>;
+}
+
+impl<C> AsRef<[u8]> for RecoverableSignature<C>
+where
+    C: RecoverableCurve,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+impl<C> Debug for RecoverableSignature<C>
+where
+    C: RecoverableCurve,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "signatory::ecdsa::RecoverableSignature<{:?}>(",
+            C::default()
+        )?;
+        fmt_colon_delimited_hex(f, self.as_ref())?;
+        write!(f, ")")
+    }
+}
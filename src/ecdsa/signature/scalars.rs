@@ -16,6 +16,25 @@ use encoding::asn1;
 use error::Error;
 use signature::Signature;
 
+/// Details of how an ASN.1 DER signature was encoded, recorded while parsing
+/// it, e.g. for strictness checkers to flag non-minimal encodings without
+/// parsing the signature again.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ParseStats {
+    /// Number of leading zero bytes stripped from the `r` INTEGER. One is
+    /// required (for the sign) when the next byte has its high bit set: any
+    /// others mean `r` wasn't minimally encoded.
+    pub r_leading_zeros: usize,
+
+    /// Number of leading zero bytes stripped from the `s` INTEGER, with the
+    /// same caveat as `r_leading_zeros`
+    pub s_leading_zeros: usize,
+
+    /// Was the `SEQUENCE` length in long form (e.g. `0x81 <len>`)? This is
+    /// only required for lengths of 128 bytes or more.
+    pub used_long_form_length: bool,
+}
+
 /// ECDSA signature `r` and `s` values, represented as slices which are at
 /// most `C::ScalarSize` bytes (but *may* be smaller)
 pub(crate) struct ScalarPair<'a, C: WeierstrassCurve> {
@@ -36,61 +55,98 @@ where
     /// Parse the given ASN.1 DER-encoded ECDSA signature, obtaining the
     /// `r` and `s` scalar pair
     pub(crate) fn from_asn1_signature(signature: &'a Asn1Signature<C>) -> Result<Self, Error> {
-        // Signature format is a SEQUENCE of two INTEGER values. We
-        // support only integers of less than 127 bytes each (signed
-        // encoding) so the resulting raw signature will have length
-        // at most 254 bytes.
-        let mut bytes = signature.as_slice();
+        Self::parse_asn1(signature.as_slice(), false).map(|(pair, _)| pair)
+    }
+
+    /// Parse the given ASN.1 DER-encoded ECDSA signature like
+    /// `from_asn1_signature`, also returning `ParseStats` describing how it
+    /// was encoded
+    pub(crate) fn from_asn1_signature_with_stats(
+        signature: &'a Asn1Signature<C>,
+    ) -> Result<(Self, ParseStats), Error> {
+        Self::parse_asn1(signature.as_slice(), false)
+    }
+
+    /// Parse the given ASN.1 DER-encoded ECDSA signature, obtaining the
+    /// `r` and `s` scalar pair, and additionally rejecting signatures which
+    /// aren't strictly valid DER:
+    ///
+    /// - the `SEQUENCE` length must be minimally encoded (i.e. the long form
+    ///   `0x81 <len>` is only permitted for lengths of 128 bytes or more)
+    /// - the declared length of `s` must exactly fill the remainder of the
+    ///   `SEQUENCE` after `r` (checked before `s` is parsed)
+    /// - both INTEGERs must be non-empty, non-negative, and minimally encoded
+    ///   (i.e. no leading zero byte unless the next byte's high bit is set)
+    pub(crate) fn from_asn1_signature_strict(
+        signature: &'a Asn1Signature<C>,
+    ) -> Result<Self, Error> {
+        Self::parse_asn1(signature.as_slice(), true).map(|(pair, _)| pair)
+    }
+
+    /// Parse the `r` and `s` scalar pair from bytes which may not be an
+    /// `Asn1Signature` (yet), with the same checks as `from_asn1_signature`
+    pub(crate) fn from_asn1_bytes(bytes: &'a [u8]) -> Result<Self, Error> {
+        Self::parse_asn1(bytes, false).map(|(pair, _)| pair)
+    }
+
+    /// Parse ASN.1 DER-encoded ECDSA signature, optionally in strict mode
+    fn parse_asn1(mut bytes: &'a [u8], strict: bool) -> Result<(Self, ParseStats), Error> {
+        // Signature format is a SEQUENCE of two INTEGER values. Lengths are
+        // checked rather than unwrapped, so malicious length fields are a
+        // `ParseError` (and never a panic). Outside of strict mode, a long
+        // form `SEQUENCE` length is accepted where the short form would do,
+        // but only as `0x81 <len>`: no ECDSA signature needs a two-byte
+        // length, so accepting `0x82` would only add malleable encodings.
+        let (zlen, header_len) = asn1::parse_header(asn1::Tag::Sequence, bytes, strict)?;
 
-        // First byte is SEQUENCE tag.
         ensure!(
-            bytes[0] == asn1::Tag::Sequence as u8,
+            header_len <= 3,
             ParseError,
-            "ASN.1 error: expected first byte to be a SEQUENCE tag: {}",
-            bytes[0]
+            "ASN.1 error: SEQUENCE length must be encoded in at most one byte"
         );
+        let content_len = bytes.len() - header_len;
 
-        // The SEQUENCE length will be encoded over one or two bytes. We
-        // limit the total SEQUENCE contents to 255 bytes, because it
-        // makes things simpler; this is enough for subgroup orders up
-        // to 999 bits.
-        let mut zlen = bytes[1] as usize;
+        ensure!(
+            zlen == content_len,
+            ParseError,
+            "ASN.1 error: sequence length mismatch ({} vs {})",
+            zlen,
+            content_len
+        );
 
-        if zlen > 0x80 {
-            ensure!(
-                zlen == 0x81,
-                ParseError,
-                "ASN.1 error: overlength signature: {}",
-                zlen
-            );
+        bytes = &bytes[header_len..];
 
-            zlen = bytes[2] as usize;
-            ensure!(
-                zlen == bytes.len().checked_sub(3).unwrap(),
-                ParseError,
-                "ASN.1 error: sequence length mismatch ({} vs {})",
-                zlen,
-                bytes.len().checked_sub(3).unwrap()
-            );
+        // Some tools emit signatures wrapped in an extra SEQUENCE: call this
+        // case out specifically, as the INTEGER tag error is confusing
+        ensure!(
+            bytes.is_empty() || bytes[0] != asn1::Tag::Sequence as u8,
+            ParseError,
+            "ASN.1 error: nested SEQUENCE found where INTEGER expected \
+             (signature may be double-wrapped)"
+        );
+
+        // First INTEGER (r)
+        let (r_integer, bytes) = asn1::parse_tlv(asn1::Tag::Integer, bytes)?;
+        let mut r = r_integer;
+
+        if strict {
+            let (slen, header_len) = asn1::parse_header(asn1::Tag::Integer, bytes, true)?;
 
-            bytes = &bytes[3..];
-        } else {
             ensure!(
-                zlen == bytes.len().checked_sub(2).unwrap(),
+                slen.checked_add(header_len) == Some(bytes.len()),
                 ParseError,
-                "ASN.1 error: sequence length mismatch ({} vs {})",
-                zlen,
-                bytes.len().checked_sub(2).unwrap()
+                "ASN.1 error: INTEGER lengths don't add up to SEQUENCE length"
             );
-
-            bytes = &bytes[2..];
-        };
-
-        // First INTEGER (r)
-        let (mut r, bytes) = Self::asn1_int_parse(bytes)?;
+        }
 
         // Second INTEGER (s)
-        let (mut s, bytes) = Self::asn1_int_parse(bytes)?;
+        let (s_integer, bytes) = asn1::parse_tlv(asn1::Tag::Integer, bytes)?;
+        let mut s = s_integer;
+
+        if strict {
+            Self::asn1_int_check_strict(r)?;
+            Self::asn1_int_check_strict(s)?;
+        }
 
         ensure!(
             bytes.is_empty(),
@@ -102,7 +158,7 @@ where
 
         if r.len() > scalar_size {
             ensure!(
-                r.len() == scalar_size.checked_add(1).unwrap(),
+                Some(r.len()) == scalar_size.checked_add(1),
                 ParseError,
                 "ASN.1 error: overlong 'r'"
             );
@@ -118,7 +174,7 @@ where
 
         if s.len() > scalar_size {
             ensure!(
-                s.len() == scalar_size.checked_add(1).unwrap(),
+                Some(s.len()) == scalar_size.checked_add(1),
                 ParseError,
                 "ASN.1 error: overlong 's'"
             );
@@ -142,6 +198,45 @@ where
             s = &s[1..];
         }
 
+        let stats = ParseStats {
+            r_leading_zeros: r_integer.len() - r.len(),
+            s_leading_zeros: s_integer.len() - s.len(),
+            used_long_form_length: header_len > 2,
+        };
+
+        Ok((
+            Self {
+                r,
+                s,
+                curve: PhantomData,
+            },
+            stats,
+        ))
+    }
+
+    /// Create a scalar pair from big endian `r` and `s` values, which may be
+    /// either fixed-width or minimal but must be at most `C::ScalarSize` bytes
+    /// (sans leading zeroes)
+    pub(crate) fn from_scalars(mut r: &'a [u8], mut s: &'a [u8]) -> Result<Self, Error> {
+        while !r.is_empty() && r[0] == 0 {
+            r = &r[1..];
+        }
+
+        while !s.is_empty() && s[0] == 0 {
+            s = &s[1..];
+        }
+
+        let scalar_size = C::ScalarSize::to_usize();
+
+        ensure!(
+            r.len() <= scalar_size && s.len() <= scalar_size,
+            SignatureInvalid,
+            "expected 'r' and 's' of at most {} bytes (got {} and {})",
+            scalar_size,
+            r.len(),
+            s.len()
+        );
+
         Ok(Self {
             r,
             s,
@@ -163,10 +258,94 @@ where
 
     /// Serialize this ECDSA signature's `r` and `s` scalar pair as ASN.1 DER
     pub(crate) fn to_asn1_signature(&self) -> Asn1Signature<C> {
+        let result = self.serialize_asn1();
+
+        // Double-check we produced an ASN.1 signature we can parse ourselves
+        #[cfg(all(debug_assertions, not(feature = "no-panic")))]
+        ScalarPair::from_asn1_signature(&result).unwrap();
+
+        result
+    }
+
+    /// Serialize this ECDSA signature's `r` and `s` scalar pair as ASN.1 DER,
+    /// always ensuring the result parses (including in release builds) and
+    /// returning an error rather than panicking if it doesn't
+    pub(crate) fn to_asn1_signature_checked(&self) -> Result<Asn1Signature<C>, Error> {
+        let result = self.serialize_asn1();
+
+        ScalarPair::from_asn1_signature(&result)?;
+
+        Ok(result)
+    }
+
+    /// Serialize the `r` and `s` scalar pair as ASN.1 DER without checking
+    /// the result is well-formed
+    fn serialize_asn1(&self) -> Asn1Signature<C> {
+        let mut bytes = GenericArray::default();
+        let length = self.to_asn1_signature_into(&mut bytes);
+
+        Asn1Signature {
+            bytes,
+            length,
+            curve: PhantomData,
+        }
+    }
+
+    /// Serialize the `r` and `s` scalar pair as ASN.1 DER into the given
+    /// buffer (without checking the result is well-formed), returning the
+    /// length of the DER-encoded signature.
+    ///
+    /// Only the first `length` bytes of the buffer are written: any bytes
+    /// after them are left as-is.
+    pub(crate) fn to_asn1_signature_into(
+        &self,
+        bytes: &mut GenericArray<u8, C::Asn1SignatureMaxSize>,
+    ) -> usize {
+        let rlen = Self::asn1_int_length(self.r);
+        let slen = Self::asn1_int_length(self.s);
+        self.serialize_asn1_into(bytes, rlen, slen)
+    }
+
+    /// Compute the length of this signature's (minimal) ASN.1 DER encoding
+    /// without serializing it, i.e. the `SEQUENCE` header plus the `r` and
+    /// `s` INTEGERs (with a long-form length for contents of 128+ bytes)
+    pub(crate) fn asn1_encoded_len(&self) -> usize {
         let rlen = Self::asn1_int_length(self.r);
         let slen = Self::asn1_int_length(self.s);
+        let zlen = rlen.checked_add(slen).unwrap().checked_add(4).unwrap();
+        let header_len = if zlen >= 0x80 { 3 } else { 2 };
+
+        zlen.checked_add(header_len).unwrap()
+    }
+
+    /// Serialize this ECDSA signature's `r` and `s` scalar pair as valid but
+    /// *non-minimal* ASN.1 DER, with both scalars always padded to
+    /// `C::ScalarSize` bytes plus a leading zero (sign) byte.
+    ///
+    /// This encoding is NOT canonical, and is rejected by strict parsers. It's
+    /// only intended for testing how other parsers handle sign-padding.
+    #[cfg(feature = "testing")]
+    pub(crate) fn to_asn1_signature_padded(&self) -> Asn1Signature<C> {
+        let len = C::ScalarSize::to_usize().checked_add(1).unwrap();
         let mut bytes = GenericArray::default();
+        let length = self.serialize_asn1_into(&mut bytes, len, len);
+
+        Asn1Signature {
+            bytes,
+            length,
+            curve: PhantomData,
+        }
+    }
 
+    /// Serialize the `r` and `s` scalar pair as ASN.1 DER INTEGERs of the
+    /// given encoded lengths into the given buffer, returning the length of
+    /// the DER-encoded signature
+    fn serialize_asn1_into(
+        &self,
+        bytes: &mut GenericArray<u8, C::Asn1SignatureMaxSize>,
+        rlen: usize,
+        slen: usize,
+    ) -> usize {
         // SEQUENCE header
         bytes[0] = asn1::Tag::Sequence as u8;
         let zlen = rlen.checked_add(slen).unwrap().checked_add(4).unwrap();
@@ -187,30 +366,30 @@ where
         // Second INTEGER (s)
         Self::asn1_int_serialize(self.s, &mut bytes[offset..], slen);
 
-        let result = Asn1Signature {
-            bytes,
-            length: offset.checked_add(2).unwrap().checked_add(slen).unwrap(),
-            curve: PhantomData,
-        };
-
-        // Double-check we produced an ASN.1 signature we can parse ourselves
-        #[cfg(debug_assertions)]
-        Self::from_asn1_signature(&result).unwrap();
-
-        result
+        offset.checked_add(2).unwrap().checked_add(slen).unwrap()
     }
 
-    pub(crate) fn to_fixed_signature(&self) -> FixedSignature<C> {
+    /// Serialize this ECDSA signature's `r` and `s` scalar pair as a
+    /// fixed-width signature.
+    ///
+    /// Returns a `ProviderError` if either scalar is larger than
+    /// `C::ScalarSize` (which `from_asn1_signature` should always prevent).
+    pub(crate) fn to_fixed_signature(&self) -> Result<FixedSignature<C>, Error> {
         let mut bytes = GenericArray::default();
 
         let scalar_size = C::ScalarSize::to_usize();
-        let rbegin = scalar_size.checked_sub(self.r.len()).unwrap();
+        let rbegin = scalar_size
+            .checked_sub(self.r.len())
+            .ok_or_else(|| err!(ProviderError, "overlong 'r' ({} bytes)", self.r.len()))?;
         bytes.as_mut_slice()[rbegin..scalar_size].copy_from_slice(self.r);
 
-        let sbegin = bytes.len().checked_sub(self.s.len()).unwrap();
+        let sbegin = scalar_size
+            .checked_sub(self.s.len())
+            .and_then(|len| len.checked_add(scalar_size))
+            .ok_or_else(|| err!(ProviderError, "overlong 's' ({} bytes)", self.s.len()))?;
         bytes.as_mut_slice()[sbegin..].copy_from_slice(self.s);
 
-        FixedSignature::from(bytes)
+        Ok(FixedSignature::from(bytes))
     }
 
     /// Compute ASN.1 DER encoded length for the provided scalar. The ASN.1
@@ -229,47 +408,319 @@ where
         }
     }
 
-    /// Parse an integer from its ASN.1 DER serialization
-    fn asn1_int_parse(bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    /// Ensure a parsed INTEGER is non-empty, non-negative, and minimally
+    /// encoded as required by DER
+    fn asn1_int_check_strict(integer: &[u8]) -> Result<(), Error> {
         ensure!(
-            bytes.len() >= 3,
+            !integer.is_empty(),
             ParseError,
-            "ASN.1 error: truncated INTEGER"
+            "ASN.1 error: empty INTEGER"
         );
 
         ensure!(
-            bytes[0] == asn1::Tag::Integer as u8,
+            integer[0] < 0x80,
             ParseError,
-            "ASN.1 error: expected INTEGER tag (0x02) (got 0x{:x})",
-            bytes[0]
+            "ASN.1 error: negative INTEGER"
         );
 
-        let len = bytes[1] as usize;
-
         ensure!(
-            len < 0x80 && len.checked_add(2).unwrap() <= bytes.len(),
+            integer.len() == 1 || integer[0] != 0 || integer[1] >= 0x80,
             ParseError,
-            "ASN.1 error: unexpected length for INTEGER: {}",
-            len
+            "ASN.1 error: non-minimal INTEGER encoding"
         );
 
-        let integer = &bytes[2..len.checked_add(2).unwrap()];
-        let remaining = &bytes[len.checked_add(2).unwrap()..];
-
-        Ok((integer, remaining))
+        Ok(())
     }
 
-    /// Serialize scalar as ASN.1 DER
-    fn asn1_int_serialize(scalar: &[u8], out: &mut [u8], len: usize) {
+    /// Serialize scalar as ASN.1 DER, where `len` is the encoded length of the
+    /// INTEGER as computed by `asn1_int_length`.
+    ///
+    /// The scalar may be either fixed-width or minimal (i.e. with its leading
+    /// zeros already stripped, as is the case for a `ScalarPair` parsed from
+    /// ASN.1 DER), so leading zeros are stripped here before left-padding the
+    /// scalar to `len` (adding the leading zero byte when needed for the sign).
+    fn asn1_int_serialize(mut scalar: &[u8], out: &mut [u8], len: usize) {
+        while !scalar.is_empty() && scalar[0] == 0 {
+            scalar = &scalar[1..];
+        }
+
         out[0] = asn1::Tag::Integer as u8;
         out[1] = len as u8;
 
-        if len > C::ScalarSize::to_usize() {
-            out[2] = 0x00;
-            out[3..C::ScalarSize::to_usize().checked_add(3).unwrap()].copy_from_slice(scalar);
-        } else {
-            out[2..len.checked_add(2).unwrap()]
-                .copy_from_slice(&scalar[C::ScalarSize::to_usize().checked_sub(len).unwrap()..]);
+        let padding = len.checked_sub(scalar.len()).unwrap();
+
+        for byte in &mut out[2..padding.checked_add(2).unwrap()] {
+            *byte = 0x00;
+        }
+
+        out[padding.checked_add(2).unwrap()..len.checked_add(2).unwrap()].copy_from_slice(scalar);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseStats, ScalarPair};
+    use curve::nistp256::{Asn1Signature, FixedSignature};
+    use curve::{NistP256, NistP384, WeierstrassCurve};
+    use ecdsa;
+    use encoding::asn1;
+    use error::ErrorKind;
+    use generic_array::{typenum::Unsigned, GenericArray};
+    use Signature;
+
+    #[test]
+    fn test_asn1_int_serialize_sign_padding() {
+        // `r` has its high bit set, so it needs a leading zero byte
+        let mut bytes = [0x01u8; 64];
+        bytes[0] = 0xff;
+
+        let fixed_signature = FixedSignature::from_bytes(&bytes[..]).unwrap();
+        let asn1_signature = Asn1Signature::from(&fixed_signature);
+        let der = asn1_signature.as_ref();
+
+        assert_eq!(&der[..5], &[0x30, 0x45, 0x02, 0x21, 0x00]);
+        assert_eq!(&der[5..37], &bytes[..32]);
+        assert_eq!(&der[37..39], &[0x02, 0x20]);
+        assert_eq!(&der[39..], &bytes[32..]);
+
+        assert_eq!(FixedSignature::from(&asn1_signature), fixed_signature);
+    }
+
+    #[test]
+    fn test_asn1_int_serialize_max_length() {
+        fn check<C: WeierstrassCurve>() {
+            // Both scalars are exactly `ScalarSize` bytes with their high bits
+            // set, so each INTEGER is `ScalarSize + 1` bytes (the DER maximum)
+            let scalar_size = C::ScalarSize::to_usize();
+            let mut bytes: GenericArray<u8, C::FixedSignatureSize> = GenericArray::default();
+            for (i, byte) in bytes.iter_mut().enumerate() {
+                *byte = 0x80 | i as u8;
+            }
+
+            let fixed_signature = ecdsa::FixedSignature::<C>::from_bytes(&bytes[..]).unwrap();
+            let asn1_signature = ecdsa::Asn1Signature::from(&fixed_signature);
+            let der = asn1_signature.as_ref();
+            let int_len = scalar_size + 1;
+            assert_eq!(der.len(), 2 + 2 * (int_len + 2));
+            assert!(der.len() <= C::Asn1SignatureMaxSize::to_usize());
+
+            let s_offset = der.len() - int_len - 2;
+            assert_eq!(
+                &der[(s_offset - int_len - 2)..][..3],
+                &[0x02, int_len as u8, 0x00]
+            );
+            assert_eq!(
+                &der[(s_offset - int_len + 1)..s_offset],
+                &bytes[..scalar_size]
+            );
+            assert_eq!(&der[s_offset..][..3], &[0x02, int_len as u8, 0x00]);
+            assert_eq!(&der[(s_offset + 3)..], &bytes[scalar_size..]);
+
+            // Parsing strips the 0x00 prefix, leaving `ScalarSize`-byte scalars
+            // which must serialize back to the same (strictly valid) DER
+            let scalars = ScalarPair::from_asn1_signature_strict(&asn1_signature).unwrap();
+            assert_eq!(scalars.r.len(), scalar_size);
+            assert_eq!(scalars.s.len(), scalar_size);
+            assert_eq!(scalars.to_asn1_signature().as_ref(), der);
+            assert_eq!(
+                scalars.to_fixed_signature().unwrap().as_ref(),
+                fixed_signature.as_ref()
+            );
+        }
+
+        check::<NistP256>();
+        check::<NistP384>();
+    }
+
+    #[test]
+    fn test_asn1_int_serialize_minimal_scalars() {
+        // `r` is short with its high bit set, `s` is short without it
+        let mut bytes = [0u8; 64];
+        bytes[2] = 0x80;
+        bytes[3] = 0x01;
+        bytes[35] = 0x7f;
+
+        let fixed_signature = FixedSignature::from_bytes(&bytes[..]).unwrap();
+        let asn1_signature = Asn1Signature::from(&fixed_signature);
+
+        // Reserialize a scalar pair whose scalars have been stripped to minimal length
+        let scalars = ScalarPair::from_asn1_signature(&asn1_signature).unwrap();
+        assert_eq!(scalars.r.len(), 30);
+        assert_eq!(scalars.s.len(), 29);
+        assert_eq!(scalars.to_asn1_signature(), asn1_signature);
+        assert_eq!(scalars.to_fixed_signature().unwrap(), fixed_signature);
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_to_asn1_signature_padded() {
+        let fixed_signature = FixedSignature::from_bytes(&[0x01; 64][..]).unwrap();
+        let scalars = ScalarPair::from_fixed_signature(&fixed_signature);
+
+        let padded = scalars.to_asn1_signature_padded();
+        assert_eq!(padded.as_ref().len(), 72);
+        assert_eq!(&padded.as_ref()[..5], b"\x30\x46\x02\x21\x00");
+        assert_eq!(&padded.as_ref()[37..40], b"\x02\x21\x00");
+
+        // Parses leniently, but not strictly
+        assert_eq!(
+            padded.to_fixed_signature_checked().unwrap(),
+            fixed_signature
+        );
+        assert!(Asn1Signature::from_bytes_strict(padded.as_ref()).is_err());
+        assert!(padded.canonical_eq(&scalars.to_asn1_signature()));
+    }
+
+    #[test]
+    fn test_from_asn1_signature_strict() {
+        let valid = Asn1Signature::from_bytes(b"\x30\x06\x02\x01\x01\x02\x01\x7f").unwrap();
+        assert!(ScalarPair::from_asn1_signature_strict(&valid).is_ok());
+
+        // Malformed signatures which are tolerated by the non-strict parser
+        let tolerated: &[&[u8]] = &[
+            // Non-minimal `r` (superfluous leading zero)
+            b"\x30\x07\x02\x02\x00\x01\x02\x01\x01",
+            // Non-minimal `s` (superfluous leading zero)
+            b"\x30\x07\x02\x01\x01\x02\x02\x00\x7f",
+            // Negative `r`
+            b"\x30\x06\x02\x01\x81\x02\x01\x01",
+            // Empty `r`
+            b"\x30\x05\x02\x00\x02\x01\x01",
+            // Long-form encoding of a short SEQUENCE length
+            b"\x30\x81\x06\x02\x01\x01\x02\x01\x7f",
+        ];
+
+        for bytes in tolerated {
+            let signature = Asn1Signature::from_bytes(bytes).unwrap();
+            assert!(ScalarPair::from_asn1_signature_strict(&signature).is_err());
+            assert!(Asn1Signature::from_bytes_strict(bytes).is_err());
+        }
+
+        // INTEGER lengths which don't add up to the SEQUENCE length
+        let mismatched: &[&[u8]] = &[
+            b"\x30\x07\x02\x01\x01\x02\x01\x01\x00",
+            b"\x30\x07\x02\x01\x01\x02\x03\x01\x01",
+            b"\x30\x03\x02\x01\x01",
+        ];
+
+        for bytes in mismatched {
+            assert!(Asn1Signature::from_bytes_strict(bytes).is_err());
+        }
+
+        // Two-byte SEQUENCE lengths are rejected even by the non-strict parser
+        let two_byte_lengths: &[&[u8]] = &[
+            b"\x30\x82\x00\x06\x02\x01\x01\x02\x01\x7f",
+            b"\x30\x82\x00\x08\x02\x02\x00\x01\x02\x02\x00\x7f",
+        ];
+
+        for bytes in two_byte_lengths {
+            let err = Asn1Signature::from_bytes(bytes).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+            assert!(Asn1Signature::from_bytes_strict(bytes).is_err());
+            assert!(asn1::validate_ecdsa_der::<NistP256>(bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn test_from_asn1_signature_with_stats() {
+        let stats = |bytes: &[u8]| {
+            let signature = Asn1Signature::from_bytes(bytes).unwrap();
+            let (pair, stats) = ScalarPair::from_asn1_signature_with_stats(&signature).unwrap();
+            assert_eq!(
+                pair.r,
+                ScalarPair::from_asn1_signature(&signature).unwrap().r
+            );
+            stats
+        };
+
+        let minimal = stats(b"\x30\x06\x02\x01\x01\x02\x01\x7f");
+        assert_eq!(minimal, ParseStats::default());
+
+        // A leading zero required for the sign is still counted
+        let sign_padded = stats(b"\x30\x07\x02\x02\x00\x80\x02\x01\x01");
+        assert_eq!(sign_padded.r_leading_zeros, 1);
+        assert_eq!(sign_padded.s_leading_zeros, 0);
+
+        let non_minimal = stats(b"\x30\x08\x02\x03\x00\x00\x01\x02\x01\x01");
+        assert_eq!(non_minimal.r_leading_zeros, 2);
+
+        let long_form = stats(b"\x30\x81\x07\x02\x01\x01\x02\x02\x00\x7f");
+        assert_eq!(
+            long_form,
+            ParseStats {
+                r_leading_zeros: 0,
+                s_leading_zeros: 1,
+                used_long_form_length: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_asn1_signature_double_wrapped() {
+        let bytes = b"\x30\x08\x30\x06\x02\x01\x01\x02\x01\x7f";
+        let err = Asn1Signature::from_bytes(&bytes[..]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+
+        #[cfg(feature = "std")]
+        assert!(format!("{}", err).contains("double-wrapped"));
+    }
+
+    #[test]
+    fn test_from_asn1_signature_indefinite_length() {
+        let bytes = b"\x30\x80\x02\x01\x01\x02\x01\x7f\x00\x00";
+        let err = Asn1Signature::from_bytes(&bytes[..]).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+
+        #[cfg(feature = "std")]
+        assert!(format!("{}", err).contains("indefinite-length"));
+    }
+
+    /// Regression tests for malicious length fields: these must all be
+    /// rejected with a `ParseError` rather than panicking
+    #[test]
+    fn test_from_asn1_signature_malicious_lengths() {
+        let malicious: &[&[u8]] = &[
+            b"\x30",
+            b"\x30\x81",
+            b"\x30\x00",
+            b"\x30\x01\x02",
+            b"\x30\x81\x00",
+            b"\x30\x81\xff\x02\x01\x01\x02\x01\x01",
+            b"\x30\xff\x02\x01\x01\x02\x01\x01",
+            b"\x30\x03\x02\x7f\x01",
+            b"\x30\x03\x02\xff\x01",
+            b"\x30\x06\x02\x01\x01\x02\x7f\x01",
+            b"\x30\x05\x02\x01\x01\x02\x01",
+            b"\x30\x04\x02\x01\x01\x02",
+        ];
+
+        for bytes in malicious {
+            let err = Asn1Signature::from_bytes(bytes).err().unwrap();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+    }
+
+    /// Fuzz-style exhaustive single-byte mutations and truncations of a
+    /// valid signature: parsing may fail, but must never panic
+    #[test]
+    fn test_from_asn1_signature_mutations() {
+        let valid = Asn1Signature::from(&FixedSignature::from_bytes(&[0x80; 64][..]).unwrap());
+
+        for len in 0..valid.length {
+            assert!(Asn1Signature::from_bytes(&valid.as_ref()[..len]).is_err());
+        }
+
+        let mut mutated = valid.clone();
+
+        for i in 0..valid.length {
+            for byte in 0..=255u8 {
+                mutated.bytes[i] = byte;
+
+                let _ = ScalarPair::from_asn1_signature(&mutated);
+                let _ = ScalarPair::from_asn1_signature_strict(&mutated);
+            }
+
+            mutated.bytes[i] = valid.bytes[i];
         }
     }
 }
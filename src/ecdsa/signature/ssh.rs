@@ -0,0 +1,238 @@
+//! Signatures in the SSH wire format described in RFC 5656 section 3.1.2:
+//! the algorithm name (e.g. `ecdsa-sha2-nistp256`) followed by a nested blob
+//! containing `r` and `s` as `mpint`s, each as an SSH `string`.
+//!
+//! <https://tools.ietf.org/html/rfc5656#section-3.1.2>
+
+use super::fixed::FixedSignature;
+use curve::WeierstrassCurve;
+use error::Error;
+use prelude::*;
+
+impl<C> FixedSignature<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Serialize this signature in the SSH wire format (as used by SSH
+    /// agents and certificates), i.e. `string algorithm_name` followed by
+    /// `string(mpint r || mpint s)`.
+    ///
+    /// Returns `ErrorKind::Unsupported` for curves SSH doesn't support (see
+    /// `WeierstrassCurveKind::to_ssh_name`), and `SignatureInvalid` if `r` or
+    /// `s` is zero (which `from_ssh_wire` would reject).
+    pub fn to_ssh_wire(&self) -> Result<Vec<u8>, Error> {
+        let name = ssh_name::<C>()?;
+        let (r, s) = (self.r_bigint_bytes(), self.s_bigint_bytes());
+
+        ensure!(
+            !r.is_empty() && !s.is_empty(),
+            SignatureInvalid,
+            "zero scalar in signature"
+        );
+
+        let mut blob = vec![];
+        write_mpint(&mut blob, r);
+        write_mpint(&mut blob, s);
+
+        let mut result = vec![];
+        write_string(&mut result, name.as_bytes());
+        write_string(&mut result, &blob);
+        Ok(result)
+    }
+
+    /// Parse a signature in the SSH wire format (see `to_ssh_wire`).
+    ///
+    /// The algorithm name must be the one for the curve `C`, the `mpint`s must
+    /// be positive and minimally encoded, and there can't be any trailing
+    /// data: anything else is a `ParseError`. Curves SSH doesn't support are
+    /// `ErrorKind::Unsupported`.
+    pub fn from_ssh_wire(bytes: &[u8]) -> Result<Self, Error> {
+        let name = ssh_name::<C>()?;
+
+        let (algorithm, rest) = read_string(bytes)?;
+        ensure!(
+            algorithm == name.as_bytes(),
+            ParseError,
+            "expected SSH signature algorithm {}",
+            name
+        );
+
+        let (blob, rest) = read_string(rest)?;
+        ensure!(
+            rest.is_empty(),
+            ParseError,
+            "trailing data after SSH signature"
+        );
+
+        let (r, blob) = read_mpint(blob)?;
+        let (s, blob) = read_mpint(blob)?;
+        ensure!(
+            blob.is_empty(),
+            ParseError,
+            "trailing data in SSH signature blob"
+        );
+
+        Self::from_scalars(r, s)
+    }
+}
+
+/// Get the SSH algorithm name for the curve `C`
+fn ssh_name<C: WeierstrassCurve>() -> Result<&'static str, Error> {
+    let name = match C::CURVE_KIND.to_ssh_name() {
+        Some(name) => name,
+        None => fail!(Unsupported, "{:?} is not supported by SSH", C::CURVE_KIND),
+    };
+
+    Ok(name)
+}
+
+/// Write an SSH `string`: a 32-bit big endian length followed by the data
+fn write_string(out: &mut Vec<u8>, data: &[u8]) {
+    let len = data.len() as u32;
+    out.extend_from_slice(&[
+        (len >> 24) as u8,
+        (len >> 16) as u8,
+        (len >> 8) as u8,
+        len as u8,
+    ]);
+    out.extend_from_slice(data);
+}
+
+/// Write a positive minimal big endian integer as an SSH `mpint`: a two's
+/// complement `string`, with a leading zero byte if the high bit is set (like
+/// an ASN.1 DER INTEGER)
+fn write_mpint(out: &mut Vec<u8>, integer: &[u8]) {
+    debug_assert!(!integer.is_empty() && integer[0] != 0);
+
+    if integer[0] >= 0x80 {
+        let mut padded = Vec::with_capacity(integer.len() + 1);
+        padded.push(0);
+        padded.extend_from_slice(integer);
+        write_string(out, &padded);
+    } else {
+        write_string(out, integer);
+    }
+}
+
+/// Read an SSH `string`, returning its data and the remaining bytes
+fn read_string(bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    ensure!(bytes.len() >= 4, ParseError, "truncated SSH string length");
+
+    let len = (bytes[0] as usize) << 24
+        | (bytes[1] as usize) << 16
+        | (bytes[2] as usize) << 8
+        | bytes[3] as usize;
+
+    ensure!(
+        bytes.len() - 4 >= len,
+        ParseError,
+        "truncated SSH string (expected {} bytes, got {})",
+        len,
+        bytes.len() - 4
+    );
+
+    Ok(bytes[4..].split_at(len))
+}
+
+/// Read a positive SSH `mpint`, returning its big endian value (sans the
+/// leading zero byte added for the sign, if any) and the remaining bytes
+fn read_mpint(bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    let (integer, rest) = read_string(bytes)?;
+
+    ensure!(!integer.is_empty(), ParseError, "zero SSH mpint");
+    ensure!(integer[0] < 0x80, ParseError, "negative SSH mpint");
+
+    if integer[0] == 0 {
+        ensure!(
+            integer.len() > 1 && integer[1] >= 0x80,
+            ParseError,
+            "non-minimal SSH mpint encoding"
+        );
+
+        Ok((&integer[1..], rest))
+    } else {
+        Ok((integer, rest))
+    }
+}
+
+#[cfg(all(test, feature = "test-vectors"))]
+mod tests {
+    use curve::nistp256::{FixedSignature, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use curve::secp256k1::FixedSignature as Secp256k1FixedSignature;
+    use error::ErrorKind;
+    use Signature;
+
+    #[test]
+    fn test_ssh_wire_roundtrip() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+            let wire = signature.to_ssh_wire().unwrap();
+
+            assert_eq!(&wire[..23], b"\x00\x00\x00\x13ecdsa-sha2-nistp256");
+            assert_eq!(
+                FixedSignature::from_ssh_wire(&wire).unwrap().as_ref(),
+                signature.as_ref()
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_ssh_wire() {
+        // `r` has its high bit set, and `s` has leading zeroes
+        let mut bytes = [0u8; 64];
+        bytes[0] = 0x80;
+        bytes[63] = 0x01;
+
+        let wire = FixedSignature::from_bytes(&bytes[..])
+            .unwrap()
+            .to_ssh_wire()
+            .unwrap();
+
+        let mut expected =
+            b"\x00\x00\x00\x13ecdsa-sha2-nistp256\x00\x00\x00\x2a\x00\x00\x00\x21\x00".to_vec();
+        expected.extend_from_slice(&bytes[..32]);
+        expected.extend_from_slice(b"\x00\x00\x00\x01\x01");
+        assert_eq!(wire, expected);
+    }
+
+    #[test]
+    fn test_from_ssh_wire_invalid() {
+        let signature = FixedSignature::from_bytes(SHA256_FIXED_SIZE_TEST_VECTORS[0].sig).unwrap();
+        let wire = signature.to_ssh_wire().unwrap();
+
+        // Truncated, trailing data, and wrong algorithm
+        for bad in &[
+            wire[..wire.len() - 1].to_vec(),
+            [&wire[..], b"\x00"].concat(),
+            [b"\x00\x00\x00\x13ecdsa-sha2-nistp384", &wire[23..]].concat(),
+        ] {
+            let err = FixedSignature::from_ssh_wire(bad).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ParseError);
+        }
+
+        // Non-minimal `s`
+        let wire = b"\x00\x00\x00\x13ecdsa-sha2-nistp256\x00\x00\x00\x0b\
+                     \x00\x00\x00\x01\x01\x00\x00\x00\x02\x00\x01";
+        let err = FixedSignature::from_ssh_wire(wire).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ParseError);
+
+        let err = Secp256k1FixedSignature::from_ssh_wire(wire).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_to_ssh_wire_zero() {
+        // Zero `r` or `s` can't be encoded as a positive `mpint`
+        for &(r, s) in &[(0u8, 1u8), (1, 0)] {
+            let mut bytes = [0u8; 64];
+            bytes[31] = r;
+            bytes[63] = s;
+
+            let err = FixedSignature::from_bytes(&bytes[..])
+                .unwrap()
+                .to_ssh_wire()
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Mock verifiers for testing generic verification helpers without a provider
+
+#[cfg(feature = "test-signer")]
+use digest::Digest;
+use sha2::Sha256;
+use std::cell::Cell;
+
+#[cfg(feature = "test-signer")]
+use curve::nistp256::SecretKey;
+use curve::nistp256::{FixedSignature, PublicKey, SHA256_FIXED_SIZE_TEST_VECTORS};
+#[cfg(feature = "test-signer")]
+use curve::NistP256;
+#[cfg(feature = "test-signer")]
+use ecdsa::MemorySigner;
+use error::Error;
+use verifier::DigestVerifier;
+#[cfg(feature = "test-signer")]
+use DigestSigner;
+
+thread_local! {
+    /// Number of verifications performed by `TestVerifier` on this thread
+    static VERIFICATIONS: Cell<usize> = Cell::new(0);
+}
+
+/// Verifier which accepts any signature without a zero first byte, but only
+/// for the public key of the first test vector, counting how often it's called
+pub(crate) struct TestVerifier(PublicKey);
+
+impl TestVerifier {
+    /// Number of verifications performed so far on this thread
+    pub(crate) fn verifications() -> usize {
+        VERIFICATIONS.with(|count| count.get())
+    }
+}
+
+impl<'a> From<&'a PublicKey> for TestVerifier {
+    fn from(public_key: &'a PublicKey) -> Self {
+        TestVerifier(public_key.clone())
+    }
+}
+
+impl DigestVerifier<Sha256, FixedSignature> for TestVerifier {
+    fn verify(&self, _digest: Sha256, signature: &FixedSignature) -> Result<(), Error> {
+        VERIFICATIONS.with(|count| count.set(count.get() + 1));
+
+        ensure!(
+            self.0.as_bytes()[1..] == *SHA256_FIXED_SIZE_TEST_VECTORS[0].pk
+                && signature.as_ref()[0] != 0,
+            SignatureInvalid,
+            "bad signature"
+        );
+
+        Ok(())
+    }
+}
+
+/// Verifier for the public keys of the test vectors (compressed or not) which
+/// checks a signature by recomputing it with a `MemorySigner` for the key's
+/// secret key. Signing is deterministic, so unlike `TestVerifier`, this
+/// rejects signatures of the wrong digest or by the wrong key.
+#[cfg(feature = "test-signer")]
+pub(crate) struct ResigningVerifier(Option<MemorySigner<NistP256>>);
+
+#[cfg(feature = "test-signer")]
+impl<'a> From<&'a PublicKey> for ResigningVerifier {
+    fn from(public_key: &'a PublicKey) -> Self {
+        let signer = public_key.decompress().ok().and_then(|public_key| {
+            SHA256_FIXED_SIZE_TEST_VECTORS
+                .iter()
+                .find(|vector| public_key.as_bytes()[1..] == *vector.pk)
+                .map(|vector| MemorySigner::from(&SecretKey::from_bytes(vector.sk).unwrap()))
+        });
+
+        ResigningVerifier(signer)
+    }
+}
+
+#[cfg(feature = "test-signer")]
+impl<D: Digest> DigestVerifier<D, FixedSignature> for ResigningVerifier {
+    fn verify(&self, digest: D, signature: &FixedSignature) -> Result<(), Error> {
+        let signer = self
+            .0
+            .as_ref()
+            .ok_or_else(|| err!(KeyInvalid, "not a test vector public key"))?;
+
+        let expected: FixedSignature = signer.sign(digest)?;
+
+        ensure!(expected == *signature, SignatureInvalid, "bad signature");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+//! Verification against a rotating set of public keys, each of which is only
+//! valid for a certain period of time (e.g. with a grace period for old keys)
+
+use digest::Digest;
+use std::time::SystemTime;
+
+use super::{FixedSignature, PublicKey};
+use curve::WeierstrassCurve;
+use error::Error;
+use prelude::*;
+use verifier::DigestVerifier;
+
+/// Public key which is only valid for a certain period of time
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct TimestampedKey<C: WeierstrassCurve> {
+    /// Public key
+    public_key: PublicKey<C>,
+
+    /// Time this key becomes valid (inclusive)
+    valid_from: SystemTime,
+
+    /// Time this key expires (exclusive)
+    valid_until: SystemTime,
+}
+
+impl<C> TimestampedKey<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Is this key valid at the given time?
+    fn is_valid_at(&self, timestamp: SystemTime) -> bool {
+        self.valid_from <= timestamp && timestamp < self.valid_until
+    }
+}
+
+/// Verifier for a rotating set of public keys, each with a validity period.
+///
+/// Signatures are verified against the keys which were valid at a given
+/// time (e.g. when the signature was made), skipping any keys which had
+/// expired or weren't yet valid. Keys' validity periods may overlap, e.g. so
+/// an old key remains valid for a grace period after its replacement.
+/// Requires the `digest` and `std` cargo features.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimestampedVerifier<C: WeierstrassCurve> {
+    /// Public keys and their validity periods, in the order they were added
+    keys: Vec<TimestampedKey<C>>,
+}
+
+impl<C> TimestampedVerifier<C>
+where
+    C: WeierstrassCurve,
+{
+    /// Create a new verifier with no keys
+    pub fn new() -> Self {
+        Self { keys: vec![] }
+    }
+
+    /// Add a public key which is valid from `valid_from` (inclusive) until
+    /// `valid_until` (exclusive).
+    ///
+    /// Returns `ErrorKind::KeyInvalid` if `valid_until` isn't after
+    /// `valid_from`, as such a key would never be valid.
+    pub fn add_key(
+        &mut self,
+        public_key: PublicKey<C>,
+        valid_from: SystemTime,
+        valid_until: SystemTime,
+    ) -> Result<(), Error> {
+        ensure!(
+            valid_from < valid_until,
+            KeyInvalid,
+            "key validity period ends before it begins"
+        );
+
+        self.keys.push(TimestampedKey {
+            public_key,
+            valid_from,
+            valid_until,
+        });
+
+        Ok(())
+    }
+
+    /// Number of keys (valid or not)
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Does this verifier have no keys?
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Verify a signature of the given digest with the provider's verifier
+    /// type `V`, trying each of the keys valid at `timestamp` in the order
+    /// they were added, and returning the public key which verified it.
+    ///
+    /// Returns `ErrorKind::KeyInvalid` if no keys were valid at `timestamp`,
+    /// or `ErrorKind::SignatureInvalid` if none of them verified the signature.
+    pub fn verify_at<V, D>(
+        &self,
+        timestamp: SystemTime,
+        digest: D,
+        signature: &FixedSignature<C>,
+    ) -> Result<&PublicKey<C>, Error>
+    where
+        V: DigestVerifier<D, FixedSignature<C>> + for<'a> From<&'a PublicKey<C>>,
+        D: Digest + Clone,
+    {
+        let mut found_valid_key = false;
+
+        for key in self.keys.iter().filter(|key| key.is_valid_at(timestamp)) {
+            found_valid_key = true;
+
+            if V::from(&key.public_key)
+                .verify(digest.clone(), signature)
+                .is_ok()
+            {
+                return Ok(&key.public_key);
+            }
+        }
+
+        ensure!(
+            found_valid_key,
+            KeyInvalid,
+            "no keys valid at the given time"
+        );
+
+        fail!(
+            SignatureInvalid,
+            "signature not verified by any key valid at the given time"
+        )
+    }
+}
+
+impl<C> Default for TimestampedVerifier<C>
+where
+    C: WeierstrassCurve,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "sha2", feature = "test-vectors"))]
+mod tests {
+    use sha2::Sha256;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::TimestampedVerifier;
+    use curve::nistp256::{FixedSignature, PublicKey, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use curve::NistP256;
+    use digest::Digest;
+    use ecdsa::test_verifier::TestVerifier;
+    use error::ErrorKind;
+    use generic_array::GenericArray;
+    use Signature;
+
+    #[test]
+    fn test_verify_at() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let new_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+        let old_key = PublicKey::from_untagged_point(GenericArray::from_slice(&[0x42; 64]));
+        let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+        let time = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+
+        // The old key remains valid for a grace period after the new key
+        let mut verifier = TimestampedVerifier::<NistP256>::new();
+        verifier.add_key(old_key, time(0), time(200)).unwrap();
+        verifier
+            .add_key(new_key.clone(), time(100), time(300))
+            .unwrap();
+        assert_eq!(verifier.len(), 2);
+
+        let verify_at =
+            |secs| verifier.verify_at::<TestVerifier, _>(time(secs), Sha256::new(), &signature);
+
+        // Only the old key is valid
+        assert_eq!(
+            verify_at(50).unwrap_err().kind(),
+            ErrorKind::SignatureInvalid
+        );
+
+        // Both keys are valid, and the new key verifies
+        assert_eq!(verify_at(100).unwrap(), &new_key);
+        assert_eq!(verify_at(250).unwrap(), &new_key);
+
+        // Both keys have expired
+        assert_eq!(verify_at(300).unwrap_err().kind(), ErrorKind::KeyInvalid);
+    }
+
+    #[test]
+    fn test_add_key_invalid_period() {
+        let mut verifier = TimestampedVerifier::<NistP256>::new();
+        let key = PublicKey::from_untagged_point(GenericArray::from_slice(&[0x42; 64]));
+        let err = verifier.add_key(key, UNIX_EPOCH, UNIX_EPOCH).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+        assert!(verifier.is_empty());
+    }
+}
@@ -7,7 +7,7 @@ use subtle_encoding::Encoding;
 #[cfg(feature = "encoding")]
 use encoding::Decode;
 #[cfg(all(feature = "alloc", feature = "encoding"))]
-use encoding::Encode;
+use encoding::{encode_vec, Encode};
 use error::Error;
 #[allow(unused_imports)]
 use prelude::*;
@@ -95,7 +95,7 @@ impl Decode for PublicKey {
 impl Encode for PublicKey {
     /// Encode an Ed25519 seed with the given encoding (e.g. hex, Base64)
     fn encode<E: Encoding>(&self, encoding: &E) -> Vec<u8> {
-        encoding.encode(self.as_bytes())
+        encode_vec(encoding, self.as_bytes())
     }
 }
 
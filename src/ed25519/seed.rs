@@ -10,7 +10,7 @@ use zeroize::Zeroize;
 #[cfg(feature = "encoding")]
 use encoding::Decode;
 #[cfg(all(feature = "alloc", feature = "encoding"))]
-use encoding::Encode;
+use encoding::{encode_vec, Encode};
 use error::Error;
 #[allow(unused_imports)]
 use prelude::*;
@@ -129,7 +129,7 @@ impl Decode for Seed {
 impl Encode for Seed {
     /// Encode an Ed25519 seed with the given encoding (e.g. hex, Base64)
     fn encode<E: Encoding>(&self, encoding: &E) -> Vec<u8> {
-        encoding.encode(self.as_secret_slice())
+        encode_vec(encoding, self.as_secret_slice())
     }
 }
 
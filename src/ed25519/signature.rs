@@ -7,7 +7,7 @@ use subtle_encoding::Encoding;
 #[cfg(feature = "encoding")]
 use encoding::Decode;
 #[cfg(all(feature = "alloc", feature = "encoding"))]
-use encoding::Encode;
+use encoding::{encode_vec, Encode};
 use error::Error;
 #[allow(unused_imports)]
 use prelude::*;
@@ -78,7 +78,7 @@ impl Decode for Signature {
 impl Encode for Signature {
     /// Encode an Ed25519 signature with the given encoding (e.g. hex, Base64)
     fn encode<E: Encoding>(&self, encoding: &E) -> Vec<u8> {
-        encoding.encode(self.as_ref())
+        encode_vec(encoding, self.as_ref())
     }
 }
 
@@ -0,0 +1,165 @@
+//! Ed448: Schnorr signatures using the untwisted Edwards form of Curve448
+//! ("Ed448-Goldilocks"), with SHAKE256 as the hash function
+//!
+//! Described in RFC 8032: <https://tools.ietf.org/html/rfc8032>
+//!
+//! Ed448 offers a ~224-bit security level (vs ~128-bit for Ed25519) at the
+//! cost of larger keys and signatures. This module contains types for Ed448
+//! public keys and signatures, along with convenience methods for signing
+//! and verifying Ed448 signatures which work with any signer or verifier.
+
+mod public_key;
+mod signature;
+
+#[cfg(feature = "test-vectors")]
+#[macro_use]
+mod test_macros;
+
+/// RFC 8032 Ed448 test vectors
+#[cfg(feature = "test-vectors")]
+mod test_vectors;
+
+#[cfg(feature = "test-vectors")]
+pub use self::test_vectors::TEST_VECTORS;
+pub use self::{
+    public_key::{PublicKey, PUBLIC_KEY_SIZE},
+    signature::{Signature, SIGNATURE_SIZE},
+};
+use error::Error;
+use public_key::PublicKeyed;
+use signer::Signer;
+use verifier::Verifier;
+
+/// Ed448 signers, i.e. any `Signer` which produces Ed448 signatures, e.g. for
+/// use as a bound in generic code (implemented for all such `Signer`s)
+pub trait Ed448Signer: Signer<Signature> {}
+
+impl<T> Ed448Signer for T where T: Signer<Signature> {}
+
+/// Ed448 verifiers, i.e. any `Verifier` of Ed448 signatures, e.g. for use as a
+/// bound in generic code (implemented for all such `Verifier`s)
+pub trait Ed448Verifier: Verifier<Signature> {}
+
+impl<T> Ed448Verifier for T where T: Verifier<Signature> {}
+
+/// Get the public key for the given public keyed object (i.e. a `Signer`)
+pub fn public_key(keyed: &PublicKeyed<PublicKey>) -> Result<PublicKey, Error> {
+    keyed.public_key()
+}
+
+/// Sign the given message slice with the given Ed448 signer
+#[inline]
+pub fn sign(signer: &Signer<Signature>, msg: &[u8]) -> Result<Signature, Error> {
+    super::sign(signer, msg)
+}
+
+/// Verify the given message slice with the given Ed448 verifier
+#[inline]
+pub fn verify(verifier: &Verifier<Signature>, msg: &[u8], sig: &Signature) -> Result<(), Error> {
+    super::verify(verifier, msg, sig)
+}
+
+#[cfg(all(test, feature = "test-vectors"))]
+mod tests {
+    use super::{PublicKey, Signature, PUBLIC_KEY_SIZE, SIGNATURE_SIZE, TEST_VECTORS};
+    use error::{Error, ErrorKind};
+    use signer::Signer;
+    use test_vector::TestVector;
+    use verifier::Verifier;
+    use Signature as SignatureTrait;
+
+    /// Signer which only knows the RFC 8032 test vectors for its secret key
+    struct TestSigner(Option<&'static TestVector>);
+
+    impl<'a> From<&'a [u8]> for TestSigner {
+        fn from(secret_key: &'a [u8]) -> Self {
+            TestSigner(TEST_VECTORS.iter().find(|vector| vector.sk == secret_key))
+        }
+    }
+
+    impl Signer<Signature> for TestSigner {
+        fn sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+            match self.0 {
+                Some(vector) if vector.msg == msg => Signature::from_bytes(vector.sig),
+                _ => fail!(ProviderError, "unknown secret key or message"),
+            }
+        }
+    }
+
+    /// Verifier which only accepts the RFC 8032 test vectors for its public key
+    struct TestVerifier(PublicKey);
+
+    impl<'a> From<&'a PublicKey> for TestVerifier {
+        fn from(public_key: &'a PublicKey) -> Self {
+            TestVerifier(*public_key)
+        }
+    }
+
+    impl Verifier<Signature> for TestVerifier {
+        fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+            ensure!(
+                TEST_VECTORS
+                    .iter()
+                    .any(|vector| vector.pk == self.0.as_ref()
+                        && vector.msg == msg
+                        && vector.sig == signature.as_ref()),
+                SignatureInvalid,
+                "bad signature"
+            );
+            Ok(())
+        }
+    }
+
+    mod rfc8032 {
+        use super::{TestSigner, TestVerifier};
+
+        ed448_tests!(TestSigner, TestVerifier);
+    }
+
+    #[test]
+    fn test_lengths() {
+        for vector in TEST_VECTORS {
+            assert_eq!(vector.pk.len(), PUBLIC_KEY_SIZE);
+            assert_eq!(vector.sig.len(), SIGNATURE_SIZE);
+
+            assert_eq!(
+                PublicKey::from_bytes(vector.pk).unwrap().as_ref(),
+                vector.pk
+            );
+            assert_eq!(
+                Signature::from_bytes(vector.sig).unwrap().as_ref(),
+                vector.sig
+            );
+        }
+
+        let sig = TEST_VECTORS[0].sig;
+
+        for len in &[0, PUBLIC_KEY_SIZE - 1, PUBLIC_KEY_SIZE + 1] {
+            let err = PublicKey::from_bytes(&sig[..*len]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::KeyInvalid);
+        }
+
+        for len in &[0, PUBLIC_KEY_SIZE, SIGNATURE_SIZE - 1] {
+            let err = Signature::from_bytes(&sig[..*len]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+        }
+
+        let mut overlong = sig.to_vec();
+        overlong.push(0);
+        let err = Signature::from_bytes(&overlong).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn test_signature_from_other_vector_rejected() {
+        let (first, second) = (&TEST_VECTORS[0], &TEST_VECTORS[1]);
+        let verifier = TestVerifier::from(&PublicKey::from_bytes(first.pk).unwrap());
+        let signature = Signature::from_bytes(second.sig).unwrap();
+
+        let err = super::verify(&verifier, first.msg, &signature).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+
+        let err = super::sign(&TestSigner::from(first.sk), second.msg).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ProviderError);
+    }
+}
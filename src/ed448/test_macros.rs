@@ -0,0 +1,67 @@
+//! Macro for generating shared tests for all Ed448 implementations
+
+/// Generate tests of the RFC 8032 Ed448 test vectors for the given signer and
+/// verifier types. The signer is constructed `From` the vector's 57-byte
+/// secret key (as a byte slice), and the verifier `From` its public key.
+#[macro_export]
+macro_rules! ed448_tests {
+    ($signer:ident, $verifier:ident) => {
+        use $crate::{
+            ed448::{self, SIGNATURE_SIZE, TEST_VECTORS},
+            error::ErrorKind,
+            Ed448PublicKey, Ed448Signature, Signature,
+        };
+
+        #[test]
+        fn sign_rfc8032_test_vectors() {
+            for vector in TEST_VECTORS {
+                let signer = $signer::from(vector.sk);
+                assert_eq!(
+                    ed448::sign(&signer, vector.msg).unwrap().as_ref(),
+                    vector.sig
+                );
+            }
+        }
+
+        #[test]
+        fn verify_rfc8032_test_vectors() {
+            for vector in TEST_VECTORS {
+                let pk = Ed448PublicKey::from_bytes(vector.pk).unwrap();
+                let verifier = $verifier::from(&pk);
+                let sig = Ed448Signature::from_bytes(vector.sig).unwrap();
+                assert!(
+                    ed448::verify(&verifier, vector.msg, &sig).is_ok(),
+                    "expected signature to verify"
+                );
+            }
+        }
+
+        #[test]
+        fn rejects_tweaked_rfc8032_test_vectors() {
+            for vector in TEST_VECTORS {
+                let pk = Ed448PublicKey::from_bytes(vector.pk).unwrap();
+                let verifier = $verifier::from(&pk);
+
+                let mut tweaked_sig = [0u8; SIGNATURE_SIZE];
+                tweaked_sig.copy_from_slice(vector.sig);
+                tweaked_sig[0] ^= 0x42;
+
+                let result = ed448::verify(
+                    &verifier,
+                    vector.msg,
+                    &Ed448Signature::from_bytes(&tweaked_sig[..]).unwrap(),
+                );
+
+                assert!(
+                    result.is_err(),
+                    "expected signature verification failure but it succeeded"
+                );
+
+                match result.err().unwrap().kind() {
+                    ErrorKind::SignatureInvalid => (),
+                    other => panic!("expected ErrorKind::SignatureInvalid, got {:?}", other),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,63 @@
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use test_vector::{TestVector, TestVectorAlgorithm};
+
+/// Ed448 test vectors (from RFC 8032 section 7.4, converted to Rust bytestring literals).
+///
+/// These are the vectors without a context, with messages of up to 64 bytes.
+pub const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        alg: TestVectorAlgorithm::Ed448,
+        sk: b"\x6C\x82\xA5\x62\xCB\x80\x8D\x10\xD6\x32\xBE\x89\xC8\x51\x3E\xBF\x6C\x92\x9F\x34\xDD\xFA\x8C\x9F\x63\xC9\x96\x0E\xF6\xE3\x48\xA3\x52\x8C\x8A\x3F\xCC\x2F\x04\x4E\x39\xA3\xFC\x5B\x94\x49\x2F\x8F\x03\x2E\x75\x49\xA2\x00\x98\xF9\x5B",
+        pk: b"\x5F\xD7\x44\x9B\x59\xB4\x61\xFD\x2C\xE7\x87\xEC\x61\x6A\xD4\x6A\x1D\xA1\x34\x24\x85\xA7\x0E\x1F\x8A\x0E\xA7\x5D\x80\xE9\x67\x78\xED\xF1\x24\x76\x9B\x46\xC7\x06\x1B\xD6\x78\x3D\xF1\xE5\x0F\x6C\xD1\xFA\x1A\xBE\xAF\xE8\x25\x61\x80",
+        nonce: None, // Ed448 uses deterministic nonces
+        msg: b"",
+        sig: b"\x53\x3A\x37\xF6\xBB\xE4\x57\x25\x1F\x02\x3C\x0D\x88\xF9\x76\xAE\x2D\xFB\x50\x4A\x84\x3E\x34\xD2\x07\x4F\xD8\x23\xD4\x1A\x59\x1F\x2B\x23\x3F\x03\x4F\x62\x82\x81\xF2\xFD\x7A\x22\xDD\xD4\x7D\x78\x28\xC5\x9B\xD0\xA2\x1B\xFD\x39\x80\xFF\x0D\x20\x28\xD4\xB1\x8A\x9D\xF6\x3E\x00\x6C\x5D\x1C\x2D\x34\x5B\x92\x5D\x8D\xC0\x0B\x41\x04\x85\x2D\xB9\x9A\xC5\xC7\xCD\xDA\x85\x30\xA1\x13\xA0\xF4\xDB\xB6\x11\x49\xF0\x5A\x73\x63\x26\x8C\x71\xD9\x58\x08\xFF\x2E\x65\x26\x00",
+        pass: true
+    },
+    TestVector {
+        alg: TestVectorAlgorithm::Ed448,
+        sk: b"\xC4\xEA\xB0\x5D\x35\x70\x07\xC6\x32\xF3\xDB\xB4\x84\x89\x92\x4D\x55\x2B\x08\xFE\x0C\x35\x3A\x0D\x4A\x1F\x00\xAC\xDA\x2C\x46\x3A\xFB\xEA\x67\xC5\xE8\xD2\x87\x7C\x5E\x3B\xC3\x97\xA6\x59\x94\x9E\xF8\x02\x1E\x95\x4E\x0A\x12\x27\x4E",
+        pk: b"\x43\xBA\x28\xF4\x30\xCD\xFF\x45\x6A\xE5\x31\x54\x5F\x7E\xCD\x0A\xC8\x34\xA5\x5D\x93\x58\xC0\x37\x2B\xFA\x0C\x6C\x67\x98\xC0\x86\x6A\xEA\x01\xEB\x00\x74\x28\x02\xB8\x43\x8E\xA4\xCB\x82\x16\x9C\x23\x51\x60\x62\x7B\x4C\x3A\x94\x80",
+        nonce: None,
+        msg: b"\x03",
+        sig: b"\x26\xB8\xF9\x17\x27\xBD\x62\x89\x7A\xF1\x5E\x41\xEB\x43\xC3\x77\xEF\xB9\xC6\x10\xD4\x8F\x23\x35\xCB\x0B\xD0\x08\x78\x10\xF4\x35\x25\x41\xB1\x43\xC4\xB9\x81\xB7\xE1\x8F\x62\xDE\x8C\xCD\xF6\x33\xFC\x1B\xF0\x37\xAB\x7C\xD7\x79\x80\x5E\x0D\xBC\xC0\xAA\xE1\xCB\xCE\xE1\xAF\xB2\xE0\x27\xDF\x36\xBC\x04\xDC\xEC\xBF\x15\x43\x36\xC1\x9F\x0A\xF7\xE0\xA6\x47\x29\x05\xE7\x99\xF1\x95\x3D\x2A\x0F\xF3\x34\x8A\xB2\x1A\xA4\xAD\xAF\xD1\xD2\x34\x44\x1C\xF8\x07\xC0\x3A\x00",
+        pass: true
+    },
+    TestVector {
+        alg: TestVectorAlgorithm::Ed448,
+        sk: b"\xCD\x23\xD2\x4F\x71\x42\x74\xE7\x44\x34\x32\x37\xB9\x32\x90\xF5\x11\xF6\x42\x5F\x98\xE6\x44\x59\xFF\x20\x3E\x89\x85\x08\x3F\xFD\xF6\x05\x00\x55\x3A\xBC\x0E\x05\xCD\x02\x18\x4B\xDB\x89\xC4\xCC\xD6\x7E\x18\x79\x51\x26\x7E\xB3\x28",
+        pk: b"\xDC\xEA\x9E\x78\xF3\x5A\x1B\xF3\x49\x9A\x83\x1B\x10\xB8\x6C\x90\xAA\xC0\x1C\xD8\x4B\x67\xA0\x10\x9B\x55\xA3\x6E\x93\x28\xB1\xE3\x65\xFC\xE1\x61\xD7\x1C\xE7\x13\x1A\x54\x3E\xA4\xCB\x5F\x7E\x9F\x1D\x8B\x00\x69\x64\x47\x00\x14\x00",
+        nonce: None,
+        msg: b"\x0C\x3E\x54\x40\x74\xEC\x63\xB0\x26\x5E\x0C",
+        sig: b"\x1F\x0A\x88\x88\xCE\x25\xE8\xD4\x58\xA2\x11\x30\x87\x9B\x84\x0A\x90\x89\xD9\x99\xAA\xBA\x03\x9E\xAF\x3E\x3A\xFA\x09\x0A\x09\xD3\x89\xDB\xA8\x2C\x4F\xF2\xAE\x8A\xC5\xCD\xFB\x7C\x55\xE9\x4D\x5D\x96\x1A\x29\xFE\x01\x09\x94\x1E\x00\xB8\xDB\xDE\xEA\x6D\x3B\x05\x10\x68\xDF\x72\x54\xC0\xCD\xC1\x29\xCB\xE6\x2D\xB2\xDC\x95\x7D\xBB\x47\xB5\x1F\xD3\xF2\x13\xFB\x86\x98\xF0\x64\x77\x42\x50\xA5\x02\x89\x61\xC9\xBF\x8F\xFD\x97\x3F\xE5\xD5\xC2\x06\x49\x2B\x14\x0E\x00",
+        pass: true
+    },
+    TestVector {
+        alg: TestVectorAlgorithm::Ed448,
+        sk: b"\x25\x8C\xDD\x4A\xDA\x32\xED\x9C\x9F\xF5\x4E\x63\x75\x6A\xE5\x82\xFB\x8F\xAB\x2A\xC7\x21\xF2\xC8\xE6\x76\xA7\x27\x68\x51\x3D\x93\x9F\x63\xDD\xDB\x55\x60\x91\x33\xF2\x9A\xDF\x86\xEC\x99\x29\xDC\xCB\x52\xC1\xC5\xFD\x2F\xF7\xE2\x1B",
+        pk: b"\x3B\xA1\x6D\xA0\xC6\xF2\xCC\x1F\x30\x18\x77\x40\x75\x6F\x5E\x79\x8D\x6B\xC5\xFC\x01\x5D\x7C\x63\xCC\x95\x10\xEE\x3F\xD4\x4A\xDC\x24\xD8\xE9\x68\xB6\xE4\x6E\x6F\x94\xD1\x9B\x94\x53\x61\x72\x6B\xD7\x5E\x14\x9E\xF0\x98\x17\xF5\x80",
+        nonce: None,
+        msg: b"\x64\xA6\x5F\x3C\xDE\xDC\xDD\x66\x81\x1E\x29\x15",
+        sig: b"\x7E\xEE\xAB\x7C\x4E\x50\xFB\x79\x9B\x41\x8E\xE5\xE3\x19\x7F\xF6\xBF\x15\xD4\x3A\x14\xC3\x43\x89\xB5\x9D\xD1\xA7\xB1\xB8\x5B\x4A\xE9\x04\x38\xAC\xA6\x34\xBE\xA4\x5E\x3A\x26\x95\xF1\x27\x0F\x07\xFD\xCD\xF7\xC6\x2B\x8E\xFE\xAF\x00\xB4\x5C\x2C\x96\xBA\x45\x7E\xB1\xA8\xBF\x07\x5A\x3D\xB2\x8E\x5C\x24\xF6\xB9\x23\xED\x4A\xD7\x47\xC3\xC9\xE0\x3C\x70\x79\xEF\xB8\x7C\xB1\x10\xD3\xA9\x98\x61\xE7\x20\x03\xCB\xAE\x6D\x6B\x8B\x82\x7E\x4E\x6C\x14\x30\x64\xFF\x3C\x00",
+        pass: true
+    },
+    TestVector {
+        alg: TestVectorAlgorithm::Ed448,
+        sk: b"\x7E\xF4\xE8\x45\x44\x23\x67\x52\xFB\xB5\x6B\x8F\x31\xA2\x3A\x10\xE4\x28\x14\xF5\xF5\x5C\xA0\x37\xCD\xCC\x11\xC6\x4C\x9A\x3B\x29\x49\xC1\xBB\x60\x70\x03\x14\x61\x17\x32\xA6\xC2\xFE\xA9\x8E\xEB\xC0\x26\x6A\x11\xA9\x39\x70\x10\x0E",
+        pk: b"\xB3\xDA\x07\x9B\x0A\xA4\x93\xA5\x77\x20\x29\xF0\x46\x7B\xAE\xBE\xE5\xA8\x11\x2D\x9D\x3A\x22\x53\x23\x61\xDA\x29\x4F\x7B\xB3\x81\x5C\x5D\xC5\x9E\x17\x6B\x4D\x9F\x38\x1C\xA0\x93\x8E\x13\xC6\xC0\x7B\x17\x4B\xE6\x5D\xFA\x57\x8E\x80",
+        nonce: None,
+        msg: b"\x64\xA6\x5F\x3C\xDE\xDC\xDD\x66\x81\x1E\x29\x15\xE7",
+        sig: b"\x6A\x12\x06\x6F\x55\x33\x1B\x6C\x22\xAC\xD5\xD5\xBF\xC5\xD7\x12\x28\xFB\xDA\x80\xAE\x8D\xEC\x26\xBD\xD3\x06\x74\x3C\x50\x27\xCB\x48\x90\x81\x0C\x16\x2C\x02\x74\x68\x67\x5E\xCF\x64\x5A\x83\x17\x6C\x0D\x73\x23\xA2\xCC\xDE\x2D\x80\xEF\xE5\xA1\x26\x8E\x8A\xCA\x1D\x6F\xBC\x19\x4D\x3F\x77\xC4\x49\x86\xEB\x4A\xB4\x17\x79\x19\xAD\x8B\xEC\x33\xEB\x47\xBB\xB5\xFC\x6E\x28\x19\x6F\xD1\xCA\xF5\x6B\x4E\x7E\x0B\xA5\x51\x92\x34\xD0\x47\x15\x5A\xC7\x27\xA1\x05\x31\x00",
+        pass: true
+    },
+    TestVector {
+        alg: TestVectorAlgorithm::Ed448,
+        sk: b"\xD6\x5D\xF3\x41\xAD\x13\xE0\x08\x56\x76\x88\xBA\xED\xDA\x8E\x9D\xCD\xC1\x7D\xC0\x24\x97\x4E\xA5\xB4\x22\x7B\x65\x30\xE3\x39\xBF\xF2\x1F\x99\xE6\x8C\xA6\x96\x8F\x3C\xCA\x6D\xFE\x0F\xB9\xF4\xFA\xB4\xFA\x13\x5D\x55\x42\xEA\x3F\x01",
+        pk: b"\xDF\x97\x05\xF5\x8E\xDB\xAB\x80\x2C\x7F\x83\x63\xCF\xE5\x56\x0A\xB1\xC6\x13\x2C\x20\xA9\xF1\xDD\x16\x34\x83\xA2\x6F\x8A\xC5\x3A\x39\xD6\x80\x8B\xF4\xA1\xDF\xBD\x26\x1B\x09\x9B\xB0\x3B\x3F\xB5\x09\x06\xCB\x28\xBD\x8A\x08\x1F\x00",
+        nonce: None,
+        msg: b"\xBD\x0F\x6A\x37\x47\xCD\x56\x1B\xDD\xDF\x46\x40\xA3\x32\x46\x1A\x4A\x30\xA1\x2A\x43\x4C\xD0\xBF\x40\xD7\x66\xD9\xC6\xD4\x58\xE5\x51\x22\x04\xA3\x0C\x17\xD1\xF5\x0B\x50\x79\x63\x1F\x64\xEB\x31\x12\x18\x2D\xA3\x00\x58\x35\x46\x11\x13\x71\x8D\x1A\x5E\xF9\x44",
+        sig: b"\x55\x4B\xC2\x48\x08\x60\xB4\x9E\xAB\x85\x32\xD2\xA5\x33\xB7\xD5\x78\xEF\x47\x3E\xEB\x58\xC9\x8B\xB2\xD0\xE1\xCE\x48\x8A\x98\xB1\x8D\xFD\xE9\xB9\xB9\x07\x75\xE6\x7F\x47\xD4\xA1\xC3\x48\x20\x58\xEF\xC9\xF4\x0D\x2C\xA0\x33\xA0\x80\x1B\x63\xD4\x5B\x3B\x72\x2E\xF5\x52\xBA\xD3\xB4\xCC\xB6\x67\xDA\x35\x01\x92\xB6\x1C\x50\x8C\xF7\xB6\xB5\xAD\xAD\xC2\xC8\xD9\xA4\x46\xEF\x00\x3F\xB0\x5C\xBA\x5F\x30\xE8\x8E\x36\xEC\x27\x03\xB3\x49\xCA\x22\x9C\x26\x70\x83\x39\x00",
+        pass: true
+    },
+];
@@ -1,6 +1,12 @@
 //! Abstract Syntax Notation One (ASN.1) support.
 //! Presently specialized for Distinguished Encoding Rules (DER)
 
+use generic_array::typenum::Unsigned;
+
+use curve::WeierstrassCurve;
+use ecdsa::signature::scalars::ScalarPair;
+use error::Error;
+
 /// ASN.1 tags
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(u8)]
@@ -8,6 +14,211 @@ pub(crate) enum Tag {
     /// ASN.1 `INTEGER`
     Integer = 0x02,
 
+    /// ASN.1 `BIT STRING`
+    BitString = 0x03,
+
+    /// ASN.1 `OCTET STRING`
+    #[cfg(feature = "pkcs8")]
+    OctetString = 0x04,
+
+    /// ASN.1 `OBJECT IDENTIFIER`
+    ObjectIdentifier = 0x06,
+
     /// ASN.1 `SEQUENCE`: lists of other elements
     Sequence = 0x30,
+
+    /// ASN.1 `SET` (and `SET OF`): unordered collections of other elements
+    #[cfg(all(feature = "digest", feature = "ecdsa", feature = "sha2"))]
+    Set = 0x31,
+
+    /// Constructed context-specific tag `[0]` (e.g. for `EXPLICIT` optional
+    /// fields)
+    #[cfg(any(
+        feature = "pkcs8",
+        all(feature = "digest", feature = "ecdsa", feature = "sha2")
+    ))]
+    ContextSpecific0 = 0xa0,
+}
+
+/// Parse a DER-encoded tag-length-value with the expected tag from the
+/// start of the given bytes, returning its value and the remaining bytes.
+///
+/// Lengths must be minimally encoded, in at most two bytes.
+pub(crate) fn parse_tlv(tag: Tag, bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    let (len, header_len) = parse_header(tag, bytes, true)?;
+
+    ensure!(
+        bytes.len() - header_len >= len,
+        ParseError,
+        "truncated ASN.1 {:?} (expected {} bytes, got {})",
+        tag,
+        len,
+        bytes.len() - header_len
+    );
+
+    let (value, rest) = bytes[header_len..].split_at(len);
+    Ok((value, rest))
+}
+
+/// Parse the header of a tag-length-value with the expected tag from the
+/// start of the given bytes, returning the declared length of its value
+/// (which may overrun the given bytes) and the length of the header.
+///
+/// Lengths must be in at most two bytes, and if `minimal` is set, must be
+/// minimally encoded as DER requires.
+pub(crate) fn parse_header(tag: Tag, bytes: &[u8], minimal: bool) -> Result<(usize, usize), Error> {
+    ensure!(
+        !bytes.is_empty() && bytes[0] == tag as u8,
+        ParseError,
+        "expected ASN.1 {:?}",
+        tag
+    );
+
+    let (len, length_len) = parse_length(&bytes[1..], minimal)?;
+    Ok((len, 1 + length_len))
+}
+
+/// Parse a definite-form length from the start of the given bytes (i.e.
+/// the bytes following a tag), returning the length and the number of
+/// bytes it was encoded in.
+///
+/// Lengths must be in at most two bytes, and if `minimal` is set, must be
+/// minimally encoded as DER requires.
+pub(crate) fn parse_length(bytes: &[u8], minimal: bool) -> Result<(usize, usize), Error> {
+    let (len, length_len) = match bytes.first() {
+        Some(&len) if len < 0x80 => (len as usize, 1),
+        Some(&0x81) if bytes.len() >= 2 => (bytes[1] as usize, 2),
+        Some(&0x82) if bytes.len() >= 3 => ((bytes[1] as usize) << 8 | bytes[2] as usize, 3),
+        Some(&0x80) => fail!(ParseError, "ASN.1 indefinite-length encoding not permitted"),
+        _ => fail!(ParseError, "invalid or truncated ASN.1 length"),
+    };
+
+    // DER requires the shortest length encoding: the long form is only
+    // permitted for lengths which don't fit in the short form
+    let min_len = [0, 0x80, 0x100][length_len - 1];
+
+    ensure!(
+        !minimal || len >= min_len,
+        ParseError,
+        "non-minimal ASN.1 length encoding ({})",
+        len
+    );
+
+    Ok((len, length_len))
+}
+
+/// Check that the given bytes are a valid ASN.1 DER-encoded ECDSA signature
+/// for the curve `C`, without constructing an `Asn1Signature`.
+///
+/// This runs the same checks as `Asn1Signature::from_bytes`, e.g. for
+/// cheaply rejecting malformed signatures before deciding which to parse
+/// and verify.
+pub fn validate_ecdsa_der<C: WeierstrassCurve>(bytes: &[u8]) -> Result<(), Error> {
+    ensure!(
+        bytes.len() <= C::Asn1SignatureMaxSize::to_usize(),
+        SignatureInvalid,
+        "max {}-byte signature (got {})",
+        C::Asn1SignatureMaxSize::to_usize(),
+        bytes.len()
+    );
+
+    ScalarPair::<C>::from_asn1_bytes(bytes)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_header, parse_length, parse_tlv, validate_ecdsa_der, Tag};
+    #[cfg(feature = "test-vectors")]
+    use curve::nistp256::{Asn1Signature, FixedSignature, SHA256_FIXED_SIZE_TEST_VECTORS};
+    #[cfg(feature = "test-vectors")]
+    use curve::NistP256;
+    #[cfg(feature = "test-vectors")]
+    use Signature;
+
+    #[test]
+    fn test_parse_tlv() {
+        let (value, rest) = parse_tlv(Tag::Integer, b"\x02\x01\x05\x30").unwrap();
+        assert_eq!(value, b"\x05");
+        assert_eq!(rest, b"\x30");
+
+        let mut long = [0u8; 3 + 0x80];
+        long[..3].copy_from_slice(b"\x03\x81\x80");
+        let (value, rest) = parse_tlv(Tag::BitString, &long).unwrap();
+        assert_eq!(value.len(), 0x80);
+        assert!(rest.is_empty());
+
+        // Wrong tag, truncated value, and non-minimal lengths
+        assert!(parse_tlv(Tag::Sequence, b"\x02\x01\x05").is_err());
+        assert!(parse_tlv(Tag::Integer, b"\x02\x02\x05").is_err());
+        assert!(parse_tlv(Tag::Integer, b"\x02\x81\x01\x05").is_err());
+        assert!(parse_tlv(Tag::Integer, b"\x02\x82\x00\x01\x05").is_err());
+    }
+
+    #[test]
+    fn test_parse_header() {
+        // The declared length isn't checked against the input
+        assert_eq!(
+            parse_header(Tag::Sequence, b"\x30\x45", true).unwrap(),
+            (0x45, 2)
+        );
+        assert_eq!(
+            parse_header(Tag::Sequence, b"\x30\x82\x01\x00", true).unwrap(),
+            (0x100, 4)
+        );
+
+        // Non-minimal lengths are only accepted if `minimal` isn't set
+        assert!(parse_header(Tag::Sequence, b"\x30\x81\x45", true).is_err());
+        assert_eq!(
+            parse_header(Tag::Sequence, b"\x30\x81\x45", false).unwrap(),
+            (0x45, 3)
+        );
+
+        assert!(parse_header(Tag::Integer, b"\x30\x45", false).is_err());
+        assert!(parse_header(Tag::Sequence, b"\x30", false).is_err());
+    }
+
+    #[test]
+    fn test_parse_length() {
+        assert_eq!(parse_length(b"\x7f", true).unwrap(), (0x7f, 1));
+        assert_eq!(parse_length(b"\x81\x80", true).unwrap(), (0x80, 2));
+        assert_eq!(parse_length(b"\x82\xff\xff", true).unwrap(), (0xffff, 3));
+        assert_eq!(parse_length(b"\x82\x00\x7f", false).unwrap(), (0x7f, 3));
+
+        // Indefinite, over two bytes, truncated, and non-minimal lengths
+        assert!(parse_length(b"\x80", false).is_err());
+        assert!(parse_length(b"\x83\x00\x00\x80", false).is_err());
+        assert!(parse_length(b"\x81", false).is_err());
+        assert!(parse_length(b"", false).is_err());
+        assert!(parse_length(b"\x82\x00\xff", true).is_err());
+    }
+
+    #[cfg(feature = "test-vectors")]
+    #[test]
+    fn test_validate_ecdsa_der() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let fixed_signature = FixedSignature::from_bytes(&vector.sig).unwrap();
+            let asn1_signature = Asn1Signature::from(&fixed_signature);
+            let der = asn1_signature.as_ref();
+            assert!(validate_ecdsa_der::<NistP256>(der).is_ok());
+
+            // Validation must agree with parsing for truncated and mutated DER
+            for len in 0..der.len() {
+                assert!(validate_ecdsa_der::<NistP256>(&der[..len]).is_err());
+            }
+
+            for i in 0..der.len() {
+                let mut mutated = der.to_vec();
+                mutated[i] ^= 0x80;
+                assert_eq!(
+                    validate_ecdsa_der::<NistP256>(&mutated).is_ok(),
+                    Asn1Signature::from_bytes(&mutated).is_ok()
+                );
+            }
+
+            let mut overlong = der.to_vec();
+            overlong.resize(73, 0);
+            assert!(validate_ecdsa_der::<NistP256>(&overlong).is_err());
+        }
+    }
 }
@@ -1,11 +1,12 @@
 #[cfg(feature = "std")]
 use std::{fs::File, io::Write, path::Path};
-#[cfg(unix)]
+#[cfg(all(unix, feature = "std"))]
 use std::{fs::OpenOptions, os::unix::fs::OpenOptionsExt};
 use subtle_encoding::Encoding;
+#[cfg(feature = "std")]
 use zeroize::Zeroize;
 
-#[cfg(unix)]
+#[cfg(all(unix, feature = "std"))]
 use super::FILE_MODE;
 use error::Error;
 use prelude::*;
@@ -75,3 +76,14 @@ pub trait Encode: Sized {
         Ok(file)
     }
 }
+
+/// Encode the given bytes with the given `Encoding`, returning a `Vec<u8>`.
+///
+/// Unlike `Encoding::encode`, this doesn't need `subtle-encoding`'s `alloc`
+/// feature (so it also works with `alloc` but not `std`).
+pub(crate) fn encode_vec<E: Encoding>(encoding: &E, bytes: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![0u8; encoding.encoded_len(bytes)];
+    let encoded_len = encoding.encode_to_slice(bytes, &mut encoded).unwrap();
+    debug_assert_eq!(encoded_len, encoded.len());
+    encoded
+}
@@ -8,16 +8,22 @@ mod macros;
 pub use subtle_encoding::{Base64, Hex, Identity};
 
 #[cfg(feature = "ecdsa")]
-pub(crate) mod asn1;
+pub mod asn1;
 mod decode;
 #[cfg(feature = "alloc")]
 mod encode;
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+mod pem;
 #[cfg(feature = "pkcs8")]
 pub mod pkcs8;
 
 pub use self::decode::Decode;
 #[cfg(feature = "alloc")]
+pub(crate) use self::encode::encode_vec;
+#[cfg(feature = "alloc")]
 pub use self::encode::Encode;
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+pub(crate) use self::pem::decode_pem;
 #[cfg(feature = "pkcs8")]
 pub use self::pkcs8::FromPkcs8;
 
@@ -0,0 +1,37 @@
+//! Decoding of PEM documents (as described in RFC 7468)
+
+use subtle_encoding::{Base64, Encoding};
+
+use error::Error;
+use prelude::*;
+
+/// Decode the DER body of a PEM document with the given encapsulation
+/// boundaries (e.g. `-----BEGIN PUBLIC KEY-----` and
+/// `-----END PUBLIC KEY-----`), ignoring whitespace.
+///
+/// Documents with any other boundaries, or with a malformed body, are a
+/// `KeyInvalid` error.
+pub(crate) fn decode_pem(pem: &str, begin: &str, end: &str) -> Result<Vec<u8>, Error> {
+    let body = pem.trim().trim_start_matches(begin).trim_end_matches(end);
+
+    ensure!(
+        body.len() + begin.len() + end.len() == pem.trim().len(),
+        KeyInvalid,
+        "expected PEM document beginning with {}",
+        begin
+    );
+
+    let encoded: Vec<u8> = body
+        .bytes()
+        .filter(|b| !(*b as char).is_ascii_whitespace())
+        .collect();
+
+    Base64::default()
+        .decoded_len(&encoded)
+        .and_then(|len| {
+            let mut der = vec![0u8; len];
+            Base64::default().decode_to_slice(&encoded, &mut der)?;
+            Ok(der)
+        })
+        .map_err(|e| err!(KeyInvalid, "malformed PEM: {}", e))
+}
@@ -5,7 +5,7 @@
 //! [RFC 5915]: https://tools.ietf.org/html/rfc5915
 
 use error::Error;
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use prelude::*;
 #[cfg(feature = "std")]
 use std::io::Write;
@@ -2,13 +2,13 @@
 
 #![allow(unused_macros)]
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::FromUtf8Error;
 use core::fmt;
+#[cfg(feature = "alloc")]
+use prelude::*;
 #[cfg(feature = "std")]
-use std::{
-    error::Error as StdError,
-    io,
-    string::{FromUtf8Error, String, ToString},
-};
+use std::{error::Error as StdError, io, string::FromUtf8Error};
 #[cfg(feature = "encoding")]
 use subtle_encoding;
 
@@ -17,7 +17,7 @@ use subtle_encoding;
 pub struct Error {
     kind: ErrorKind,
 
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     description: Option<String>,
 }
 
@@ -28,7 +28,7 @@ impl Error {
         Error {
             kind,
 
-            #[cfg(feature = "std")]
+            #[cfg(feature = "alloc")]
             description: description.map(|desc| desc.to_string()),
         }
     }
@@ -39,19 +39,19 @@ impl Error {
     }
 }
 
-#[cfg(not(feature = "std"))]
+#[cfg(not(feature = "alloc"))]
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.kind.as_str())
+        write!(f, "{} (code {})", self.kind.as_str(), self.kind.code())
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.description {
-            Some(ref desc) => write!(f, "{}: {}", self.description(), desc),
-            None => write!(f, "{}", self.description()),
+            Some(ref desc) => write!(f, "{}: {}", self.kind.as_str(), desc),
+            None => write!(f, "{}", self.kind.as_str()),
         }
     }
 }
@@ -67,12 +67,28 @@ impl StdError for Error {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Error {
+    /// Log this error with `defmt`, including its description (if any)
+    fn format(&self, f: defmt::Formatter) {
+        #[cfg(feature = "alloc")]
+        {
+            if let Some(ref desc) = self.description {
+                defmt::write!(f, "{}: {=str}", self.kind, desc.as_str());
+                return;
+            }
+        }
+
+        defmt::write!(f, "{}", self.kind)
+    }
+}
+
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Error {
         Error {
             kind,
 
-            #[cfg(feature = "std")]
+            #[cfg(feature = "alloc")]
             description: None,
         }
     }
@@ -95,6 +111,9 @@ pub enum ErrorKind {
 
     /// Signature is not valid
     SignatureInvalid,
+
+    /// Algorithm or operation is not supported (e.g. mismatched curves)
+    Unsupported,
 }
 
 impl ErrorKind {
@@ -107,6 +126,23 @@ impl ErrorKind {
             ErrorKind::ParseError => "parse error",
             ErrorKind::ProviderError => "internal crypto provider error",
             ErrorKind::SignatureInvalid => "bad signature",
+            ErrorKind::Unsupported => "unsupported algorithm or operation",
+        }
+    }
+
+    /// Obtain a numeric code for this error, e.g. for reporting errors as
+    /// a single byte from `no_std` environments.
+    ///
+    /// These codes are stable across releases: existing codes will never be
+    /// renumbered or reused, and new kinds of errors will be assigned new codes.
+    pub fn code(self) -> u8 {
+        match self {
+            ErrorKind::Io => 1,
+            ErrorKind::KeyInvalid => 2,
+            ErrorKind::ParseError => 3,
+            ErrorKind::ProviderError => 4,
+            ErrorKind::SignatureInvalid => 5,
+            ErrorKind::Unsupported => 6,
         }
     }
 }
@@ -117,19 +153,30 @@ impl fmt::Display for ErrorKind {
     }
 }
 
-/// Create a new error (of a given enum variant) with a formatted message
-#[cfg(not(feature = "std"))]
+#[cfg(feature = "defmt")]
+impl defmt::Format for ErrorKind {
+    /// Log this error kind with `defmt` as its description and numeric code
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str} (code {=u8})", self.as_str(), self.code())
+    }
+}
+
+/// Create a new error (of a given enum variant) with a formatted message.
+/// Without `alloc` the message is discarded, but its arguments are still
+/// type checked (and so don't trigger unused variable warnings).
+#[cfg(not(feature = "alloc"))]
 macro_rules! err {
     ($variant:ident, $msg:expr) => {
         ::error::Error::from(::error::ErrorKind::$variant)
     };
-    ($variant:ident, $fmt:expr, $($arg:tt)+) => {
+    ($variant:ident, $fmt:expr, $($arg:tt)+) => {{
+        let _ = format_args!($fmt, $($arg)+);
         ::error::Error::from(::error::ErrorKind::$variant)
-    };
+    }};
 }
 
 /// Create a new error (of a given enum variant) with a formatted message
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 macro_rules! err {
     ($variant:ident, $msg:expr) => {
         ::error::Error::new(
@@ -167,7 +214,7 @@ macro_rules! ensure {
     };
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl From<FromUtf8Error> for Error {
     fn from(err: FromUtf8Error) -> Self {
         err!(ParseError, &err.to_string())
@@ -192,3 +239,23 @@ impl From<subtle_encoding::Error> for Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorKind;
+
+    #[test]
+    fn test_error_kind_codes() {
+        // These values are part of the public API and must never change
+        for &(kind, code) in &[
+            (ErrorKind::Io, 1),
+            (ErrorKind::KeyInvalid, 2),
+            (ErrorKind::ParseError, 3),
+            (ErrorKind::ProviderError, 4),
+            (ErrorKind::SignatureInvalid, 5),
+            (ErrorKind::Unsupported, 6),
+        ] {
+            assert_eq!(kind.code(), code, "code for {:?}", kind);
+        }
+    }
+}
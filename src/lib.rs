@@ -9,6 +9,8 @@
 //! - [ecdsa]: Elliptic Curve Digital Signature Algorithm ([FIPS 186-4])
 //! - [ed25519]: Edwards Digital Signature Algorithm (EdDSA) instantiated using
 //!   the twisted Edwards form of Curve25519 ([RFC 8032]).
+//! - [ed448]: EdDSA instantiated using the untwisted Edwards form of Curve448
+//!   ([RFC 8032]). No providers currently support Ed448.
 //!
 //! ## Providers
 //!
@@ -80,16 +82,30 @@
 //!   against the provided signature, i.e. IUF. This method wraps the
 //!   [DigestVerifier] trait and is most useful for verifying large messages
 //!   in conjunction with hardware-backed signers.
-//! * [signatory::verify_sha256], [signatory::verify_sha384],
-//!   [signatory::verify_sha512] - verify the given message after first
-//!   computing its SHA-2 digest. These methods wrap the [Sha256Verifier],
-//!   [Sha384Verifier], and [Sha512Verifier] traits respectively, and are most
+//! * [signatory::verify_sha224], [signatory::verify_sha256],
+//!   [signatory::verify_sha384], [signatory::verify_sha512] - verify the given
+//!   message after first computing its SHA-2 digest. These methods wrap the
+//!   [Sha224Verifier], [Sha256Verifier], [Sha384Verifier], and
+//!   [Sha512Verifier] traits respectively, and are most
 //!   useful in conjunction with [ecdsa].
 //!
+//! ## Other digest functions
+//!
+//! [DigestSigner] and [DigestVerifier] accept any hash function which
+//! implements `signatory::digest::Digest` (currently v0.7 of the [digest]
+//! crate), so long as its output is the size ECDSA providers expect for the
+//! curve. For example, Ethereum signs secp256k1 ECDSA over Keccak-256, which
+//! works with `sha3::Keccak256` from v0.7 of the [sha3] crate. Hash function
+//! crates built against a different version of `digest` don't implement the
+//! same trait, and are rejected at compile time.
+//!
 //! [FIPS 186-4]: https://csrc.nist.gov/publications/detail/fips/186/4/final
 //! [RFC 8032]: https://tools.ietf.org/html/rfc8032
+//! [digest]: https://docs.rs/crate/digest/
+//! [sha3]: https://docs.rs/crate/sha3/
 //! [ecdsa]: https://docs.rs/signatory/latest/signatory/ecdsa/index.html
 //! [ed25519]: https://docs.rs/signatory/latest/signatory/ed25519/index.html
+//! [ed448]: https://docs.rs/signatory/latest/signatory/ed448/index.html
 //! [signatory-dalek]: https://docs.rs/crate/signatory-dalek/
 //! [ed25519-dalek]: https://docs.rs/crate/ed25519-dalek/
 //! [signatory-ring]: https://docs.rs/crate/signatory-ring/
@@ -112,11 +128,13 @@
 //! [Sha512Signer]: https://docs.rs/signatory/latest/signatory/trait.Sha512Signer.html
 //! [signatory::verify]: https://docs.rs/signatory/latest/signatory/fn.verify.html
 //! [signatory::verify_digest]: https://docs.rs/signatory/latest/signatory/fn.verify_digest.html
+//! [signatory::verify_sha224]: https://docs.rs/signatory/latest/signatory/fn.verify_sha224.html
 //! [signatory::verify_sha256]: https://docs.rs/signatory/latest/signatory/fn.verify_sha256.html
 //! [signatory::verify_sha384]: https://docs.rs/signatory/latest/signatory/fn.verify_sha384.html
 //! [signatory::verify_sha512]: https://docs.rs/signatory/latest/signatory/fn.verify_sha512.html
 //! [Verifier]: https://docs.rs/signatory/latest/signatory/trait.Verifier.html
 //! [DigestVerifier]: https://docs.rs/signatory/latest/signatory/trait.DigestVerifier.html
+//! [Sha224Verifier]: https://docs.rs/signatory/latest/signatory/trait.Sha224Verifier.html
 //! [Sha256Verifier]: https://docs.rs/signatory/latest/signatory/trait.Sha256Verifier.html
 //! [Sha384Verifier]: https://docs.rs/signatory/latest/signatory/trait.Sha384Verifier.html
 //! [Sha512Verifier]: https://docs.rs/signatory/latest/signatory/trait.Sha512Verifier.html
@@ -125,29 +143,43 @@
 #![crate_name = "signatory"]
 #![crate_type = "lib"]
 #![no_std]
-#![cfg_attr(
-    all(feature = "nightly", not(feature = "std")),
-    feature(alloc)
-)]
 #![deny(warnings, missing_docs, trivial_casts, trivial_numeric_casts)]
 #![deny(unsafe_code, unused_import_braces, unused_qualifications)]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/tendermint/signatory/master/img/signatory-rustacean.png",
-    html_root_url = "https://docs.rs/signatory/0.9.3"
+    html_root_url = "https://docs.rs/signatory/0.10.0"
 )]
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[allow(unused_imports)]
+#[macro_use]
+extern crate alloc;
 #[cfg(any(feature = "std", test))]
 #[macro_use]
 extern crate std;
 
+#[cfg(feature = "defmt")]
+extern crate defmt;
 #[cfg(feature = "digest")]
 pub extern crate digest;
 #[cfg(feature = "generic-array")]
 pub extern crate generic_array;
+#[cfg(feature = "rustcrypto")]
+extern crate k256;
+#[cfg(feature = "rustcrypto")]
+extern crate p256;
+#[cfg(feature = "rustcrypto")]
+extern crate p384;
 #[cfg(feature = "rand")]
 extern crate rand;
+#[cfg(feature = "rustcrypto")]
+extern crate rustcrypto_ecdsa;
 #[cfg(feature = "sha2")]
 extern crate sha2;
+#[cfg(feature = "signature")]
+extern crate signature as signature_crate;
+#[cfg(feature = "subtle")]
+extern crate subtle;
 #[cfg(feature = "encoding")]
 pub extern crate subtle_encoding;
 #[cfg(feature = "zeroize")]
@@ -156,15 +188,22 @@ extern crate zeroize;
 #[macro_use]
 pub mod error;
 
+#[cfg(all(feature = "digest", feature = "signature"))]
+pub mod adapter;
 #[cfg(feature = "ecdsa")]
 pub mod ecdsa;
 #[cfg(feature = "ed25519")]
 #[macro_use]
 pub mod ed25519;
+#[cfg(feature = "ed448")]
+#[macro_use]
+pub mod ed448;
 #[cfg(feature = "encoding")]
 pub mod encoding;
 pub(crate) mod prelude;
 mod public_key;
+#[cfg(feature = "unstable-provider-internals")]
+pub mod raw_provider;
 mod signature;
 mod signer;
 #[cfg(feature = "test-vectors")]
@@ -182,10 +221,16 @@ pub use ecdsa::{
 pub use ed25519::{
     PublicKey as Ed25519PublicKey, Seed as Ed25519Seed, Signature as Ed25519Signature,
 };
+#[cfg(feature = "ed448")]
+pub use ed448::{
+    Ed448Signer, Ed448Verifier, PublicKey as Ed448PublicKey, Signature as Ed448Signature,
+};
 #[cfg(feature = "encoding")]
 pub use encoding::*;
 pub use error::{Error, ErrorKind};
 pub use public_key::{public_key, PublicKey, PublicKeyed};
+#[cfg(feature = "unstable-provider-internals")]
+pub use raw_provider::RawProvider;
 pub use signature::Signature;
 #[cfg(feature = "digest")]
 pub use signer::digest::sign_digest;
@@ -194,10 +239,12 @@ pub use signer::{
     sha2::{sign_sha256, sign_sha384, sign_sha512},
     sign,
 };
+#[cfg(all(feature = "digest", feature = "std"))]
+pub use verifier::digest::verify_file_digest;
 #[cfg(feature = "digest")]
-pub use verifier::digest::verify_digest;
+pub use verifier::digest::{verify_digest, verify_with_context};
 pub use verifier::*;
 pub use verifier::{
-    sha2::{verify_sha256, verify_sha384, verify_sha512},
+    sha2::{verify_sha224, verify_sha256, verify_sha384, verify_sha512},
     verify,
 };
@@ -1,7 +1,10 @@
-//! Use either of `std` prelude or `alloc` prelude (latter only on nightly)
+//! Use either of `std` prelude or the equivalent parts of `alloc`
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
-pub use alloc::prelude::*;
+pub use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 #[cfg(feature = "std")]
 pub use std::prelude::v1::*;
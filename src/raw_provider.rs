@@ -0,0 +1,32 @@
+//! **UNSTABLE**: escape hatch for accessing providers' internal handles
+//!
+//! Enable Signatory's `unstable-provider-internals` cargo feature to enable
+//! this trait. It is NOT covered by semver, and may change or be removed in
+//! any release.
+
+/// Access a signer or verifier's underlying provider-specific handle (e.g.
+/// a `secp256k1` key, or a *ring* key pair), for advanced users who need
+/// to perform operations Signatory doesn't expose (e.g. when integrating
+/// with an HSM) without forking the provider.
+///
+/// # Safety
+///
+/// Using the handle voids all of Signatory's guarantees about the signer or
+/// verifier it was obtained from. Nothing checks what's done with it: it
+/// can be used to produce signatures which bypass Signatory's API (and any
+/// checks or normalization done there), to copy secret key material out of
+/// types which would otherwise zeroize it on drop, or to perform operations
+/// with the provider's library which Signatory considers unsafe.
+///
+/// Callers must uphold whatever invariants the provider's library requires
+/// of the handle, and are responsible for the security of anything done
+/// with it.
+pub trait RawProvider {
+    /// Provider-specific handle type
+    type Handle;
+
+    /// Borrow the provider's underlying handle. See the trait documentation
+    /// for the (lack of) guarantees once it's been obtained.
+    #[allow(unsafe_code)]
+    unsafe fn raw_handle(&self) -> &Self::Handle;
+}
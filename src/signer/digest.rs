@@ -17,6 +17,25 @@ where
     /// Sign the output of the given digest with signer's private key,
     /// returning a signature.
     fn sign(&self, digest: D) -> Result<S, Error>;
+
+    /// Sign the output of the given digest using a deterministic nonce
+    /// (RFC 6979) with the given additional data `k'` mixed into the nonce
+    /// derivation, as described in RFC 6979 section 3.6.
+    ///
+    /// This blinds the nonce (e.g. against fault attacks) while keeping
+    /// signing deterministic for any given `added_entropy`. The default
+    /// implementation returns `ErrorKind::Unsupported`.
+    #[allow(unused_variables)]
+    fn sign_digest_deterministic_with_entropy(
+        &self,
+        digest: D,
+        added_entropy: &[u8],
+    ) -> Result<S, Error> {
+        fail!(
+            Unsupported,
+            "signer doesn't support RFC 6979 signing with added entropy"
+        );
+    }
 }
 
 /// Sign the given prehashed `Digest` with the given signer.
@@ -0,0 +1,225 @@
+//! Deterministic generation of ECDSA test vectors from a seed
+
+use digest::Digest;
+use generic_array::{typenum::Unsigned, GenericArray};
+
+use super::TestVectorAlgorithm;
+use ecdsa::curve::{WeierstrassCurve, WeierstrassCurveKind};
+use ecdsa::{PublicKey, SecretKey};
+use error::Error;
+#[allow(unused_imports)]
+use prelude::*;
+use signer::DigestSigner;
+use PublicKeyed;
+use Signature;
+
+/// Test vector generated by `generate_test_vectors`.
+///
+/// Unlike `TestVector`, all fields are owned. They are raw bytes, which can
+/// be serialized in whatever golden file format is convenient.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GeneratedTestVector {
+    /// Algorithm name
+    pub alg: TestVectorAlgorithm,
+
+    /// Secret key (i.e. `x` value for ECDSA)
+    pub sk: Vec<u8>,
+
+    /// Public key, serialized as returned by the signer
+    pub pk: Vec<u8>,
+
+    /// Message which was signed
+    pub msg: Vec<u8>,
+
+    /// Signature produced by the signer
+    pub sig: Vec<u8>,
+}
+
+/// Generate `count` test vectors deterministically derived from `seed`.
+///
+/// Secret keys and messages are derived from `seed` by hashing it with the
+/// digest function `D` (along with a label and a counter). Each message is
+/// signed using a signer of type `P` constructed from the derived secret key,
+/// with its digest computed using `D`.
+///
+/// The results are only fully deterministic if the signer is too, i.e. it
+/// computes nonces per RFC 6979 (as e.g. the `signatory-secp256k1` provider
+/// does).
+pub fn generate_test_vectors<C, D, S, P>(
+    seed: &[u8],
+    count: usize,
+) -> Result<Vec<GeneratedTestVector>, Error>
+where
+    C: WeierstrassCurve,
+    D: Digest,
+    S: Signature,
+    P: DigestSigner<D, S> + PublicKeyed<PublicKey<C>> + for<'a> From<&'a SecretKey<C>>,
+{
+    let mut vectors = Vec::with_capacity(count);
+
+    for index in 0..count as u64 {
+        let secret_key = derive_secret_key::<C, D>(seed, index);
+        let msg = derive::<D>(seed, b"msg", index, 0).to_vec();
+
+        let signer = P::from(&secret_key);
+        let public_key = signer.public_key()?;
+
+        let mut digest = D::default();
+        digest.input(&msg);
+        let signature = signer.sign(digest)?;
+
+        vectors.push(GeneratedTestVector {
+            alg: TestVectorAlgorithm::from(C::CURVE_KIND),
+            sk: secret_key.as_secret_slice().to_vec(),
+            pk: public_key.as_bytes().to_vec(),
+            msg,
+            sig: signature.as_slice().to_vec(),
+        });
+    }
+
+    Ok(vectors)
+}
+
+/// Derive the secret key at the given index from the seed, retrying until
+/// it's a valid scalar (i.e. `0 < x < n`)
+fn derive_secret_key<C, D>(seed: &[u8], index: u64) -> SecretKey<C>
+where
+    C: WeierstrassCurve,
+    D: Digest,
+{
+    let scalar_size = C::ScalarSize::to_usize();
+    let output_size = D::OutputSize::to_usize();
+    let mut counter = 0u64;
+
+    loop {
+        let mut bytes: GenericArray<u8, C::ScalarSize> = GenericArray::default();
+
+        for chunk in bytes.as_mut_slice().chunks_mut(output_size) {
+            let output = derive::<D>(seed, b"sk", index, counter);
+            chunk.copy_from_slice(&output[..chunk.len()]);
+            counter += 1;
+        }
+
+        // Big endian integers of equal length compare lexicographically
+        let is_zero = bytes.iter().all(|&byte| byte == 0);
+        if !is_zero && bytes[..] < C::ORDER[..scalar_size] {
+            return SecretKey::new(bytes);
+        }
+    }
+}
+
+/// Hash the seed along with a label, index, and counter
+fn derive<D: Digest>(
+    seed: &[u8],
+    label: &[u8],
+    index: u64,
+    counter: u64,
+) -> GenericArray<u8, D::OutputSize> {
+    let mut digest = D::default();
+    digest.input(&encode_u64(seed.len() as u64));
+    digest.input(seed);
+    digest.input(label);
+    digest.input(&encode_u64(index));
+    digest.input(&encode_u64(counter));
+    digest.result()
+}
+
+/// Serialize a `u64` as big endian
+fn encode_u64(value: u64) -> [u8; 8] {
+    let mut bytes = [0u8; 8];
+
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = (value >> (56 - i * 8)) as u8;
+    }
+
+    bytes
+}
+
+impl From<WeierstrassCurveKind> for TestVectorAlgorithm {
+    fn from(curve_kind: WeierstrassCurveKind) -> TestVectorAlgorithm {
+        match curve_kind {
+            WeierstrassCurveKind::NistP256 => TestVectorAlgorithm::NistP256,
+            WeierstrassCurveKind::NistP384 => TestVectorAlgorithm::NistP384,
+            WeierstrassCurveKind::Secp256k1 => TestVectorAlgorithm::Secp256k1,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "sha2", feature = "test-signer"))]
+mod tests {
+    use digest::Digest;
+    use sha2::Sha256;
+
+    use super::{generate_test_vectors, GeneratedTestVector};
+    use curve::{NistP256, NistP384, WeierstrassCurve};
+    use ecdsa::{FixedSignature, MemorySigner, PublicKey, SecretKey};
+    #[allow(unused_imports)]
+    use prelude::*;
+    use test_vector::TestVectorAlgorithm;
+    use {DigestSigner, PublicKeyed, Signature};
+
+    /// Generate P-256 test vectors from the given seed
+    fn generate(seed: &[u8], count: usize) -> Vec<GeneratedTestVector> {
+        generate_test_vectors::<NistP256, Sha256, FixedSignature<NistP256>, MemorySigner<NistP256>>(
+            seed, count,
+        )
+        .unwrap()
+    }
+
+    /// Check the vector's public key and signature are the ones computed with
+    /// its secret key
+    fn check_vector<C: WeierstrassCurve>(vector: &GeneratedTestVector) {
+        let signer = MemorySigner::from(&SecretKey::<C>::from_bytes(&vector.sk).unwrap());
+        assert_eq!(
+            signer.public_key().unwrap(),
+            PublicKey::from_bytes(&vector.pk).unwrap()
+        );
+
+        let mut digest = Sha256::default();
+        digest.input(&vector.msg);
+        let signature: FixedSignature<C> = signer.sign(digest).unwrap();
+        assert_eq!(signature.as_slice(), vector.sig.as_slice());
+    }
+
+    #[test]
+    fn test_generate_test_vectors_is_deterministic() {
+        let vectors = generate(b"signatory test vectors", 3);
+        assert_eq!(vectors.len(), 3);
+        assert_ne!(vectors[0], vectors[1]);
+        assert_ne!(vectors[0].sk, vectors[1].sk);
+        assert_ne!(vectors[0].msg, vectors[1].msg);
+
+        // Same seed yields the same vectors, since signing is RFC 6979 deterministic
+        assert_eq!(vectors, generate(b"signatory test vectors", 3));
+        assert_eq!(&vectors[..2], &generate(b"signatory test vectors", 2)[..]);
+        assert_ne!(vectors, generate(b"another seed", 3));
+
+        assert!(generate(b"signatory test vectors", 0).is_empty());
+    }
+
+    #[test]
+    fn test_generated_test_vectors_are_valid() {
+        for vector in &generate(b"signatory test vectors", 3) {
+            assert_eq!(vector.alg, TestVectorAlgorithm::NistP256);
+            check_vector::<NistP256>(vector);
+        }
+    }
+
+    #[test]
+    fn test_generate_secret_keys_wider_than_digest() {
+        // P-384 secret keys are derived from two SHA-256 outputs
+        let vectors = generate_test_vectors::<
+            NistP384,
+            Sha256,
+            FixedSignature<NistP384>,
+            MemorySigner<NistP384>,
+        >(b"signatory test vectors", 2)
+        .unwrap();
+
+        for vector in &vectors {
+            assert_eq!(vector.alg, TestVectorAlgorithm::NistP384);
+            assert_eq!(vector.sk.len(), 48);
+            check_vector::<NistP384>(vector);
+        }
+    }
+}
@@ -1,8 +1,13 @@
 //! Test vector structure for signatures
 
+#[cfg(all(feature = "digest", feature = "ecdsa", feature = "testing"))]
+mod generate;
 #[cfg(feature = "alloc")]
 mod pkcs8;
 
+#[cfg(all(feature = "digest", feature = "ecdsa", feature = "testing"))]
+pub use self::generate::{generate_test_vectors, GeneratedTestVector};
+
 /// Signature test vector
 pub struct TestVector {
     /// Algorithm name
@@ -41,4 +46,7 @@ pub enum TestVectorAlgorithm {
 
     /// "edwards25519" elliptic curve
     Ed25519,
+
+    /// "edwards448" elliptic curve
+    Ed448,
 }
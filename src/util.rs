@@ -1,6 +1,8 @@
 //! Miscellaneous utility functions
 
 use core::fmt;
+#[cfg(feature = "subtle")]
+use subtle::Choice;
 
 #[allow(dead_code)]
 pub(crate) fn fmt_colon_delimited_hex<B>(f: &mut fmt::Formatter, bytes: B) -> fmt::Result
@@ -19,3 +21,55 @@ where
 
     Ok(())
 }
+
+/// Shift the given big-endian integer right by one bit (i.e. divide by 2),
+/// writing the result into `out` (which must be the same length)
+#[allow(dead_code)]
+pub(crate) fn be_shr1(x: &[u8], out: &mut [u8]) {
+    let mut carry = 0u8;
+
+    for (o, &byte) in out.iter_mut().zip(x.iter()) {
+        *o = (byte >> 1) | carry;
+        carry = byte << 7;
+    }
+}
+
+/// Subtract the big-endian integer `b` from `a` (which must be at least as
+/// large), writing the result into `out` (all must be the same length),
+/// without branching on their values
+#[allow(dead_code)]
+pub(crate) fn be_sub(a: &[u8], b: &[u8], out: &mut [u8]) {
+    let borrow = be_wrapping_sub(a, b, out);
+    debug_assert_eq!(borrow, 0, "underflow");
+}
+
+/// Subtract the big-endian integer `b` from `a` modulo `2^(8 * len)` like
+/// `be_sub`, but permitting `a < b`, returning the final borrow (i.e. 1 if
+/// the subtraction wrapped, otherwise 0)
+#[allow(dead_code)]
+pub(crate) fn be_wrapping_sub(a: &[u8], b: &[u8], out: &mut [u8]) -> u8 {
+    let mut borrow = 0u16;
+
+    for ((o, &x), &y) in out.iter_mut().zip(a.iter()).zip(b.iter()).rev() {
+        let diff = 0x100u16 + u16::from(x) - u16::from(y) - borrow;
+        *o = diff as u8;
+        borrow = 1 - (diff >> 8);
+    }
+
+    borrow as u8
+}
+
+/// Is the big-endian integer `a` less than `b` (which must be the same
+/// length)? Computed in constant time, from the borrow of `a - b`.
+#[cfg(feature = "subtle")]
+#[allow(dead_code)]
+pub(crate) fn ct_scalar_less_than(a: &[u8], b: &[u8]) -> Choice {
+    let mut borrow = 0u16;
+
+    for (&x, &y) in a.iter().zip(b.iter()).rev() {
+        let diff = 0x100u16 + u16::from(x) - u16::from(y) - borrow;
+        borrow = 1 - (diff >> 8);
+    }
+
+    Choice::from(borrow as u8)
+}
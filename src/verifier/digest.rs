@@ -3,6 +3,8 @@
 //! Enable Signatory's `digest` cargo feature to enable this trait.
 
 use digest::Digest;
+#[cfg(feature = "std")]
+use std::{fs::File, io::Read, path::Path};
 
 use error::Error;
 use Signature;
@@ -32,3 +34,227 @@ where
 {
     verifier.verify(digest, signature)
 }
+
+/// Incremental (a.k.a. "IUF") verification state: message chunks are input
+/// into a `Digest` as they're received, and the signature is verified against
+/// the resulting digest with a `DigestVerifier` once the message is complete.
+pub struct DigestVerifyState<'a, D, S>
+where
+    D: Digest + Default,
+    S: Signature,
+{
+    /// Verifier to verify the signature with once the message is complete
+    verifier: &'a DigestVerifier<D, S>,
+
+    /// Digest of the message chunks input so far
+    digest: D,
+
+    /// Signature to verify
+    signature: S,
+}
+
+impl<'a, D, S> DigestVerifyState<'a, D, S>
+where
+    D: Digest + Default,
+    S: Signature,
+{
+    /// Begin verifying the given signature with the given verifier
+    pub fn new(verifier: &'a DigestVerifier<D, S>, signature: S) -> Self {
+        Self {
+            verifier,
+            digest: D::default(),
+            signature,
+        }
+    }
+
+    /// Input the next chunk of the message
+    pub fn update(&mut self, data: &[u8]) {
+        self.digest.process(data);
+    }
+
+    /// Verify the signature against the digest of the complete message
+    pub fn finalize(self) -> Result<(), Error> {
+        self.verifier.verify(self.digest, &self.signature)
+    }
+}
+
+/// Verify a signature of the given message with a domain separation
+/// `context` (e.g. a protocol-specific tag) prepended to it, using the
+/// given `DigestVerifier`.
+///
+/// The signed data is the digest of `context || msg`, i.e. the context
+/// immediately followed by the message with no separator or length prefix.
+/// Signers must compute the digest the same way (by inputting `context` and
+/// then `msg` into `D`).
+pub fn verify_with_context<D, S>(
+    verifier: &DigestVerifier<D, S>,
+    context: &[u8],
+    msg: &[u8],
+    signature: &S,
+) -> Result<(), Error>
+where
+    D: Digest + Default,
+    S: Signature,
+{
+    let mut digest = D::default();
+    digest.process(context);
+    digest.process(msg);
+    verifier.verify(digest, signature)
+}
+
+/// Verify a detached signature of the file at the given path, streaming the
+/// file's contents through the `Digest` (without reading it all into memory)
+/// and then verifying the resulting digest with the given `DigestVerifier`.
+///
+/// The digest is passed to the verifier in its entirety: when the digest is
+/// larger than the curve's scalars (e.g. SHA-512 with P-256), it's up to the
+/// verifier to truncate it to the leftmost bits of the scalar size, per the
+/// rules in FIPS 186-4 section 6.4.
+#[cfg(feature = "std")]
+pub fn verify_file_digest<D, S, P>(
+    verifier: &DigestVerifier<D, S>,
+    path: P,
+    signature: &S,
+) -> Result<(), Error>
+where
+    D: Digest + Default,
+    S: Signature,
+    P: AsRef<Path>,
+{
+    let mut file = File::open(path)?;
+    let mut digest = D::default();
+    let mut buffer = [0u8; 8192];
+
+    loop {
+        let nbytes = file.read(&mut buffer)?;
+
+        if nbytes == 0 {
+            break;
+        }
+
+        digest.process(&buffer[..nbytes]);
+    }
+
+    verifier.verify(digest, signature)
+}
+
+#[cfg(all(
+    test,
+    feature = "sha2",
+    feature = "std",
+    feature = "test-signer",
+    feature = "test-vectors"
+))]
+mod tests {
+    use digest::Digest;
+    use sha2::Sha256;
+
+    use super::{verify_with_context, DigestVerifyState};
+    use curve::nistp256::{FixedSignature, SecretKey, SHA256_FIXED_SIZE_TEST_VECTORS};
+    use ecdsa::test_verifier::ResigningVerifier;
+    use ecdsa::MemorySigner;
+    use error::ErrorKind;
+    use {DigestSigner, PublicKeyed};
+
+    /// Verifier for the first test vector, and its signature of the SHA-256
+    /// digest of `context || msg`
+    fn sign(context: &[u8], msg: &[u8]) -> (ResigningVerifier, FixedSignature) {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = MemorySigner::from(&SecretKey::from_bytes(vector.sk).unwrap());
+
+        let mut digest = Sha256::default();
+        digest.input(context);
+        digest.input(msg);
+
+        let verifier = ResigningVerifier::from(&signer.public_key().unwrap());
+        (verifier, signer.sign(digest).unwrap())
+    }
+
+    #[test]
+    fn test_digest_verify_state_chunked_message() {
+        let msg = SHA256_FIXED_SIZE_TEST_VECTORS[0].msg;
+        let (verifier, signature) = sign(b"", msg);
+
+        let mut one_chunk = DigestVerifyState::<Sha256, _>::new(&verifier, signature.clone());
+        one_chunk.update(msg);
+        assert!(one_chunk.finalize().is_ok());
+
+        let mut many_chunks = DigestVerifyState::<Sha256, _>::new(&verifier, signature.clone());
+        for chunk in msg.chunks(7) {
+            many_chunks.update(chunk);
+        }
+        assert!(many_chunks.finalize().is_ok());
+    }
+
+    #[test]
+    fn test_digest_verify_state_rejects_truncated_message() {
+        let msg = SHA256_FIXED_SIZE_TEST_VECTORS[0].msg;
+        let (verifier, signature) = sign(b"", msg);
+
+        let mut truncated = DigestVerifyState::<Sha256, _>::new(&verifier, signature);
+        truncated.update(&msg[1..]);
+        assert_eq!(
+            truncated.finalize().unwrap_err().kind(),
+            ErrorKind::SignatureInvalid
+        );
+    }
+
+    #[test]
+    fn test_verify_with_context() {
+        let msg = SHA256_FIXED_SIZE_TEST_VECTORS[0].msg;
+        let (verifier, signature) = sign(b"signatory-test-context", msg);
+
+        assert!(verify_with_context::<Sha256, _>(
+            &verifier,
+            b"signatory-test-context",
+            msg,
+            &signature
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_verify_with_context_rejects_wrong_context() {
+        let msg = SHA256_FIXED_SIZE_TEST_VECTORS[0].msg;
+        let (verifier, signature) = sign(b"signatory-test-context", msg);
+
+        for context in &[&b"another-context"[..], b""] {
+            let err =
+                verify_with_context::<Sha256, _>(&verifier, context, msg, &signature).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+        }
+
+        // A signature without a context isn't valid with one
+        let (verifier, signature) = sign(b"", msg);
+        let err =
+            verify_with_context::<Sha256, _>(&verifier, b"signatory-test-context", msg, &signature)
+                .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignatureInvalid);
+    }
+
+    #[test]
+    fn test_verify_file_digest() {
+        use super::verify_file_digest;
+        use std::{env, fs, process};
+
+        let msg = SHA256_FIXED_SIZE_TEST_VECTORS[0].msg;
+        let (verifier, signature) = sign(b"", msg);
+        let path = env::temp_dir().join(format!("signatory-{}.msg", process::id()));
+
+        fs::write(&path, msg).unwrap();
+        let result = verify_file_digest::<Sha256, _, _>(&verifier, &path, &signature);
+
+        fs::write(&path, &msg[1..]).unwrap();
+        let truncated_result = verify_file_digest::<Sha256, _, _>(&verifier, &path, &signature);
+
+        fs::remove_file(&path).unwrap();
+        assert!(result.is_ok());
+        assert_eq!(
+            truncated_result.unwrap_err().kind(),
+            ErrorKind::SignatureInvalid
+        );
+
+        let err = verify_file_digest::<Sha256, _, _>(&verifier, &path, &signature).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Io);
+    }
+}
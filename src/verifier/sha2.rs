@@ -1,7 +1,7 @@
 #[cfg(all(feature = "digest", feature = "sha2"))]
 use digest::Input;
 #[cfg(all(feature = "digest", feature = "sha2"))]
-use sha2::{Sha256, Sha384, Sha512};
+use sha2::{Sha224, Sha256, Sha384, Sha512};
 
 #[cfg(all(feature = "digest", feature = "sha2"))]
 use super::DigestVerifier;
@@ -9,6 +9,28 @@ use {Error, Signature};
 
 // TODO: define these using a macro?
 
+/// Verifier which computes SHA-224 digests of messages
+pub trait Sha224Verifier<S>: Send + Sync
+where
+    S: Signature,
+{
+    /// Verify a signature of the SHA-224 digest of a message
+    fn verify_sha224(&self, msg: &[u8], signature: &S) -> Result<(), Error>;
+}
+
+#[cfg(all(feature = "digest", feature = "sha2"))]
+impl<S, T> Sha224Verifier<S> for T
+where
+    S: Signature,
+    T: DigestVerifier<Sha224, S>,
+{
+    fn verify_sha224(&self, msg: &[u8], signature: &S) -> Result<(), Error> {
+        let mut sha224 = Sha224::default();
+        sha224.process(msg);
+        self.verify(sha224, signature)
+    }
+}
+
 /// Verifier which computes SHA-256 digests of messages
 pub trait Sha256Verifier<S>: Send + Sync
 where
@@ -75,6 +97,19 @@ where
     }
 }
 
+/// Verify SHA-224 of the given message and then sign the resulting digest.
+/// This can be used to avoid importing the `Verifier` and `Signature` traits
+pub fn verify_sha224<S>(
+    verifier: &Sha224Verifier<S>,
+    msg: &[u8],
+    signature: &S,
+) -> Result<(), Error>
+where
+    S: Signature,
+{
+    verifier.verify_sha224(msg, signature)
+}
+
 /// Verify SHA-256 of the given message and then sign the resulting digest.
 /// This can be used to avoid importing the `Verifier` and `Signature` traits
 pub fn verify_sha256<S>(